@@ -0,0 +1,165 @@
+//! Use the Billing Agreements API to charge payers on the reference transactions program without their presence.
+//!
+//! The payer approves an agreement token, which is then exchanged for a billing agreement. Prefer the vault api
+//! for new integrations.
+//!
+//! Charge an agreement with [CaptureOrder::with_billing_agreement](crate::api::orders::CaptureOrder::with_billing_agreement),
+//! or create the order with [OrderPaymentSource::billing_agreement](crate::data::orders::OrderPaymentSource::billing_agreement).
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payments/v1/#billing-agreements>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::billing_agreements::{
+        AgreementToken, AgreementTokenPayload, BillingAgreement, BillingAgreementPayload, CancelAgreementPayload,
+    },
+    data::ids::BillingAgreementId,
+    endpoint::Endpoint,
+};
+
+/// Creates an agreement token, for the payer to approve at its `approval_url` link.
+#[derive(Debug, Clone)]
+pub struct CreateAgreementToken {
+    /// The agreement token to create.
+    pub token: AgreementTokenPayload,
+}
+
+impl CreateAgreementToken {
+    /// New constructor.
+    pub fn new(token: AgreementTokenPayload) -> Self {
+        Self { token }
+    }
+}
+
+impl Endpoint for CreateAgreementToken {
+    type Query = ();
+
+    type Body = AgreementTokenPayload;
+
+    type Response = AgreementToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing-agreements/agreement-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.token))
+    }
+}
+
+/// Creates a billing agreement from an agreement token the payer approved.
+#[derive(Debug, Clone)]
+pub struct CreateBillingAgreement {
+    /// The approved agreement token.
+    pub agreement: BillingAgreementPayload,
+}
+
+impl CreateBillingAgreement {
+    /// New constructor.
+    pub fn new(token_id: impl ToString) -> Self {
+        Self {
+            agreement: BillingAgreementPayload {
+                token_id: token_id.to_string(),
+            },
+        }
+    }
+}
+
+impl Endpoint for CreateBillingAgreement {
+    type Query = ();
+
+    type Body = BillingAgreementPayload;
+
+    type Response = BillingAgreement;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing-agreements/agreements")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.agreement))
+    }
+}
+
+/// Shows details for a billing agreement, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowBillingAgreement {
+    /// The agreement id.
+    pub agreement_id: BillingAgreementId,
+}
+
+impl ShowBillingAgreement {
+    /// New constructor.
+    pub fn new(agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            agreement_id: agreement_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowBillingAgreement {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = BillingAgreement;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing-agreements/agreements/{}", self.agreement_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Cancels a billing agreement, by ID, so it can't be charged anymore.
+#[derive(Debug, Clone)]
+pub struct CancelBillingAgreement {
+    /// The agreement id.
+    pub agreement_id: BillingAgreementId,
+    /// The reason of the cancellation.
+    pub reason: CancelAgreementPayload,
+}
+
+impl CancelBillingAgreement {
+    /// New constructor.
+    pub fn new(agreement_id: impl Into<BillingAgreementId>, reason: CancelAgreementPayload) -> Self {
+        Self {
+            agreement_id: agreement_id.into(),
+            reason,
+        }
+    }
+}
+
+impl Endpoint for CancelBillingAgreement {
+    type Query = ();
+
+    type Body = CancelAgreementPayload;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/billing-agreements/agreements/{}/cancel",
+            self.agreement_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.reason))
+    }
+}
@@ -0,0 +1,138 @@
+//! Merchants can use the Catalog Products API to create products, which are goods and services.
+//!
+//! The products are referenced by the subscription plans.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/catalog-products/v1/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    data::catalog_products::{Product, ProductList, ProductPayload},
+    endpoint::{next_page_number, Endpoint, PageableEndpoint},
+};
+
+/// Creates a product.
+#[derive(Debug, Clone)]
+pub struct CreateProduct {
+    /// The product to create.
+    pub product: ProductPayload,
+}
+
+impl CreateProduct {
+    /// New constructor.
+    pub fn new(product: ProductPayload) -> Self {
+        Self { product }
+    }
+}
+
+impl Endpoint for CreateProduct {
+    type Query = ();
+
+    type Body = ProductPayload;
+
+    type Response = Product;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/catalogs/products")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.product))
+    }
+}
+
+/// The query used to list the products.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListProductsQuery {
+    /// The page number, starting at 1.
+    pub page: Option<i32>,
+    /// The number of products per page.
+    pub page_size: Option<i32>,
+    /// Whether to return the total number of items and pages.
+    pub total_required: Option<bool>,
+}
+
+/// Lists the products.
+#[derive(Debug, Clone)]
+pub struct ListProducts {
+    /// The endpoint query.
+    pub query: ListProductsQuery,
+}
+
+impl ListProducts {
+    /// New constructor.
+    pub fn new(query: ListProductsQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListProducts {
+    type Query = ListProductsQuery;
+
+    type Body = ();
+
+    type Response = ProductList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/catalogs/products")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ListProducts {
+    fn next_page(&self, response: &ProductList) -> Option<Self> {
+        let page = next_page_number(self.query.page, response.total_pages, &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+/// Shows details for a product, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowProduct {
+    /// The product id.
+    pub product_id: String,
+}
+
+impl ShowProduct {
+    /// New constructor.
+    pub fn new(product_id: impl ToString) -> Self {
+        Self {
+            product_id: product_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowProduct {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Product;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/catalogs/products/{}", self.product_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,82 @@
+//! Use Log in with PayPal to let users sign in to your site with their PayPal account.
+//!
+//! The user is sent to the consent url built with [AuthorizeUrlBuilder], and after consenting
+//! PayPal redirects back to the `redirect_uri` with an authorization code.
+//!
+//! Reference: <https://developer.paypal.com/docs/log-in-with-paypal/>
+
+use derive_builder::Builder;
+
+use crate::{data::identity::Scope, PaypalEnv};
+
+/// The Log in with PayPal consent url.
+///
+/// ```
+/// use paypal_rs::{api::identity::AuthorizeUrlBuilder, data::identity::Scope, PaypalEnv};
+///
+/// let url = AuthorizeUrlBuilder::default()
+///     .env(PaypalEnv::Sandbox)
+///     .client_id("clientid")
+///     .redirect_uri("https://example.com/callback")
+///     .scope(Scope::OpenId)
+///     .scope(Scope::Email)
+///     .state("some-state")
+///     .build()
+///     .unwrap()
+///     .url();
+///
+/// assert!(url.starts_with("https://www.sandbox.paypal.com/connect?"));
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct AuthorizeUrl {
+    /// The environment the user will log in to.
+    pub env: PaypalEnv,
+    /// Your client id.
+    pub client_id: String,
+    /// The url PayPal redirects the user to after consenting. Must match the return url configured in your app.
+    pub redirect_uri: String,
+    /// The requested scopes. `openid` is always requested.
+    #[builder(setter(each(name = "scope", into)), default)]
+    pub scopes: Vec<Scope>,
+    /// An opaque value used to maintain state between the request and the callback.
+    #[builder(default)]
+    pub state: Option<String>,
+    /// A value that is returned in the id token, used to mitigate replay attacks.
+    #[builder(default)]
+    pub nonce: Option<String>,
+}
+
+impl AuthorizeUrl {
+    /// Returns the consent url the user should be redirected to.
+    pub fn url(&self) -> String {
+        let mut scopes = vec![Scope::OpenId.as_str()];
+        scopes.extend(
+            self.scopes
+                .iter()
+                .filter(|scope| **scope != Scope::OpenId)
+                .map(Scope::as_str),
+        );
+
+        let mut url = reqwest::Url::parse(&self.env.make_web_url("/connect")).expect("valid consent url");
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("flowEntry", "static")
+                .append_pair("client_id", &self.client_id)
+                .append_pair("response_type", "code")
+                .append_pair("scope", &scopes.join(" "))
+                .append_pair("redirect_uri", &self.redirect_uri);
+
+            if let Some(state) = &self.state {
+                query.append_pair("state", state);
+            }
+
+            if let Some(nonce) = &self.nonce {
+                query.append_pair("nonce", nonce);
+            }
+        }
+
+        url.into()
+    }
+}
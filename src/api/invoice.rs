@@ -10,13 +10,20 @@ use std::borrow::Cow;
 
 use derive_builder::Builder;
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 
 use crate::{
     data::{
-        invoice::{CancelReason, Invoice, InvoiceList, InvoicePayload, SendInvoicePayload},
-        orders::InvoiceNumber,
+        ids::InvoiceId,
+        invoice::{
+            CancelReason, FileReference, Invoice, InvoiceList, InvoiceNumber, InvoicePayload, QRCodeParams, QrCode,
+            RecordPaymentPayload, RecordPaymentResponse, RecordRefundResponse, RefundDetail, SearchInvoicesPayload,
+            SendInvoicePayload,
+        },
+        invoice_template::{Template, TemplateList},
     },
-    endpoint::Endpoint,
+    endpoint::{next_page_number, Endpoint, ItemsEndpoint, PageableEndpoint},
+    errors::ResponseError,
     Query,
 };
 
@@ -45,7 +52,7 @@ impl Endpoint for GenerateInvoiceNumber {
 
     type Response = InvoiceNumber;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/generate-next-invoice-number")
     }
 
@@ -53,8 +60,8 @@ impl Endpoint for GenerateInvoiceNumber {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.invoice_number.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.invoice_number))
     }
 }
 
@@ -80,7 +87,7 @@ impl Endpoint for CreateDraftInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/invoices")
     }
 
@@ -88,8 +95,8 @@ impl Endpoint for CreateDraftInvoice {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.invoice.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.invoice))
     }
 }
 
@@ -97,14 +104,14 @@ impl Endpoint for CreateDraftInvoice {
 #[derive(Debug, Clone)]
 pub struct GetInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
 }
 
 impl GetInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
         }
     }
 }
@@ -116,7 +123,7 @@ impl Endpoint for GetInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice_id))
     }
 
@@ -125,29 +132,45 @@ impl Endpoint for GetInvoice {
     }
 }
 
+/// The list invoices query.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListInvoicesQuery {
+    /// The page number to be retrieved, for the list of invoices.
+    pub page: Option<i32>,
+    /// The maximum number of invoices to return in the response. Value is from 1 to 100.
+    pub page_size: Option<i32>,
+    /// Indicates whether the to show the total count in the response.
+    pub total_required: Option<bool>,
+    /// A comma-separated list of fields to return in the response. Value is `all` or `none`.
+    /// To return only the invoice number, reference and status, specify `none`.
+    pub fields: Option<String>,
+}
+
 /// Lists invoices. To filter the invoices that appear in the response, you can specify one or more optional query parameters.
 /// Page size has the following limits: [1, 100].
-#[derive(Debug, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct ListInvoices {
     /// The endpoint query.
-    pub query: Query,
+    pub query: ListInvoicesQuery,
 }
 
 impl ListInvoices {
     /// New constructor.
-    pub fn new(query: Query) -> Self {
+    pub fn new(query: ListInvoicesQuery) -> Self {
         Self { query }
     }
 }
 
 impl Endpoint for ListInvoices {
-    type Query = Query;
+    type Query = ListInvoicesQuery;
 
     type Body = ();
 
     type Response = InvoiceList;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/invoicing/invoices")
     }
 
@@ -160,6 +183,21 @@ impl Endpoint for ListInvoices {
     }
 }
 
+impl PageableEndpoint for ListInvoices {
+    fn next_page(&self, response: &InvoiceList) -> Option<Self> {
+        let page = next_page_number(self.query.page, Some(response.total_pages), &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+impl ItemsEndpoint for ListInvoices {
+    type Item = Invoice;
+
+    const ITEMS_FIELD: &'static str = "items";
+}
+
 /// Deletes a draft or scheduled invoice, by ID. Deletes invoices in the draft or scheduled state only.
 ///
 /// For invoices that have already been sent, you can cancel the invoice.
@@ -168,14 +206,14 @@ impl Endpoint for ListInvoices {
 #[derive(Debug, Clone)]
 pub struct DeleteInvoice {
     /// The invocie id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
 }
 
 impl DeleteInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
         }
     }
 }
@@ -187,7 +225,7 @@ impl Endpoint for DeleteInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice_id))
     }
 
@@ -230,7 +268,7 @@ impl Endpoint for UpdateInvoice {
 
     type Response = Invoice;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}", self.invoice.id))
     }
 
@@ -238,8 +276,8 @@ impl Endpoint for UpdateInvoice {
         reqwest::Method::PUT
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.invoice.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.invoice))
     }
 
     fn query(&self) -> Option<Self::Query> {
@@ -251,16 +289,16 @@ impl Endpoint for UpdateInvoice {
 #[derive(Debug, Clone)]
 pub struct CancelInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
     /// The reason of the cancelation.
     pub reason: CancelReason,
 }
 
 impl CancelInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString, reason: CancelReason) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>, reason: CancelReason) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
             reason,
         }
     }
@@ -273,7 +311,7 @@ impl Endpoint for CancelInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}/cancel", self.invoice_id))
     }
 
@@ -281,8 +319,8 @@ impl Endpoint for CancelInvoice {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.reason.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.reason))
     }
 }
 
@@ -290,16 +328,16 @@ impl Endpoint for CancelInvoice {
 #[derive(Debug, Clone)]
 pub struct SendInvoice {
     /// The invoice id.
-    pub invoice_id: String,
+    pub invoice_id: InvoiceId,
     /// The payload.
     pub payload: SendInvoicePayload,
 }
 
 impl SendInvoice {
     /// New constructor.
-    pub fn new(invoice_id: impl ToString, payload: SendInvoicePayload) -> Self {
+    pub fn new(invoice_id: impl Into<InvoiceId>, payload: SendInvoicePayload) -> Self {
         Self {
-            invoice_id: invoice_id.to_string(),
+            invoice_id: invoice_id.into(),
             payload,
         }
     }
@@ -312,7 +350,7 @@ impl Endpoint for SendInvoice {
 
     type Response = ();
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/invoicing/invoices/{}/send", self.invoice_id))
     }
 
@@ -320,118 +358,571 @@ impl Endpoint for SendInvoice {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.payload.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+}
+
+/// Sends a reminder to the payer about an invoice, by ID.
+///
+/// In the JSON request body, include a notification object that defines the subject of the reminder and other details.
+#[derive(Debug, Clone)]
+pub struct SendInvoiceReminder {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The reminder notification.
+    pub payload: SendInvoicePayload,
+}
+
+impl SendInvoiceReminder {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, payload: SendInvoicePayload) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            payload,
+        }
     }
 }
 
-/*
+impl Endpoint for SendInvoiceReminder {
+    type Query = ();
+
+    type Body = SendInvoicePayload;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/invoices/{}/remind", self.invoice_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
 
-impl super::Client {
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+}
 
-    /// Generate a QR code
-    pub async fn generate_qr_code(
-        &mut self,
-        invoice_id: &str,
-        params: QRCodeParams,
-        header_params: HeaderParams,
-    ) -> Result<Bytes, ResponseError> {
-        let build = self
-            .setup_headers(
-                self.client.post(
-                    format!(
-                        "{}/v2/invoicing/invoices/{}/generate-qr-code",
-                        self.endpoint(),
-                        invoice_id
-                    )
-                    .as_str(),
-                ),
-                header_params,
-            )
-            .await;
+/// The list templates query.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListTemplatesQuery {
+    /// The fields to return in the response. Value is `all` or `none`. To return only the template name, ID, and default attributes, specify `none`.
+    pub fields: Option<String>,
+    /// The page number to be retrieved, for the list of templates.
+    pub page: Option<i32>,
+    /// The maximum number of templates to return in the response.
+    pub page_size: Option<i32>,
+}
 
-        let res = build.json(&params).send().await?;
+/// Lists merchant-created templates with associated details.
+///
+/// The associated details include the emails, addresses, and phone numbers from the user's PayPal profile.
+#[derive(Debug, Default, Clone)]
+pub struct ListTemplates {
+    /// The endpoint query.
+    pub query: ListTemplatesQuery,
+}
+
+impl ListTemplates {
+    /// New constructor.
+    pub fn new(query: ListTemplatesQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListTemplates {
+    type Query = ListTemplatesQuery;
+
+    type Body = ();
+
+    type Response = TemplateList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/invoicing/templates")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ListTemplates {
+    fn next_page(&self, response: &TemplateList) -> Option<Self> {
+        let page = next_page_number(self.query.page, None, response.links.as_deref().unwrap_or_default())?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+/// Creates an invoice template.
+///
+/// You can use details from this template to create an invoice. You can create up to 50 templates.
+#[derive(Debug, Clone)]
+pub struct CreateTemplate {
+    /// The template to create.
+    pub template: Template,
+}
+
+impl CreateTemplate {
+    /// New constructor.
+    pub fn new(template: Template) -> Self {
+        Self { template }
+    }
+}
+
+impl Endpoint for CreateTemplate {
+    type Query = ();
+
+    type Body = Template;
+
+    type Response = Template;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/invoicing/templates")
+    }
 
-        if res.status().is_success() {
-            let b = res.bytes().await?;
-            Ok(b)
-        } else {
-            Err(res.json::<PaypalError>().await?.into())
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.template))
+    }
+}
+
+/// Shows details for a template, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowTemplate {
+    /// The template id.
+    pub template_id: String,
+}
+
+impl ShowTemplate {
+    /// New constructor.
+    pub fn new(template_id: impl ToString) -> Self {
+        Self {
+            template_id: template_id.to_string(),
         }
     }
+}
+
+impl Endpoint for ShowTemplate {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Template;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/templates/{}", self.template_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Fully updates a template, by ID.
+///
+/// In the JSON request body, include a complete template object. This call does not support partial updates.
+#[derive(Debug, Clone)]
+pub struct UpdateTemplate {
+    /// The template id.
+    pub template_id: String,
+    /// The updated template.
+    pub template: Template,
+}
+
+impl UpdateTemplate {
+    /// New constructor.
+    pub fn new(template_id: impl ToString, template: Template) -> Self {
+        Self {
+            template_id: template_id.to_string(),
+            template,
+        }
+    }
+}
+
+impl Endpoint for UpdateTemplate {
+    type Query = ();
+
+    type Body = Template;
+
+    type Response = Template;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/templates/{}", self.template_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PUT
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.template))
+    }
+}
+
+/// Deletes a template, by ID.
+#[derive(Debug, Clone)]
+pub struct DeleteTemplate {
+    /// The template id.
+    pub template_id: String,
+}
 
-    /// Records a payment for the invoice. If no payment is due, the invoice is marked as PAID. Otherwise, the invoice is marked as PARTIALLY PAID.
-    pub async fn record_invoice_payment(
-        &mut self,
-        invoice_id: &str,
-        payload: RecordPaymentPayload,
-        header_params: crate::HeaderParams,
-    ) -> Result<String, ResponseError> {
-        let build = self
-            .setup_headers(
-                self.client
-                    .post(format!("{}/v2/invoicing/invoices/{}/payments", self.endpoint(), invoice_id).as_str()),
-                header_params,
-            )
-            .await;
-
-        let res = build.json(&payload).send().await?;
-
-        if res.status().is_success() {
-            let x = res.json::<HashMap<String, String>>().await?;
-            Ok(x.get("payment_id").unwrap().to_owned())
-        } else {
-            Err(res.json::<PaypalError>().await?.into())
+impl DeleteTemplate {
+    /// New constructor.
+    pub fn new(template_id: impl ToString) -> Self {
+        Self {
+            template_id: template_id.to_string(),
         }
     }
+}
 
-    // TODO: https://developer.paypal.com/docs/api/invoicing/v2/#invoices_payments-delete
+impl Endpoint for DeleteTemplate {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/templates/{}", self.template_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
 }
-*/
 
-/*
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::data::common::*;
-    use crate::data::invoice::*;
-    use crate::Client;
+/// The search invoices query.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SearchInvoicesQuery {
+    /// The page number to be retrieved, for the list of items.
+    pub page: Option<i32>,
+    /// The maximum number of invoices to return in the response.
+    pub page_size: Option<i32>,
+    /// Indicates whether the to show the total count in the response.
+    pub total_required: Option<bool>,
+}
 
-    async fn create_client() -> Client {
-        dotenvy::dotenv().ok();
-        let clientid = std::env::var("PAYPAL_CLIENTID").unwrap();
-        let secret = std::env::var("PAYPAL_SECRET").unwrap();
+/// Searches for and lists invoices that match search criteria.
+///
+/// If you pass multiple criteria, the response lists invoices that match all criteria.
+#[derive(Debug, Clone)]
+pub struct SearchInvoices {
+    /// The search filters.
+    pub payload: SearchInvoicesPayload,
+    /// The endpoint query.
+    pub query: SearchInvoicesQuery,
+}
 
-        let mut client = Client::new(clientid, secret, crate::PaypalEnv::Sandbox);
-        client.get_access_token().await.unwrap();
-        client
+impl SearchInvoices {
+    /// New constructor.
+    pub fn new(payload: SearchInvoicesPayload, query: SearchInvoicesQuery) -> Self {
+        Self { payload, query }
     }
+}
 
-    #[tokio::test]
-    async fn test_invoice_create_cancel() -> color_eyre::Result<()> {
-        let client = create_client().await;
+impl Endpoint for SearchInvoices {
+    type Query = SearchInvoicesQuery;
 
-        let payload = InvoicePayloadBuilder::default()
-            .detail(InvoiceDetailBuilder::default().currency_code(Currency::EUR).build()?)
-            .invoicer(
-                InvoicerInfoBuilder::default()
-                    .name(NameBuilder::default().full_name("Test Person").build()?)
-                    .build()?,
-            )
-            .items(vec![ItemBuilder::default()
-                .name("Some name")
-                .unit_amount(Money {
-                    currency_code: Currency::EUR,
-                    value: "10.0".to_string(),
-                })
-                .quantity("1")
-                .build()?])
-            .build()?;
+    type Body = SearchInvoicesPayload;
+
+    type Response = InvoiceList;
 
-        let invoice = CreateDraftInvoice::new(payload);
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/invoicing/search-invoices")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for SearchInvoices {
+    fn next_page(&self, response: &InvoiceList) -> Option<Self> {
+        let page = next_page_number(self.query.page, Some(response.total_pages), &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+impl ItemsEndpoint for SearchInvoices {
+    type Item = Invoice;
+
+    const ITEMS_FIELD: &'static str = "items";
+}
+
+/// Records a payment for an invoice, by ID.
+///
+/// Used for payments made outside of paypal, such as cash or check. If no payment is due, the invoice is marked as PAID.
+/// Otherwise, the invoice is marked as PARTIALLY PAID.
+#[derive(Debug, Clone)]
+pub struct RecordPayment {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The payment details.
+    pub payment: RecordPaymentPayload,
+}
+
+impl RecordPayment {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, payment: RecordPaymentPayload) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            payment,
+        }
+    }
+}
+
+impl Endpoint for RecordPayment {
+    type Query = ();
+
+    type Body = RecordPaymentPayload;
+
+    type Response = RecordPaymentResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/invoices/{}/payments", self.invoice_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payment))
+    }
+}
+
+/// Records a refund for an invoice, by ID.
+///
+/// Used for refunds made outside of paypal, such as cash or check. If the refunded amount equals the paid amount,
+/// the invoice is marked as REFUNDED. Otherwise, the invoice is marked as PARTIALLY REFUNDED.
+#[derive(Debug, Clone)]
+pub struct RecordRefund {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The refund details.
+    pub refund: RefundDetail,
+}
+
+impl RecordRefund {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, refund: RefundDetail) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            refund,
+        }
+    }
+}
+
+impl Endpoint for RecordRefund {
+    type Query = ();
+
+    type Body = RefundDetail;
+
+    type Response = RecordRefundResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/invoices/{}/refunds", self.invoice_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.refund))
+    }
+}
+
+/// Deletes an external payment, by invoice ID and transaction ID.
+///
+/// Used to undo a payment recorded by mistake, the invoice returns to the unpaid or partially paid state.
+#[derive(Debug, Clone)]
+pub struct DeleteExternalPayment {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The ID of the external payment transaction to delete.
+    pub transaction_id: String,
+}
+
+impl DeleteExternalPayment {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, transaction_id: impl ToString) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            transaction_id: transaction_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeleteExternalPayment {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v2/invoicing/invoices/{}/payments/{}",
+            self.invoice_id, self.transaction_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
+
+/// Deletes an external refund, by invoice ID and transaction ID.
+#[derive(Debug, Clone)]
+pub struct DeleteExternalRefund {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The ID of the external refund transaction to delete.
+    pub transaction_id: String,
+}
+
+impl DeleteExternalRefund {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, transaction_id: impl ToString) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            transaction_id: transaction_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeleteExternalRefund {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v2/invoicing/invoices/{}/refunds/{}",
+            self.invoice_id, self.transaction_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
+
+/// Generates a QR code for an invoice, by ID.
+///
+/// The QR code is a PNG image that encodes the URL of the invoice, customers can scan it to pay with a mobile device.
+#[derive(Debug, Clone)]
+pub struct GenerateQrCode {
+    /// The invoice id.
+    pub invoice_id: InvoiceId,
+    /// The QR code parameters.
+    pub params: QRCodeParams,
+}
+
+impl GenerateQrCode {
+    /// New constructor.
+    pub fn new(invoice_id: impl Into<InvoiceId>, params: QRCodeParams) -> Self {
+        Self {
+            invoice_id: invoice_id.into(),
+            params,
+        }
+    }
+}
+
+impl Endpoint for GenerateQrCode {
+    type Query = ();
+
+    type Body = QRCodeParams;
+
+    type Response = QrCode;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/invoicing/invoices/{}/generate-qr-code", self.invoice_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.params))
+    }
+
+    fn parse_response(&self, body: bytes::Bytes) -> Result<Self::Response, ResponseError> {
+        QrCode::from_response(&body).map_err(ResponseError::DecodeError)
+    }
+}
+
+/// Uploads a file to be attached to invoices.
+///
+/// The returned file reference can be added to [InvoiceDetail::attachments](crate::data::invoice::InvoiceDetail::attachments)
+/// when creating or updating an invoice.
+#[derive(Debug, Clone)]
+pub struct UploadInvoiceAttachment {
+    /// The file name.
+    pub file_name: String,
+    /// The file media type, for example `application/pdf`.
+    pub content_type: String,
+    /// The file contents.
+    pub data: bytes::Bytes,
+}
+
+impl UploadInvoiceAttachment {
+    /// New constructor.
+    pub fn new(file_name: impl ToString, content_type: impl ToString, data: impl Into<bytes::Bytes>) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            content_type: content_type.to_string(),
+            data: data.into(),
+        }
+    }
+}
+
+impl Endpoint for UploadInvoiceAttachment {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = FileReference;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/invoicing/files")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
 
-        let _res = client.execute(&invoice).await?;
-        Ok(())
+    fn form(&self) -> Option<reqwest::multipart::Form> {
+        let part = || reqwest::multipart::Part::bytes(self.data.to_vec()).file_name(self.file_name.clone());
+        let part = part().mime_str(&self.content_type).unwrap_or_else(|_| part());
+        Some(reqwest::multipart::Form::new().part("file", part))
     }
 }
-*/
@@ -1,5 +1,15 @@
 //! This module contains the api endpoints.
 
+pub mod billing_agreements;
+pub mod catalog_products;
+pub mod identity;
 pub mod invoice;
 pub mod orders;
+pub mod partner_referrals;
 pub mod payments;
+pub mod payouts;
+pub mod subscriptions;
+pub mod transactions;
+pub mod vault;
+pub mod web_profiles;
+pub mod webhooks;
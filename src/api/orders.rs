@@ -8,10 +8,13 @@ use derive_builder::Builder;
 use serde::Serialize;
 
 use crate::{
+    data::ids::{BillingAgreementId, OrderId},
     data::orders::{Order, OrderPayload},
     endpoint::Endpoint,
 };
 
+pub use crate::data::orders::PaymentSourceToken;
+
 /// Creates an order.
 #[derive(Debug)]
 pub struct CreateOrder {
@@ -33,7 +36,7 @@ impl Endpoint for CreateOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Borrowed("/v2/checkout/orders")
     }
 
@@ -41,8 +44,8 @@ impl Endpoint for CreateOrder {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.order.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.order))
     }
 }
 
@@ -50,14 +53,14 @@ impl Endpoint for CreateOrder {
 #[derive(Debug)]
 pub struct ShowOrderDetails {
     /// The order id.
-    pub order_id: String,
+    pub order_id: OrderId,
 }
 
 impl ShowOrderDetails {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
         }
     }
 }
@@ -69,7 +72,7 @@ impl Endpoint for ShowOrderDetails {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}", self.order_id))
     }
 
@@ -78,17 +81,6 @@ impl Endpoint for ShowOrderDetails {
     }
 }
 
-/// The payment source used to fund the payment.
-#[derive(Debug, Serialize, Builder, Clone)]
-pub struct PaymentSourceToken {
-    /// The PayPal-generated ID for the token.
-    pub id: String,
-    /// The tokenization method that generated the ID.
-    ///
-    /// Can only be BILLING_AGREEMENT.
-    pub r#type: String,
-}
-
 /// Payment source used in the capture order endpoint.
 #[derive(Debug, Serialize, Builder, Clone)]
 pub struct PaymentSource {
@@ -96,6 +88,15 @@ pub struct PaymentSource {
     pub token: PaymentSourceToken,
 }
 
+impl PaymentSource {
+    /// A payment source charging the given billing agreement.
+    pub fn billing_agreement(agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            token: PaymentSourceToken::billing_agreement(agreement_id),
+        }
+    }
+}
+
 /// The capture order endpoint body.
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct PaymentSourceBody {
@@ -110,19 +111,41 @@ pub struct PaymentSourceBody {
 #[derive(Debug, Clone, Builder)]
 pub struct CaptureOrder {
     /// The id of the order.
-    pub order_id: String,
+    pub order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl CaptureOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
+
+    /// Captures the order by charging the given billing agreement, without the payer approving it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use paypal_rs::{api::orders::CaptureOrder, Client};
+    ///
+    /// # async fn charge(client: Client) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let capture = CaptureOrder::with_billing_agreement("5O190127TN364715T", "B-50V812176H0783741");
+    /// let order = client.execute(&capture).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_billing_agreement(order_id: impl Into<OrderId>, agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            order_id: order_id.into(),
+            body: PaymentSourceBody {
+                payment_source: Some(PaymentSource::billing_agreement(agreement_id)),
+            },
+        }
+    }
 }
 
 impl Endpoint for CaptureOrder {
@@ -132,7 +155,7 @@ impl Endpoint for CaptureOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}/capture", self.order_id))
     }
 
@@ -140,8 +163,8 @@ impl Endpoint for CaptureOrder {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.body.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.body))
     }
 }
 
@@ -151,19 +174,29 @@ impl Endpoint for CaptureOrder {
 #[derive(Debug)]
 pub struct AuthorizeOrder {
     /// The order id.
-    order_id: String,
+    order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl AuthorizeOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
+
+    /// Authorizes the order by charging the given billing agreement, without the payer approving it.
+    pub fn with_billing_agreement(order_id: impl Into<OrderId>, agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            order_id: order_id.into(),
+            body: PaymentSourceBody {
+                payment_source: Some(PaymentSource::billing_agreement(agreement_id)),
+            },
+        }
+    }
 }
 
 impl Endpoint for AuthorizeOrder {
@@ -173,7 +206,7 @@ impl Endpoint for AuthorizeOrder {
 
     type Response = Order;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/checkout/orders/{}/authorize", self.order_id))
     }
 
@@ -181,8 +214,8 @@ impl Endpoint for AuthorizeOrder {
         reqwest::Method::POST
     }
 
-    fn body(&self) -> Option<Self::Body> {
-        Some(self.body.clone())
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.body))
     }
 }
 /*
@@ -194,7 +227,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_order() -> color_eyre::Result<()> {
-        let mut client = create_client().await;
+        let client = create_client().await;
         client.get_access_token().await.expect("get access token error");
 
         let order = OrderPayloadBuilder::default()
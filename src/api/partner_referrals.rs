@@ -0,0 +1,175 @@
+//! Use the Partner Referrals API to add PayPal seller accounts to PayPal Complete Payments Platform for Marketplaces and Platforms.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/partner-referrals/v2/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::{
+    data::partner_referrals::{
+        MerchantIntegration, MerchantIntegrationLink, PartnerReferralLinks, PartnerReferralPayload, ReferralData,
+    },
+    endpoint::Endpoint,
+};
+
+/// Creates a partner referral that is shared by the API caller.
+///
+/// The returned `action_url` link is where the seller signs up for or links their PayPal account.
+#[derive(Debug, Clone)]
+pub struct CreatePartnerReferral {
+    /// The referral data.
+    pub referral: PartnerReferralPayload,
+}
+
+impl CreatePartnerReferral {
+    /// New constructor.
+    pub fn new(referral: PartnerReferralPayload) -> Self {
+        Self { referral }
+    }
+}
+
+impl Endpoint for CreatePartnerReferral {
+    type Query = ();
+
+    type Body = PartnerReferralPayload;
+
+    type Response = PartnerReferralLinks;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v2/customer/partner-referrals")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.referral))
+    }
+}
+
+/// Shows details for referral data, by ID.
+///
+/// Use it to inspect the onboarding data once the seller returns from the signup flow.
+#[derive(Debug, Clone)]
+pub struct ShowReferralData {
+    /// The ID of the partner referral, returned in the `self` link when it was created.
+    pub partner_referral_id: String,
+}
+
+impl ShowReferralData {
+    /// New constructor.
+    pub fn new(partner_referral_id: impl ToString) -> Self {
+        Self {
+            partner_referral_id: partner_referral_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowReferralData {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ReferralData;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/customer/partner-referrals/{}", self.partner_referral_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Shows the onboarding status of a seller, which must be checked before enabling payments for them.
+#[derive(Debug, Clone)]
+pub struct ShowMerchantIntegration {
+    /// The partner merchant id.
+    pub partner_id: String,
+    /// The seller merchant id.
+    pub merchant_id: String,
+}
+
+impl ShowMerchantIntegration {
+    /// New constructor.
+    pub fn new(partner_id: impl ToString, merchant_id: impl ToString) -> Self {
+        Self {
+            partner_id: partner_id.to_string(),
+            merchant_id: merchant_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowMerchantIntegration {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = MerchantIntegration;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/customer/partners/{}/merchant-integrations/{}",
+            self.partner_id, self.merchant_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// The query used to look up a merchant integration.
+#[derive(Debug, Clone, Serialize, Builder)]
+#[builder(setter(into))]
+pub struct MerchantIntegrationsQuery {
+    /// The partner's unique identifier for the seller, as given when creating the referral.
+    pub tracking_id: String,
+}
+
+/// Looks up the PayPal merchant id of a seller by the tracking id used when creating the referral.
+#[derive(Debug, Clone)]
+pub struct FindMerchantIntegration {
+    /// The partner merchant id.
+    pub partner_id: String,
+    /// The endpoint query.
+    pub query: MerchantIntegrationsQuery,
+}
+
+impl FindMerchantIntegration {
+    /// New constructor.
+    pub fn new(partner_id: impl ToString, tracking_id: impl ToString) -> Self {
+        Self {
+            partner_id: partner_id.to_string(),
+            query: MerchantIntegrationsQuery {
+                tracking_id: tracking_id.to_string(),
+            },
+        }
+    }
+}
+
+impl Endpoint for FindMerchantIntegration {
+    type Query = MerchantIntegrationsQuery;
+
+    type Body = ();
+
+    type Response = MerchantIntegrationLink;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/customer/partners/{}/merchant-integrations",
+            self.partner_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
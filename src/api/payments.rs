@@ -6,7 +6,12 @@ use std::borrow::Cow;
 
 use derive_builder::Builder;
 
-use crate::{data::payment::*, endpoint::Endpoint};
+use crate::{
+    data::ids::{AuthorizationId, CaptureId, RefundId},
+    data::orders::{Capture, Refund},
+    data::payment::*,
+    endpoint::Endpoint,
+};
 
 /// Generates the next invoice number that is available to the merchant.
 ///
@@ -16,14 +21,14 @@ use crate::{data::payment::*, endpoint::Endpoint};
 #[derive(Debug, Default, Clone, Builder)]
 pub struct GetAuthorizedPayment {
     /// The ID of the authorized payment for which to show details.
-    pub authorization_id: String,
+    pub authorization_id: AuthorizationId,
 }
 
 impl GetAuthorizedPayment {
     /// New constructor.
-    pub fn new(authorization_id: impl ToString) -> Self {
+    pub fn new(authorization_id: impl Into<AuthorizationId>) -> Self {
         Self {
-            authorization_id: authorization_id.to_string(),
+            authorization_id: authorization_id.into(),
         }
     }
 }
@@ -35,7 +40,7 @@ impl Endpoint for GetAuthorizedPayment {
 
     type Response = AuthorizedPaymentDetails;
 
-    fn relative_path(&self) -> Cow<str> {
+    fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/payments/authorizations/{}", self.authorization_id))
     }
 
@@ -43,3 +48,108 @@ impl Endpoint for GetAuthorizedPayment {
         reqwest::Method::GET
     }
 }
+
+/// Shows details for a captured payment, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowCapturedPayment {
+    /// The ID of the captured payment for which to show details.
+    pub capture_id: CaptureId,
+}
+
+impl ShowCapturedPayment {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowCapturedPayment {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Capture;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/captures/{}", self.capture_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Refunds a captured payment, by ID.
+///
+/// For a full refund, leave the amount of the refund request empty.
+#[derive(Debug, Clone)]
+pub struct RefundCapture {
+    /// The ID of the captured payment to refund.
+    pub capture_id: CaptureId,
+    /// The refund request.
+    pub refund: RefundRequest,
+}
+
+impl RefundCapture {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>, refund: RefundRequest) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+            refund,
+        }
+    }
+}
+
+impl Endpoint for RefundCapture {
+    type Query = ();
+
+    type Body = RefundRequest;
+
+    type Response = Refund;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/captures/{}/refund", self.capture_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.refund))
+    }
+}
+
+/// Shows details for a refund, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowRefund {
+    /// The ID of the refund for which to show details.
+    pub refund_id: RefundId,
+}
+
+impl ShowRefund {
+    /// New constructor.
+    pub fn new(refund_id: impl Into<RefundId>) -> Self {
+        Self {
+            refund_id: refund_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowRefund {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Refund;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/refunds/{}", self.refund_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,145 @@
+//! Use the Payouts API to make payments to multiple PayPal or Venmo recipients.
+//!
+//! A batch payout sends up to 15000 payouts in a single call, which are processed asynchronously.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payments.payouts-batch/v1/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    data::payouts::{PayoutBatch, PayoutBatchPayload, PayoutItemDetails},
+    endpoint::{next_page_number, Endpoint, PageableEndpoint},
+};
+
+/// Creates a batch payout.
+///
+/// Set the sender batch id as the [request id](crate::HeaderParams::request_id) to retry it safely.
+#[derive(Debug, Clone)]
+pub struct CreateBatchPayout {
+    /// The batch to create.
+    pub batch: PayoutBatchPayload,
+}
+
+impl CreateBatchPayout {
+    /// New constructor.
+    pub fn new(batch: PayoutBatchPayload) -> Self {
+        Self { batch }
+    }
+}
+
+impl Endpoint for CreateBatchPayout {
+    type Query = ();
+
+    type Body = PayoutBatchPayload;
+
+    type Response = PayoutBatch;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/payments/payouts")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.batch))
+    }
+}
+
+/// The query used to show a payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ShowPayoutBatchQuery {
+    /// The page of items, starting at 1.
+    pub page: Option<i32>,
+    /// The number of items per page.
+    pub page_size: Option<i32>,
+    /// Whether to return the total number of items and pages.
+    pub total_required: Option<bool>,
+}
+
+/// Shows the details of a payout batch and its items, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPayoutBatch {
+    /// The id of the batch.
+    pub payout_batch_id: String,
+    /// The endpoint query.
+    pub query: ShowPayoutBatchQuery,
+}
+
+impl ShowPayoutBatch {
+    /// New constructor.
+    pub fn new(payout_batch_id: impl ToString) -> Self {
+        Self {
+            payout_batch_id: payout_batch_id.to_string(),
+            query: ShowPayoutBatchQuery::default(),
+        }
+    }
+}
+
+impl Endpoint for ShowPayoutBatch {
+    type Query = ShowPayoutBatchQuery;
+
+    type Body = ();
+
+    type Response = PayoutBatch;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payments/payouts/{}", self.payout_batch_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ShowPayoutBatch {
+    fn next_page(&self, response: &PayoutBatch) -> Option<Self> {
+        let page = next_page_number(self.query.page, response.total_pages, &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+/// Shows the details of a payout item, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPayoutItem {
+    /// The id of the payout item.
+    pub payout_item_id: String,
+}
+
+impl ShowPayoutItem {
+    /// New constructor.
+    pub fn new(payout_item_id: impl ToString) -> Self {
+        Self {
+            payout_item_id: payout_item_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowPayoutItem {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = PayoutItemDetails;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payments/payouts-item/{}", self.payout_item_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
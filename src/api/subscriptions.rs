@@ -0,0 +1,175 @@
+//! You can use billing plans and subscriptions to create subscriptions that process recurring PayPal payments for
+//! physical or digital goods, or services.
+//!
+//! A plan includes pricing and billing cycle information that defines the amount and frequency of charge for a
+//! subscription, and references a product of the catalog.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/subscriptions/v1/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    data::ids::SubscriptionId,
+    data::subscriptions::{Plan, PlanList, PlanPayload, Subscription},
+    endpoint::{next_page_number, Endpoint, PageableEndpoint},
+};
+
+/// Creates a plan that defines pricing and billing cycle details for subscriptions.
+#[derive(Debug, Clone)]
+pub struct CreatePlan {
+    /// The plan to create.
+    pub plan: PlanPayload,
+}
+
+impl CreatePlan {
+    /// New constructor.
+    pub fn new(plan: PlanPayload) -> Self {
+        Self { plan }
+    }
+}
+
+impl Endpoint for CreatePlan {
+    type Query = ();
+
+    type Body = PlanPayload;
+
+    type Response = Plan;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing/plans")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.plan))
+    }
+}
+
+/// The query used to list the plans.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListPlansQuery {
+    /// Filters the plans by product id.
+    pub product_id: Option<String>,
+    /// The page number, starting at 1.
+    pub page: Option<i32>,
+    /// The number of plans per page.
+    pub page_size: Option<i32>,
+    /// Whether to return the total number of items and pages.
+    pub total_required: Option<bool>,
+}
+
+/// Lists the billing plans.
+#[derive(Debug, Clone)]
+pub struct ListPlans {
+    /// The endpoint query.
+    pub query: ListPlansQuery,
+}
+
+impl ListPlans {
+    /// New constructor.
+    pub fn new(query: ListPlansQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListPlans {
+    type Query = ListPlansQuery;
+
+    type Body = ();
+
+    type Response = PlanList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing/plans")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ListPlans {
+    fn next_page(&self, response: &PlanList) -> Option<Self> {
+        let page = next_page_number(self.query.page, response.total_pages, &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+/// Shows details for a plan, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPlan {
+    /// The plan id.
+    pub plan_id: String,
+}
+
+impl ShowPlan {
+    /// New constructor.
+    pub fn new(plan_id: impl ToString) -> Self {
+        Self {
+            plan_id: plan_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowPlan {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Plan;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/plans/{}", self.plan_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Shows details for a subscription, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowSubscription {
+    /// The subscription id.
+    pub subscription_id: SubscriptionId,
+}
+
+impl ShowSubscription {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowSubscription {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Subscription;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
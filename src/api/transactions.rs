@@ -0,0 +1,94 @@
+//! Use the Transaction Search API to get the history of transactions for a PayPal account.
+//!
+//! Transactions take up to three hours to appear, and a search covers at most 31 days.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/transaction-search/v1/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::{serde_as, skip_serializing_none};
+
+use crate::{
+    data::date_time::Rfc3339,
+    data::transactions::{TransactionDetail, TransactionList},
+    endpoint::{next_page_number, Endpoint, ItemsEndpoint, PageableEndpoint},
+};
+
+/// The query used to list the transactions.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListTransactionsQuery {
+    /// The start date and time of the range, required.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The end date and time of the range, required and at most 31 days after the start.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filters the transactions by id.
+    pub transaction_id: Option<String>,
+    /// Filters the transactions by event code.
+    pub transaction_type: Option<String>,
+    /// Filters the transactions by status code: D, P, S or V.
+    pub transaction_status: Option<String>,
+    /// Filters the transactions by currency.
+    pub transaction_currency: Option<String>,
+    /// The fields to return, comma separated: `transaction_info` by default, or `all`.
+    pub fields: Option<String>,
+    /// The page number, starting at 1.
+    pub page: Option<i32>,
+    /// The number of transactions per page, at most 500.
+    pub page_size: Option<i32>,
+}
+
+/// Lists the transactions of the account in a date range.
+#[derive(Debug, Clone)]
+pub struct ListTransactions {
+    /// The endpoint query.
+    pub query: ListTransactionsQuery,
+}
+
+impl ListTransactions {
+    /// New constructor.
+    pub fn new(query: ListTransactionsQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListTransactions {
+    type Query = ListTransactionsQuery;
+
+    type Body = ();
+
+    type Response = TransactionList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/reporting/transactions")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ListTransactions {
+    fn next_page(&self, response: &TransactionList) -> Option<Self> {
+        let page = next_page_number(self.query.page, response.total_pages, &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+impl ItemsEndpoint for ListTransactions {
+    type Item = TransactionDetail;
+
+    const ITEMS_FIELD: &'static str = "transaction_details";
+}
@@ -0,0 +1,253 @@
+//! The Payment Method Tokens API saves payment methods so payers don't have to enter details for future transactions.
+//!
+//! A setup token is created first, approved by the payer when needed, and then exchanged for a permanent payment token.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payment-tokens/v3/>
+
+use std::borrow::Cow;
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    data::vault::{PaymentToken, PaymentTokenList, PaymentTokenPayload, SetupToken, SetupTokenPayload},
+    endpoint::{next_page_number, Endpoint, PageableEndpoint},
+};
+
+/// Creates a setup token, used to save a payment method without a purchase.
+#[derive(Debug, Clone)]
+pub struct CreateSetupToken {
+    /// The setup token payload.
+    pub payload: SetupTokenPayload,
+}
+
+impl CreateSetupToken {
+    /// New constructor.
+    pub fn new(payload: SetupTokenPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for CreateSetupToken {
+    type Query = ();
+
+    type Body = SetupTokenPayload;
+
+    type Response = SetupToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/setup-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+}
+
+/// Shows details for a setup token, by ID.
+///
+/// Used to poll whether the payer has approved the setup token before converting it into a payment token.
+#[derive(Debug, Clone)]
+pub struct ShowSetupToken {
+    /// The setup token id.
+    pub setup_token_id: String,
+}
+
+impl ShowSetupToken {
+    /// New constructor.
+    pub fn new(setup_token_id: impl ToString) -> Self {
+        Self {
+            setup_token_id: setup_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowSetupToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = SetupToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/setup-tokens/{}", self.setup_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Creates a payment token from an approved setup token.
+#[derive(Debug, Clone)]
+pub struct CreatePaymentToken {
+    /// The payment token payload.
+    pub payload: PaymentTokenPayload,
+}
+
+impl CreatePaymentToken {
+    /// New constructor.
+    pub fn new(payload: PaymentTokenPayload) -> Self {
+        Self { payload }
+    }
+
+    /// Creates a payment token from the given approved setup token.
+    pub fn from_setup_token(setup_token_id: impl ToString) -> Self {
+        Self::new(PaymentTokenPayload::from_setup_token(setup_token_id))
+    }
+}
+
+impl Endpoint for CreatePaymentToken {
+    type Query = ();
+
+    type Body = PaymentTokenPayload;
+
+    type Response = PaymentToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/payment-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+}
+
+/// The query used to list the payment tokens of a customer.
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListPaymentTokensQuery {
+    /// The id of the customer.
+    pub customer_id: String,
+    /// The page number, starting at 1.
+    pub page: Option<i32>,
+    /// The number of payment tokens per page.
+    pub page_size: Option<i32>,
+    /// Whether to return the total number of items and pages.
+    pub total_required: Option<bool>,
+}
+
+/// Lists all the payment tokens of a customer.
+#[derive(Debug, Clone)]
+pub struct ListPaymentTokens {
+    /// The endpoint query.
+    pub query: ListPaymentTokensQuery,
+}
+
+impl ListPaymentTokens {
+    /// New constructor.
+    pub fn new(query: ListPaymentTokensQuery) -> Self {
+        Self { query }
+    }
+
+    /// Lists the payment tokens of the given customer, using the default page.
+    pub fn for_customer(customer_id: impl ToString) -> Self {
+        Self::new(ListPaymentTokensQuery {
+            customer_id: customer_id.to_string(),
+            ..Default::default()
+        })
+    }
+}
+
+impl Endpoint for ListPaymentTokens {
+    type Query = ListPaymentTokensQuery;
+
+    type Body = ();
+
+    type Response = PaymentTokenList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v3/vault/payment-tokens")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl PageableEndpoint for ListPaymentTokens {
+    fn next_page(&self, response: &PaymentTokenList) -> Option<Self> {
+        let page = next_page_number(self.query.page, response.total_pages, &response.links)?;
+        let mut next = self.clone();
+        next.query.page = Some(page);
+        Some(next)
+    }
+}
+
+/// Shows details for a payment token, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPaymentToken {
+    /// The payment token id.
+    pub payment_token_id: String,
+}
+
+impl ShowPaymentToken {
+    /// New constructor.
+    pub fn new(payment_token_id: impl ToString) -> Self {
+        Self {
+            payment_token_id: payment_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowPaymentToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = PaymentToken;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/payment-tokens/{}", self.payment_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Deletes a payment token, by ID. The vaulted payment method can no longer be used.
+#[derive(Debug, Clone)]
+pub struct DeletePaymentToken {
+    /// The payment token id.
+    pub payment_token_id: String,
+}
+
+impl DeletePaymentToken {
+    /// New constructor.
+    pub fn new(payment_token_id: impl ToString) -> Self {
+        Self {
+            payment_token_id: payment_token_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeletePaymentToken {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v3/vault/payment-tokens/{}", self.payment_token_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
@@ -0,0 +1,223 @@
+//! Use the Payment Experience API to create seamless payment experience profiles.
+//!
+//! A web experience profile customizes the classic paypal checkout flow, for example
+//! with the brand name and logo or by hiding the shipping address fields.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payment-experience/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::{
+        common::Patch,
+        web_profiles::{CreatedWebProfile, WebProfile},
+    },
+    endpoint::Endpoint,
+};
+
+/// Creates a web experience profile. Pass the profile name and details in the JSON request body.
+#[derive(Debug, Clone)]
+pub struct CreateWebProfile {
+    /// The web experience profile.
+    pub profile: WebProfile,
+}
+
+impl CreateWebProfile {
+    /// New constructor.
+    pub fn new(profile: WebProfile) -> Self {
+        Self { profile }
+    }
+}
+
+impl Endpoint for CreateWebProfile {
+    type Query = ();
+
+    type Body = WebProfile;
+
+    type Response = CreatedWebProfile;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/payment-experience/web-profiles")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.profile))
+    }
+}
+
+/// Lists the latest web experience profiles for a merchant or subject.
+///
+/// Temporary profiles are not listed.
+#[derive(Debug, Default, Clone)]
+pub struct ListWebProfiles;
+
+impl ListWebProfiles {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Endpoint for ListWebProfiles {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Vec<WebProfile>;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/payment-experience/web-profiles")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Shows details for a web experience profile, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowWebProfile {
+    /// The web experience profile id.
+    pub profile_id: String,
+}
+
+impl ShowWebProfile {
+    /// New constructor.
+    pub fn new(profile_id: impl ToString) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowWebProfile {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = WebProfile;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payment-experience/web-profiles/{}", self.profile_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Fully updates a web experience profile, by ID.
+///
+/// In the JSON request body, include a complete profile object. This call does not support partial updates.
+#[derive(Debug, Clone)]
+pub struct UpdateWebProfile {
+    /// The web experience profile id.
+    pub profile_id: String,
+    /// The updated web experience profile.
+    pub profile: WebProfile,
+}
+
+impl UpdateWebProfile {
+    /// New constructor.
+    pub fn new(profile_id: impl ToString, profile: WebProfile) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+            profile,
+        }
+    }
+}
+
+impl Endpoint for UpdateWebProfile {
+    type Query = ();
+
+    type Body = WebProfile;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payment-experience/web-profiles/{}", self.profile_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PUT
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.profile))
+    }
+}
+
+/// Partially updates a web experience profile, by ID.
+#[derive(Debug, Clone)]
+pub struct PatchWebProfile {
+    /// The web experience profile id.
+    pub profile_id: String,
+    /// The patch operations to apply.
+    pub operations: Vec<Patch>,
+}
+
+impl PatchWebProfile {
+    /// New constructor.
+    pub fn new(profile_id: impl ToString, operations: Vec<Patch>) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+            operations,
+        }
+    }
+}
+
+impl Endpoint for PatchWebProfile {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payment-experience/web-profiles/{}", self.profile_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.operations))
+    }
+}
+
+/// Deletes a web experience profile, by ID.
+#[derive(Debug, Clone)]
+pub struct DeleteWebProfile {
+    /// The web experience profile id.
+    pub profile_id: String,
+}
+
+impl DeleteWebProfile {
+    /// New constructor.
+    pub fn new(profile_id: impl ToString) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeleteWebProfile {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payment-experience/web-profiles/{}", self.profile_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
@@ -0,0 +1,107 @@
+//! Use the Webhooks Management API to subscribe your app to the events of your account.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/webhooks/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::webhooks::{
+        VerifyWebhookSignaturePayload, VerifyWebhookSignatureResponse, Webhook, WebhookList, WebhookPayload,
+    },
+    endpoint::Endpoint,
+};
+
+/// Subscribes your webhook listener to events.
+#[derive(Debug, Clone)]
+pub struct CreateWebhook {
+    /// The webhook to create.
+    pub webhook: WebhookPayload,
+}
+
+impl CreateWebhook {
+    /// New constructor.
+    pub fn new(webhook: WebhookPayload) -> Self {
+        Self { webhook }
+    }
+}
+
+impl Endpoint for CreateWebhook {
+    type Query = ();
+
+    type Body = WebhookPayload;
+
+    type Response = Webhook;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/webhooks")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.webhook))
+    }
+}
+
+/// Lists the webhooks of the app.
+#[derive(Debug, Default, Clone)]
+pub struct ListWebhooks;
+
+impl ListWebhooks {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Endpoint for ListWebhooks {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = WebhookList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/webhooks")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Verifies the signature of a webhook event.
+#[derive(Debug, Clone)]
+pub struct VerifyWebhookSignature {
+    /// The event and its transmission headers.
+    pub payload: VerifyWebhookSignaturePayload,
+}
+
+impl VerifyWebhookSignature {
+    /// New constructor.
+    pub fn new(payload: VerifyWebhookSignaturePayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for VerifyWebhookSignature {
+    type Query = ();
+
+    type Body = VerifyWebhookSignaturePayload;
+
+    type Response = VerifyWebhookSignatureResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/verify-webhook-signature")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        Some(Cow::Borrowed(&self.payload))
+    }
+}
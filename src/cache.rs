@@ -0,0 +1,199 @@
+//! Caches the responses of GET endpoints by their ETag, so polling a resource that didn't change is cheap.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use reqwest::header::{self, HeaderMap};
+
+use crate::{errors::ResponseError, Middleware, Next};
+
+/// A cached response body and the ETag it was returned with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The ETag of the response.
+    pub etag: String,
+    /// The response body.
+    pub body: Bytes,
+}
+
+/// Stores the responses cached by an [EtagCache], by the url, account and `Prefer` header of their request.
+///
+/// Implement it to share the cache between processes, for example in redis.
+pub trait EtagStore: Send + Sync {
+    /// Returns the response cached for the key, if any.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Caches the response for the key, replacing the previous one.
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// An in-memory [EtagStore], holding a bounded number of responses and evicting the oldest ones.
+#[derive(Debug)]
+pub struct MemoryEtagStore {
+    capacity: usize,
+    entries: Mutex<(HashMap<String, CachedResponse>, VecDeque<String>)>,
+}
+
+impl MemoryEtagStore {
+    /// A store holding at most `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+}
+
+impl Default for MemoryEtagStore {
+    /// A store holding at most 1000 responses.
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl EtagStore for MemoryEtagStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries.lock().unwrap().0.get(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap();
+        let (responses, order) = &mut *entries;
+        if responses.insert(key.to_string(), response).is_none() {
+            order.push_back(key.to_string());
+        }
+        while responses.len() > self.capacity {
+            match order.pop_front() {
+                Some(oldest) => responses.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+}
+
+/// A [Middleware] caching the responses of GET requests by their ETag.
+///
+/// The request of a cached resource is sent with `If-None-Match`, and when paypal answers it didn't change with
+/// `304 Not Modified`, the cached body is returned as if paypal sent it, so the endpoint returns its typed value.
+///
+/// Responses are cached per account, by their `PayPal-Auth-Assertion`, and per `Prefer` header, since a minimal
+/// response doesn't hold the whole resource. A cache should only be shared by clients of the same paypal application.
+///
+/// # Examples
+///
+/// ```no_run
+/// use paypal_rs::{api::orders::ShowOrderDetails, cache::EtagCache, Client, PaypalEnv};
+///
+/// # async fn poll(client_id: String, secret: String) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder(client_id, secret, PaypalEnv::Sandbox)
+///     .middleware(EtagCache::new(1000))
+///     .build()?;
+///
+/// // Only the first call downloads the order, as long as it doesn't change.
+/// for _ in 0..10 {
+///     let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EtagCache<S = MemoryEtagStore> {
+    store: S,
+}
+
+impl EtagCache {
+    /// A cache holding at most `capacity` responses in memory.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_store(MemoryEtagStore::new(capacity))
+    }
+}
+
+impl<S: EtagStore> EtagCache<S> {
+    /// A cache keeping the responses in the given store.
+    pub fn with_store(store: S) -> Self {
+        Self { store }
+    }
+
+    /// Returns the store of the cached responses.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    async fn send(&self, mut request: reqwest::Request, next: Next<'_>) -> Result<reqwest::Response, ResponseError> {
+        let key = cache_key(&request);
+        let cached = self.store.get(&key);
+        if let Some(cached) = &cached {
+            if let Ok(etag) = cached.etag.parse() {
+                request.headers_mut().insert(header::IF_NONE_MATCH, etag);
+            }
+        }
+
+        let res = next.run(request).await?;
+        if res.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                let mut headers = res.headers().clone();
+                headers.insert(
+                    header::CONTENT_TYPE,
+                    header::HeaderValue::from_static("application/json"),
+                );
+                return Ok(build_response(reqwest::StatusCode::OK, headers, cached.body));
+            }
+        }
+        if !res.status().is_success() {
+            return Ok(res);
+        }
+        let Some(etag) = res.headers().get(header::ETAG).and_then(|etag| etag.to_str().ok()) else {
+            return Ok(res);
+        };
+
+        let etag = etag.to_string();
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.bytes().await?;
+        self.store.put(
+            &key,
+            CachedResponse {
+                etag,
+                body: body.clone(),
+            },
+        );
+        Ok(build_response(status, headers, body))
+    }
+}
+
+impl<S: EtagStore> Middleware for EtagCache<S> {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response, ResponseError>> {
+        Box::pin(async move {
+            if request.method() == reqwest::Method::GET {
+                self.send(request, next).await
+            } else {
+                next.run(request).await
+            }
+        })
+    }
+}
+
+/// The url of the request, the account it acts on behalf of, if any, and how much of the resource it prefers.
+fn cache_key(request: &reqwest::Request) -> String {
+    let mut key = request.url().to_string();
+    for name in ["PayPal-Auth-Assertion", "Prefer"] {
+        if let Some(value) = request.headers().get(name).and_then(|value| value.to_str().ok()) {
+            key.push_str(&format!(" {}: {}", name, value));
+        }
+    }
+    key
+}
+
+fn build_response(status: reqwest::StatusCode, headers: HeaderMap, body: Bytes) -> reqwest::Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    response.headers_mut().remove(header::CONTENT_LENGTH);
+    reqwest::Response::from(response)
+}
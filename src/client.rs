@@ -1,21 +1,26 @@
 //! The paypal api wrapper client, which holds the http request client.
 
-use base64::Engine;
+use futures_util::{future::BoxFuture, StreamExt};
 use reqwest::header::{self, HeaderMap};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::time::Instant;
+use zeroize::Zeroize;
 
 use crate::{
-    endpoint::Endpoint,
-    errors::{PaypalError, ResponseError},
-    AuthAssertionClaims, HeaderParams, LIVE_ENDPOINT, SANDBOX_ENDPOINT,
+    endpoint::{query_string, Endpoint, ItemsEndpoint, PageableEndpoint},
+    errors::{BuildError, PaypalError, ResponseError},
+    json_items::JsonItems,
+    AuthAssertion, HeaderParams, Prefer, LIVE_ENDPOINT, LIVE_WEB_ENDPOINT, SANDBOX_ENDPOINT, SANDBOX_WEB_ENDPOINT,
 };
 
 /// Represents the access token returned by the OAuth2 authentication.
 ///
+/// It can be serialized to share it between processes, see [Client::with_access_token].
+///
 /// <https://developer.paypal.com/docs/api/get-an-access-token-postman/>
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AccessToken {
     /// The OAuth2 scopes.
     pub scope: String,
@@ -31,20 +36,553 @@ pub struct AccessToken {
     pub nonce: String,
 }
 
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessToken")
+            .field("scope", &self.scope)
+            .field("access_token", &"[REDACTED]")
+            .field("token_type", &self.token_type)
+            .field("app_id", &self.app_id)
+            .field("expires_in", &self.expires_in)
+            .field("nonce", &self.nonce)
+            .finish()
+    }
+}
+
+/// A secret value, such as the client secret.
+///
+/// It is redacted from the debug output and zeroed from memory when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Creates a secret from the given value.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Returns the secret value.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
 /// Stores OAuth2 information.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// The access token is shared between clones, so refreshing it on one client refreshes it on all of them.
+#[derive(Debug, Clone)]
 pub struct Auth {
     /// Your client id.
     pub client_id: String,
     /// The secret.
-    pub secret: String,
+    pub secret: Secret,
+    /// The access token returned by oauth2 authentication and when it expires.
+    token: Arc<RwLock<TokenState>>,
+    /// Held while getting a new access token, so concurrent requests wait for it instead of getting their own.
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+#[derive(Debug, Default)]
+struct TokenState {
+    access_token: Option<AccessToken>,
+    expires: Option<(Instant, Duration)>,
+    obtained_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The Authorization header value, built once per token instead of on every request.
+    authorization: Option<header::HeaderValue>,
+}
+
+impl Auth {
+    /// Creates the auth information without an access token.
+    pub fn new(client_id: String, secret: String) -> Self {
+        Self {
+            client_id,
+            secret: Secret::new(secret),
+            token: Default::default(),
+            refresh_lock: Default::default(),
+        }
+    }
+
     /// The access token returned by oauth2 authentication.
-    pub access_token: Option<AccessToken>,
+    pub fn access_token(&self) -> Option<AccessToken> {
+        self.token.read().unwrap().access_token.clone()
+    }
+
     /// Used to check when the token expires.
-    pub expires: Option<(Instant, Duration)>,
+    pub fn expires(&self) -> Option<(Instant, Duration)> {
+        self.token.read().unwrap().expires
+    }
+
+    /// When the access token was obtained, to persist it along the token.
+    pub fn obtained_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.token.read().unwrap().obtained_at
+    }
+
+    /// The Authorization header value of the current access token.
+    fn authorization(&self) -> Option<header::HeaderValue> {
+        self.token.read().unwrap().authorization.clone()
+    }
+
+    fn set_access_token(&self, token: AccessToken, obtained_at: chrono::DateTime<chrono::Utc>) {
+        let expires_in = Duration::new(token.expires_in, 0);
+        // A token obtained in the future is considered just obtained.
+        let elapsed = (chrono::Utc::now() - obtained_at).to_std().unwrap_or_default();
+        let expires = match Instant::now().checked_sub(elapsed) {
+            Some(obtained) => (obtained, expires_in),
+            None => (Instant::now(), Duration::ZERO),
+        };
+
+        let mut authorization = header::HeaderValue::try_from(format!("Bearer {}", token.access_token)).ok();
+        if let Some(authorization) = &mut authorization {
+            // Keeps the token out of debug output, such as the request traces.
+            authorization.set_sensitive(true);
+        }
+
+        let mut state = self.token.write().unwrap();
+        state.authorization = authorization;
+        state.expires = Some(expires);
+        state.obtained_at = Some(obtained_at);
+        state.access_token = Some(token);
+    }
+
+    /// Forgets the access token, unless it was replaced by another one since.
+    fn clear_access_token(&self, access_token: &str) {
+        let mut state = self.token.write().unwrap();
+        if state.access_token.as_ref().map(|token| token.access_token.as_str()) == Some(access_token) {
+            state.authorization = None;
+            state.expires = None;
+            state.obtained_at = None;
+            state.access_token = None;
+        }
+    }
+}
+
+/// The kind of token being revoked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenTypeHint {
+    /// An access token.
+    AccessToken,
+    /// A refresh token.
+    RefreshToken,
+}
+
+impl TokenTypeHint {
+    /// Returns the value used in the `token_type_hint` parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenTypeHint::AccessToken => "ACCESS_TOKEN",
+            TokenTypeHint::RefreshToken => "REFRESH_TOKEN",
+        }
+    }
+}
+
+/// Decides if a response with the given status should be retried.
+pub type RetryPredicate = Arc<dyn Fn(reqwest::StatusCode) -> bool + Send + Sync>;
+
+/// How failed requests are retried, using exponential backoff between attempts.
+///
+/// Requests that failed to connect or timed out are always retried, other failures are retried if the retry predicate
+/// returns true for the response status. By default only rate limits (429) and server errors (5xx) are retried.
+///
+/// Set a [HeaderParams::request_id] on requests that create resources so retrying them is idempotent.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one. A value of 1 disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled on each following retry.
+    pub base_delay: Duration,
+    /// The maximum delay between attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize the delays, to avoid many clients retrying at the same time.
+    pub jitter: bool,
+    /// Decides if a response with the given status should be retried.
+    pub retry_on: RetryPredicate,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to `max_attempts` attempts with the default delays.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the maximum delay between attempts.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets whether to randomize the delays.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the predicate deciding which response statuses are retried.
+    pub fn retry_on(mut self, predicate: impl Fn(reqwest::StatusCode) -> bool + Send + Sync + 'static) -> Self {
+        self.retry_on = Arc::new(predicate);
+        self
+    }
+
+    /// Returns the delay to wait before the given retry, starting at 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if self.jitter {
+            // Anything between half and the full delay, the last bytes of a v4 uuid are random.
+            let random = uuid::Uuid::new_v4().as_u64_pair().1 as u32;
+            delay / 2 + delay.mul_f64(f64::from(random) / f64::from(u32::MAX) / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on: Arc::new(|status| status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()),
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stops sending requests for a while when too many of them fail, so a paypal outage fails fast with
+/// [ResponseError::ServiceUnavailable] instead of piling up slow failing requests.
+///
+/// Server errors (5xx), timeouts and connection failures count as failures. When the failure rate of the last
+/// `window` requests reaches `failure_rate`, the circuit opens for `cool_down`. Then a single trial request is let
+/// through: the circuit closes if it succeeds and opens again otherwise.
+///
+/// The state is shared between clones of the breaker, and so between clones of a client.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::{CircuitBreaker, Client, PaypalEnv};
+/// use std::time::Duration;
+///
+/// // Opens for 30 seconds when half of the last 20 requests failed.
+/// let client = Client::builder("clientid", "secret", PaypalEnv::Sandbox)
+///     .circuit_breaker(CircuitBreaker::new(0.5, 20, Duration::from_secs(30)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    /// The rate of failed requests, from 0 to 1, which opens the circuit.
+    pub failure_rate: f64,
+    /// The number of recent requests used to compute the failure rate.
+    pub window: usize,
+    /// How long the circuit stays open before a trial request is let through.
+    pub cool_down: Duration,
+    state: Arc<std::sync::Mutex<CircuitState>>,
+}
+
+#[derive(Debug, Default)]
+struct CircuitState {
+    outcomes: std::collections::VecDeque<bool>,
+    open_until: Option<Instant>,
+    /// The generation of the trial request in flight, only its outcome closes or reopens the circuit.
+    trial: Option<u64>,
+    generation: u64,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker.
+    pub fn new(failure_rate: f64, window: usize, cool_down: Duration) -> Self {
+        Self {
+            failure_rate,
+            window: window.max(1),
+            cool_down,
+            state: Default::default(),
+        }
+    }
+
+    /// Whether requests are currently rejected.
+    pub fn is_open(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.open_until.is_some_and(|open_until| Instant::now() < open_until)
+    }
+
+    /// Checks if a request may be sent, returning how long to wait otherwise.
+    ///
+    /// The trial request gets the generation of its trial, to record its outcome with.
+    fn acquire(&self) -> Result<Option<u64>, Duration> {
+        let mut state = self.state.lock().unwrap();
+        match state.open_until {
+            Some(open_until) => {
+                let now = Instant::now();
+                if now < open_until {
+                    return Err(open_until - now);
+                }
+                // Other requests wait for the trial, which gets another cool down in case it never completes.
+                state.generation += 1;
+                state.trial = Some(state.generation);
+                state.open_until = Some(now + self.cool_down);
+                Ok(state.trial)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Records the outcome of a request, with the trial generation it was given, if any.
+    fn record(&self, trial: Option<u64>, success: bool) {
+        let mut state = self.state.lock().unwrap();
+
+        if trial.is_some() && trial == state.trial {
+            state.trial = None;
+            if success {
+                state.open_until = None;
+                state.outcomes.clear();
+            } else {
+                state.open_until = Some(Instant::now() + self.cool_down);
+            }
+            return;
+        }
+        // The requests sent before the circuit opened, or by an abandoned trial, don't change it.
+        if state.open_until.is_some() {
+            return;
+        }
+
+        state.outcomes.push_back(success);
+        while state.outcomes.len() > self.window {
+            state.outcomes.pop_front();
+        }
+
+        if state.outcomes.len() == self.window {
+            let failures = state.outcomes.iter().filter(|success| !**success).count();
+            if failures as f64 / self.window as f64 >= self.failure_rate {
+                state.open_until = Some(Instant::now() + self.cool_down);
+                state.outcomes.clear();
+            }
+        }
+    }
+}
+
+/// Describes a request made by the client, given to a [RequestObserver].
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    /// The name of the endpoint, for example `CreateOrder`.
+    pub endpoint: &'static str,
+    /// The http method.
+    pub method: reqwest::Method,
+    /// The path relative to the api url, for example `/v2/checkout/orders`.
+    pub path: String,
+    /// The attempt number, starting at 1 and increased on each retry.
+    pub attempt: u32,
+}
+
+/// Observes the requests made by a [Client], to record metrics.
+///
+/// All the methods do nothing by default, implement the ones you need.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::{Client, PaypalEnv, RequestInfo, RequestObserver};
+/// use std::time::Duration;
+///
+/// struct Metrics;
+///
+/// impl RequestObserver for Metrics {
+///     fn on_response(&self, request: &RequestInfo, status: Option<reqwest::StatusCode>, elapsed: Duration) {
+///         println!("{} {:?} took {:?}", request.endpoint, status, elapsed);
+///     }
+/// }
+///
+/// let client = Client::builder("clientid", "secret", PaypalEnv::Sandbox)
+///     .observer(Metrics)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait RequestObserver: Send + Sync {
+    /// Called before each attempt of a request is sent.
+    fn on_request(&self, _request: &RequestInfo) {}
+
+    /// Called after each attempt of a request, the status is `None` if no response was received.
+    fn on_response(&self, _request: &RequestInfo, _status: Option<reqwest::StatusCode>, _elapsed: Duration) {}
+
+    /// Called when a request will be retried after the given delay.
+    fn on_retry(&self, _request: &RequestInfo, _delay: Duration) {}
+}
+
+// Allows keeping a handle to an observer given to the client, to read the recorded metrics.
+impl<T: RequestObserver + ?Sized> RequestObserver for Arc<T> {
+    fn on_request(&self, request: &RequestInfo) {
+        (**self).on_request(request)
+    }
+
+    fn on_response(&self, request: &RequestInfo, status: Option<reqwest::StatusCode>, elapsed: Duration) {
+        (**self).on_response(request, status, elapsed)
+    }
+
+    fn on_retry(&self, request: &RequestInfo, delay: Duration) {
+        (**self).on_retry(request, delay)
+    }
+}
+
+impl std::fmt::Debug for dyn RequestObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RequestObserver")
+    }
+}
+
+/// Intercepts the requests sent by a [Client] and their responses.
+///
+/// Middlewares run in the order they were added, each one calling [Next::run] to pass the request to the following
+/// middleware and finally send it. They run on every attempt and on the access token requests, after the client
+/// headers were set up, so they can mutate, sign, log or replace requests and responses.
+///
+/// # Examples
+///
+/// ```
+/// use futures_util::future::BoxFuture;
+/// use paypal_rs::{errors::ResponseError, Client, Middleware, Next, PaypalEnv};
+///
+/// struct AuditLog;
+///
+/// impl Middleware for AuditLog {
+///     fn handle<'a>(
+///         &'a self,
+///         request: reqwest::Request,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, Result<reqwest::Response, ResponseError>> {
+///         Box::pin(async move {
+///             println!("{} {}", request.method(), request.url());
+///             let res = next.run(request).await?;
+///             println!("-> {}", res.status());
+///             Ok(res)
+///         })
+///     }
+/// }
+///
+/// let client = Client::builder("clientid", "secret", PaypalEnv::Sandbox)
+///     .middleware(AuditLog)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handles the request, calling `next` to send it.
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response, ResponseError>>;
+}
+
+impl std::fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Middleware")
+    }
+}
+
+/// The rest of the middleware chain, which ends sending the request.
+#[derive(Debug, Clone)]
+pub struct Next<'a> {
+    client: &'a reqwest::Client,
+    middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    /// Runs the next middleware, or sends the request if there are no more.
+    pub fn run(self, request: reqwest::Request) -> BoxFuture<'a, Result<reqwest::Response, ResponseError>> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => middleware.handle(
+                request,
+                Next {
+                    client: self.client,
+                    middlewares,
+                },
+            ),
+            None => Box::pin(async move { Ok(self.client.execute(request).await?) }),
+        }
+    }
+}
+
+/// A response body along its metadata, returned by [Client::execute_raw].
+#[derive(Debug, Clone)]
+pub struct RawResponse<T> {
+    /// The parsed response body.
+    pub body: T,
+    /// The http status code.
+    pub status: reqwest::StatusCode,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The Paypal-Debug-Id header, which identifies the request when contacting paypal support.
+    pub debug_id: Option<String>,
+    /// The PayPal-Request-Id sent with the request, if any.
+    pub request_id: Option<String>,
+}
+
+impl<T> RawResponse<T> {
+    /// Whether paypal accepted the request to process it asynchronously (202 Accepted).
+    ///
+    /// Follow the [RawResponse::location] or the links of the body to get the result.
+    pub fn is_accepted(&self) -> bool {
+        self.status == reqwest::StatusCode::ACCEPTED
+    }
+
+    /// The Location header, which links to the created or processing resource.
+    pub fn location(&self) -> Option<&str> {
+        self.headers.get(header::LOCATION).and_then(|value| value.to_str().ok())
+    }
 }
 
 /// Represents a client used to interact with the paypal api.
+///
+/// Cloning a client is cheap and the clones share the same access token,
+/// so a single client can be cloned or shared in an [Arc] across tasks.
 #[derive(Debug, Clone)]
 pub struct Client {
     /// Internal http client
@@ -53,6 +591,358 @@ pub struct Client {
     pub env: PaypalEnv,
     /// Api Auth information
     pub auth: Auth,
+    /// Whether to get a new access token when it expired or was rejected before executing an endpoint.
+    ///
+    /// Enabled by default, disable it if you manage the tokens yourself.
+    pub auto_refresh: bool,
+    /// How failed requests are retried. Requests are not retried by default.
+    pub retry_policy: RetryPolicy,
+    /// The header values used when the ones given to [Client::execute_ext] are not set.
+    pub default_header_params: HeaderParams,
+    /// Additional headers sent on every request, unless the request already sets them.
+    pub default_headers: HeaderMap,
+    /// Observes the requests sent to the api, see [RequestObserver].
+    pub observer: Option<Arc<dyn RequestObserver>>,
+    /// The middlewares every request goes through, in order, see [Middleware].
+    pub middlewares: Vec<Arc<dyn Middleware>>,
+    /// Whether to send a generated PayPal-Request-Id on POST requests without one, so retrying them is idempotent.
+    ///
+    /// Disabled by default. The id used is returned by [Client::execute_raw].
+    pub generate_request_id: bool,
+    /// Rejects the requests while paypal is failing, see [CircuitBreaker]. Disabled by default.
+    pub circuit_breaker: Option<CircuitBreaker>,
+    /// The url the requests are sent to instead of the environment one, for example a partner gateway.
+    ///
+    /// Endpoints can override it with [Endpoint::base_url].
+    pub base_url: Option<String>,
+    /// The certificates webhook events are verified with by [Client::verify_webhook].
+    #[cfg(feature = "webhook-verification")]
+    pub webhook_certs: Arc<crate::verification::CertCache>,
+}
+
+/// Builds a [Client] with custom http settings.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::{Client, PaypalEnv};
+/// use std::time::Duration;
+///
+/// let client = Client::builder("clientid", "secret", PaypalEnv::Sandbox)
+///     .timeout(Duration::from_secs(10))
+///     .connect_timeout(Duration::from_secs(2))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    client_id: String,
+    secret: Secret,
+    env: PaypalEnv,
+    http_client: Option<reqwest::Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    proxy_configs: Vec<ProxyConfig>,
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    identity: Option<reqwest::Identity>,
+    auto_refresh: bool,
+    retry_policy: RetryPolicy,
+    default_header_params: HeaderParams,
+    default_headers: HeaderMap,
+    observer: Option<Arc<dyn RequestObserver>>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    generate_request_id: bool,
+    circuit_breaker: Option<CircuitBreaker>,
+    base_url: Option<String>,
+    allow_live: bool,
+    #[cfg(feature = "webhook-verification")]
+    webhook_certs: Arc<crate::verification::CertCache>,
+}
+
+impl ClientBuilder {
+    /// Uses the given pre-configured http client.
+    ///
+    /// The timeouts, proxies and identity of this builder are ignored when a http client is given,
+    /// and the responses are only decompressed if it was built with decompression enabled.
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Sets the timeout of each request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout to connect to paypal.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a proxy the requests go through.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adds a proxy the requests go through, given its url, credentials and the hosts it is bypassed for.
+    ///
+    /// An invalid proxy url fails to build.
+    pub fn proxy_config(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy_configs.push(proxy);
+        self
+    }
+
+    /// Sets the client certificate presented when connecting, for setups requiring mutual TLS.
+    ///
+    /// The identity must match the TLS backend: [reqwest::Identity::from_pkcs12_der] or
+    /// [reqwest::Identity::from_pkcs8_pem] with the `native-tls` feature, and `reqwest::Identity::from_pem` with the
+    /// `rustls` one. A mismatched identity fails to build.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Sets whether to refresh the access token automatically, see [Client::auto_refresh].
+    pub fn auto_refresh(mut self, auto_refresh: bool) -> Self {
+        self.auto_refresh = auto_refresh;
+        self
+    }
+
+    /// Sets how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the partner BN code sent as `PayPal-Partner-Attribution-Id` on every request.
+    pub fn partner_attribution_id(mut self, partner_attribution_id: impl ToString) -> Self {
+        self.default_header_params.partner_attribution_id = Some(partner_attribution_id.to_string());
+        self
+    }
+
+    /// Sets the merchant payer id used on the `PayPal-Auth-Assertion` of every request,
+    /// to act on behalf of that merchant.
+    pub fn merchant_payer_id(mut self, merchant_payer_id: impl ToString) -> Self {
+        self.default_header_params.merchant_payer_id = Some(merchant_payer_id.to_string());
+        self
+    }
+
+    /// Sets the `PayPal-Auth-Assertion` of every request, to act on behalf of a merchant by its payer id or email.
+    pub fn auth_assertion(mut self, auth_assertion: AuthAssertion) -> Self {
+        self.default_header_params.auth_assertion = Some(auth_assertion);
+        self
+    }
+
+    /// Sets how much of the resources paypal returns on every request, see [Prefer].
+    pub fn prefer(mut self, prefer: Prefer) -> Self {
+        self.default_header_params.prefer = Some(prefer);
+        self
+    }
+
+    /// Sets the user agent sent on every request.
+    pub fn user_agent(mut self, user_agent: header::HeaderValue) -> Self {
+        self.default_headers.insert(header::USER_AGENT, user_agent);
+        self
+    }
+
+    /// Adds a header sent on every request.
+    pub fn default_header(mut self, name: header::HeaderName, value: header::HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Sets the observer notified of each attempt of a request, see [RequestObserver].
+    ///
+    /// It is called before each attempt is sent, after its response or failure with the status and latency, and
+    /// before it is retried with the delay. Each call receives the endpoint, method, path and attempt number.
+    pub fn observer(mut self, observer: impl RequestObserver + 'static) -> Self {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Sends a generated PayPal-Request-Id on POST requests without one, see [Client::generate_request_id].
+    pub fn generate_request_id(mut self, generate_request_id: bool) -> Self {
+        self.generate_request_id = generate_request_id;
+        self
+    }
+
+    /// Rejects the requests while paypal is failing, see [CircuitBreaker].
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Sends the requests to the given url instead of the environment one, see [Client::base_url].
+    pub fn base_url(mut self, base_url: impl ToString) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
+    }
+
+    /// Adds a middleware at the end of the chain, see [Middleware].
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets how the certificates of the webhook events are cached and pinned, see [Client::webhook_certs].
+    #[cfg(feature = "webhook-verification")]
+    pub fn webhook_certs(mut self, certs: crate::verification::CertCache) -> Self {
+        self.webhook_certs = Arc::new(certs);
+        self
+    }
+
+    /// Allows building a client for the live environment in debug builds and when `PAYPAL_FORCE_SANDBOX` is set.
+    ///
+    /// Without it, such live clients fail to build, so a developer machine doesn't charge real payment sources by
+    /// mistake. Release builds without `PAYPAL_FORCE_SANDBOX` build live clients either way.
+    pub fn allow_live(mut self) -> Self {
+        self.allow_live = true;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// Fails if the http client can't be built, if a default header parameter is not a valid header value, or if the
+    /// client is for the live environment while it is not allowed, see [ClientBuilder::allow_live].
+    pub fn build(self) -> Result<Client, BuildError> {
+        if self.env == PaypalEnv::Live && !self.allow_live && live_guarded() {
+            return Err(BuildError::LiveNotAllowed);
+        }
+        let params = &self.default_header_params;
+        for value in [
+            &params.client_metadata_id,
+            &params.partner_attribution_id,
+            &params.request_id,
+            &params.content_type,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            header::HeaderValue::from_str(value)?;
+        }
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                for proxy in &self.proxy_configs {
+                    builder = builder.proxy(proxy.to_proxy()?);
+                }
+                #[cfg(any(feature = "native-tls", feature = "rustls"))]
+                if let Some(identity) = self.identity {
+                    builder = builder.identity(identity);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(Client {
+            client,
+            env: self.env,
+            auth: Auth {
+                client_id: self.client_id,
+                secret: self.secret,
+                token: Default::default(),
+                refresh_lock: Default::default(),
+            },
+            auto_refresh: self.auto_refresh,
+            retry_policy: self.retry_policy,
+            default_header_params: self.default_header_params,
+            default_headers: self.default_headers,
+            observer: self.observer,
+            middlewares: self.middlewares,
+            generate_request_id: self.generate_request_id,
+            circuit_breaker: self.circuit_breaker,
+            base_url: self.base_url,
+            #[cfg(feature = "webhook-verification")]
+            webhook_certs: self.webhook_certs,
+        })
+    }
+}
+
+/// A http proxy the requests go through, such as an authenticated egress proxy.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::{Client, PaypalEnv, ProxyConfig};
+///
+/// let proxy = ProxyConfig::new("http://proxy.internal:3128")
+///     .basic_auth("egress", "proxy-password")
+///     .no_proxy("localhost,.internal");
+/// let client = Client::builder("clientid", "secret", PaypalEnv::Sandbox)
+///     .proxy_config(proxy)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    url: String,
+    username: Option<String>,
+    password: Option<Secret>,
+    no_proxy: Option<String>,
+}
+
+impl ProxyConfig {
+    /// A proxy at the given url, such as `http://proxy.internal:3128`.
+    pub fn new(url: impl ToString) -> Self {
+        Self {
+            url: url.to_string(),
+            username: None,
+            password: None,
+            no_proxy: None,
+        }
+    }
+
+    /// Authenticates to the proxy with the given username and password.
+    pub fn basic_auth(mut self, username: impl ToString, password: impl ToString) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(Secret::new(password.to_string()));
+        self
+    }
+
+    /// Bypasses the proxy for the given hosts, written like the `NO_PROXY` environment variable: a comma separated
+    /// list of hosts, domains such as `.internal`, and ip addresses or ranges.
+    pub fn no_proxy(mut self, no_proxy: impl ToString) -> Self {
+        self.no_proxy = Some(no_proxy.to_string());
+        self
+    }
+
+    /// Returns the url of the proxy.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Creates the reqwest proxy, failing if the url is not valid.
+    pub fn to_proxy(&self) -> Result<reqwest::Proxy, reqwest::Error> {
+        let mut proxy = reqwest::Proxy::all(&self.url)?;
+        if let (Some(username), Some(password)) = (&self.username, &self.password) {
+            proxy = proxy.basic_auth(username, password.expose());
+        }
+        if let Some(no_proxy) = &self.no_proxy {
+            proxy = proxy.no_proxy(reqwest::NoProxy::from_string(no_proxy));
+        }
+        Ok(proxy)
+    }
+}
+
+/// Whether live clients must be explicitly allowed: in debug builds or when `PAYPAL_FORCE_SANDBOX` is set.
+fn live_guarded() -> bool {
+    cfg!(debug_assertions) || std::env::var_os("PAYPAL_FORCE_SANDBOX").is_some()
 }
 
 /// The paypal api environment.
@@ -81,10 +971,32 @@ impl PaypalEnv {
         assert!(target.starts_with('/'), "target path must start with '/'");
         format!("{}{}", self.endpoint(), target)
     }
+
+    /// Returns the paypal website of this environment.
+    ///
+    /// When mocking, this is the same as the api endpoint.
+    pub fn web_endpoint(&self) -> &str {
+        match &self {
+            PaypalEnv::Live => LIVE_WEB_ENDPOINT,
+            PaypalEnv::Sandbox => SANDBOX_WEB_ENDPOINT,
+            PaypalEnv::Mock(endpoint) => endpoint.as_str(),
+        }
+    }
+
+    /// Constructs a paypal website url from the target.
+    pub fn make_web_url(&self, target: &str) -> String {
+        assert!(target.starts_with('/'), "target path must start with '/'");
+        format!("{}{}", self.web_endpoint(), target)
+    }
 }
 
 impl Client {
-    /// Returns a new client, you must get_access_token afterwards to interact with the api.
+    /// Returns a new client.
+    ///
+    /// The access token is fetched when executing the first endpoint, or you can call [Client::get_access_token] beforehand.
+    ///
+    /// Panics for the live environment in debug builds or when `PAYPAL_FORCE_SANDBOX` is set, see [Client::try_new],
+    /// or [ClientBuilder::allow_live] to allow it.
     ///
     /// # Examples
     ///
@@ -97,7 +1009,7 @@ impl Client {
     ///     let clientid = std::env::var("PAYPAL_CLIENTID").unwrap();
     ///     let secret = std::env::var("PAYPAL_SECRET").unwrap();
     ///
-    ///     let mut client = Client::new(
+    ///     let client = Client::new(
     ///         clientid,
     ///         secret,
     ///         PaypalEnv::Sandbox,
@@ -106,103 +1018,243 @@ impl Client {
     /// }
     /// ```
     pub fn new(client_id: String, secret: String, env: PaypalEnv) -> Client {
-        Client {
-            client: reqwest::Client::new(),
+        Client::try_new(client_id, secret, env).expect(
+            "live clients are not allowed in debug builds or when PAYPAL_FORCE_SANDBOX is set, \
+             build them with ClientBuilder::allow_live",
+        )
+    }
+
+    /// Returns a new client, like [Client::new], or [BuildError::LiveNotAllowed] instead of panicking.
+    pub fn try_new(client_id: String, secret: String, env: PaypalEnv) -> Result<Client, BuildError> {
+        Client::builder(client_id, secret, env)
+            .http_client(reqwest::Client::new())
+            .build()
+    }
+
+    /// Uses the given access token, obtained at the given time, instead of getting a new one.
+    ///
+    /// Allows sharing a token between processes, for example through a cache, instead of each one getting its own
+    /// and hitting the token endpoint rate limits. Persist the [Auth::access_token] and [Auth::obtained_at] of the
+    /// client that got it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::{AccessToken, Client, PaypalEnv};
+    ///
+    /// let stored = r#"{
+    ///     "scope": "https://uri.paypal.com/services/invoicing",
+    ///     "access_token": "A21AAFEpH4PsADK7qSS7pSRsgzfENtu-Q1ysgEDVDESseMHBYXVJYE8ovjj68elIDy8nF26AwPhfXTIeWAZHSLIsQkSYz9ifg",
+    ///     "token_type": "Bearer",
+    ///     "app_id": "APP-80W284485P519543T",
+    ///     "expires_in": 31668,
+    ///     "nonce": "2020-04-03T15:35:36ZaYZlGvEkV4yVSz8g6bAKFoGSEzuy3CQcz3ljhibkOHg"
+    /// }"#;
+    /// let token: AccessToken = serde_json::from_str(stored).unwrap();
+    ///
+    /// let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+    ///     .with_access_token(token, chrono::Utc::now());
+    /// assert!(!client.access_token_expired());
+    /// ```
+    pub fn with_access_token(self, token: AccessToken, obtained_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.auth.set_access_token(token, obtained_at);
+        self
+    }
+
+    /// Returns a builder to configure the client http settings.
+    pub fn builder(client_id: impl ToString, secret: impl ToString, env: PaypalEnv) -> ClientBuilder {
+        ClientBuilder {
+            client_id: client_id.to_string(),
+            secret: Secret::new(secret.to_string()),
             env,
-            auth: Auth {
-                client_id,
-                secret,
-                access_token: None,
-                expires: None,
-            },
+            http_client: None,
+            timeout: None,
+            connect_timeout: None,
+            proxies: Vec::new(),
+            proxy_configs: Vec::new(),
+            #[cfg(any(feature = "native-tls", feature = "rustls"))]
+            identity: None,
+            auto_refresh: true,
+            retry_policy: RetryPolicy::none(),
+            default_header_params: HeaderParams::default(),
+            default_headers: HeaderMap::new(),
+            observer: None,
+            middlewares: Vec::new(),
+            generate_request_id: false,
+            circuit_breaker: None,
+            base_url: None,
+            allow_live: false,
+            #[cfg(feature = "webhook-verification")]
+            webhook_certs: Default::default(),
         }
     }
 
+    /// Constructs the url of the target, relative to the given base url or else the one of this client.
+    fn make_url(&self, base_url: Option<&str>, target: &str) -> String {
+        assert!(target.starts_with('/'), "target path must start with '/'");
+        let base_url = base_url
+            .or(self.base_url.as_deref())
+            .unwrap_or_else(|| self.env.endpoint());
+        format!("{}{}", base_url.trim_end_matches('/'), target)
+    }
+
     /// Sets up the request headers as required on https://developer.paypal.com/docs/api/reference/api-requests/#http-request-headers
     async fn setup_headers(
         &self,
         builder: reqwest::RequestBuilder,
         header_params: HeaderParams,
     ) -> Result<reqwest::RequestBuilder, ResponseError> {
-        let mut headers = HeaderMap::new();
+        let header_params = header_params.or(&self.default_header_params);
+        let mut headers = HeaderMap::with_capacity(4 + self.default_headers.len());
 
-        headers.append(header::ACCEPT, "application/json".parse().unwrap());
+        headers.append(header::ACCEPT, header::HeaderValue::from_static("application/json"));
 
-        if let Some(token) = &self.auth.access_token {
-            headers.append(
-                header::AUTHORIZATION,
-                format!("Bearer {}", token.access_token).parse().unwrap(),
-            );
+        if let Some(authorization) = self.auth.authorization() {
+            headers.append(header::AUTHORIZATION, authorization);
         }
 
-        if let Some(merchant_payer_id) = header_params.merchant_payer_id {
-            let claims = AuthAssertionClaims {
-                iss: self.auth.client_id.clone(),
-                payer_id: merchant_payer_id,
-            };
-            let jwt_header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256);
-            let token = jsonwebtoken::encode(
-                &jwt_header,
-                &claims,
-                &jsonwebtoken::EncodingKey::from_secret(self.auth.secret.as_ref()),
-            )
-            .unwrap();
-            let encoded_token = base64::engine::general_purpose::STANDARD_NO_PAD.encode(token);
-            headers.append("PayPal-Auth-Assertion", encoded_token.parse().unwrap());
+        let auth_assertion = header_params.auth_assertion.or_else(|| {
+            header_params
+                .merchant_payer_id
+                .map(|payer_id| AuthAssertion::for_payer_id(&self.auth.client_id, payer_id))
+        });
+        if let Some(auth_assertion) = auth_assertion {
+            let mut value: header::HeaderValue = auth_assertion.encode().parse()?;
+            value.set_sensitive(true);
+            headers.append("PayPal-Auth-Assertion", value);
         }
 
         if let Some(client_metadata_id) = header_params.client_metadata_id {
-            headers.append("PayPal-Client-Metadata-Id", client_metadata_id.parse().unwrap());
+            headers.append("PayPal-Client-Metadata-Id", client_metadata_id.parse()?);
         }
 
         if let Some(partner_attribution_id) = header_params.partner_attribution_id {
-            headers.append("PayPal-Partner-Attribution-Id", partner_attribution_id.parse().unwrap());
+            headers.append("PayPal-Partner-Attribution-Id", partner_attribution_id.parse()?);
         }
 
         if let Some(request_id) = header_params.request_id {
-            headers.append("PayPal-Request-Id", request_id.parse().unwrap());
+            headers.append("PayPal-Request-Id", request_id.parse()?);
         }
 
-        headers.append("Prefer", "return=representation".parse().unwrap());
+        let prefer = header_params.prefer.unwrap_or_default();
+        headers.append("Prefer", header::HeaderValue::from_static(prefer.as_str()));
 
         if let Some(content_type) = header_params.content_type {
-            headers.append(header::CONTENT_TYPE, content_type.parse().unwrap());
+            headers.append(header::CONTENT_TYPE, content_type.parse()?);
+        }
+
+        if let Some(mock_response) = header_params.mock_response {
+            let value = serde_json::to_string(&mock_response).expect("serialize the mock response");
+            headers.append("PayPal-Mock-Response", value.parse()?);
+        }
+
+        for (name, value) in &self.default_headers {
+            if !headers.contains_key(name) {
+                headers.insert(name, value.clone());
+            }
         }
 
         Ok(builder.headers(headers))
     }
 
     /// Gets a access token used in all the api calls and saves it.
-    pub async fn get_access_token(&mut self) -> Result<(), ResponseError> {
+    ///
+    /// Concurrent calls share a single token request.
+    pub async fn get_access_token(&self) -> Result<(), ResponseError> {
         if !self.access_token_expired() {
             return Ok(());
         }
-        let res = self
+        let _guard = self.auth.refresh_lock.lock().await;
+        // Another call may have got it while waiting for the lock.
+        if !self.access_token_expired() {
+            return Ok(());
+        }
+        self.refresh_access_token().await
+    }
+
+    /// Gets a new access token after paypal rejected the given one, unless another call already replaced it.
+    async fn replace_rejected_token(&self, rejected: Option<header::HeaderValue>) -> Result<(), ResponseError> {
+        let _guard = self.auth.refresh_lock.lock().await;
+        let current = self.auth.authorization();
+        if current.is_some() && current != rejected {
+            return Ok(());
+        }
+        self.refresh_access_token().await
+    }
+
+    /// Gets a new access token, even if the current one did not expire.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "paypal.access_token",
+            skip_all,
+            fields(status, debug_id, latency_ms),
+            err(Display)
+        )
+    )]
+    async fn refresh_access_token(&self) -> Result<(), ResponseError> {
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+
+        let request = self
             .client
-            .post(self.env.make_url("/v1/oauth2/token"))
-            .basic_auth(&self.auth.client_id, Some(&self.auth.secret))
+            .post(self.make_url(None, "/v1/oauth2/token"))
+            .basic_auth(&self.auth.client_id, Some(self.auth.secret.expose()))
             .header("Content-Type", "x-www-form-urlencoded")
             .header("Accept", "application/json")
             .body("grant_type=client_credentials")
-            .send()
-            .await
-            .map_err(ResponseError::HttpError)?;
+            .build()?;
+        let res = self.send_request(request).await?;
+
+        #[cfg(feature = "tracing")]
+        record_response(&res, started);
 
         if res.status().is_success() {
             let token = res.json::<AccessToken>().await.map_err(ResponseError::HttpError)?;
-            self.auth.expires = Some((Instant::now(), Duration::new(token.expires_in, 0)));
-            self.auth.access_token = Some(token);
+            self.auth.set_access_token(token, chrono::Utc::now());
             Ok(())
         } else {
-            Err(ResponseError::ApiError(
-                res.json::<PaypalError>().await.map_err(ResponseError::HttpError)?,
-            ))
+            Err(api_error(res).await)
+        }
+    }
+
+    /// Revokes the current access token and forgets it once paypal revoked it.
+    ///
+    /// Useful to invalidate the credentials on logout or when they may have been compromised.
+    /// You must call [Client::get_access_token] again before interacting with the api. If revoking fails, the client
+    /// keeps the token.
+    pub async fn revoke_token(&self) -> Result<(), ResponseError> {
+        if let Some(token) = self.auth.access_token() {
+            self.revoke_token_ext(&token.access_token, TokenTypeHint::AccessToken)
+                .await?;
+            self.auth.clear_access_token(&token.access_token);
+        }
+        Ok(())
+    }
+
+    /// Revokes the given access or refresh token.
+    ///
+    /// <https://developer.paypal.com/api/rest/authentication/>
+    pub async fn revoke_token_ext(&self, token: &str, token_type_hint: TokenTypeHint) -> Result<(), ResponseError> {
+        let request = self
+            .client
+            .post(self.make_url(None, "/v1/oauth2/token/terminate"))
+            .basic_auth(&self.auth.client_id, Some(self.auth.secret.expose()))
+            .header("Accept", "application/json")
+            .form(&[("token", token), ("token_type_hint", token_type_hint.as_str())])
+            .build()?;
+        let res = self.send_request(request).await?;
+
+        if res.status().is_success() {
+            Ok(())
+        } else {
+            Err(api_error(res).await)
         }
     }
 
     /// Checks if the access token expired.
     pub fn access_token_expired(&self) -> bool {
-        if let Some(expires) = self.auth.expires {
+        if let Some(expires) = self.auth.expires() {
             expires.0.elapsed() >= expires.1
         } else {
             true
@@ -210,47 +1262,513 @@ impl Client {
     }
 
     /// Executes the given endpoint with the given headers.
+    ///
+    /// If [Client::auto_refresh] is enabled, a new access token is fetched when the current one expired,
+    /// and the request is retried once if paypal rejects the token.
     pub async fn execute_ext<E>(&self, endpoint: &E, headers: HeaderParams) -> Result<E::Response, ResponseError>
     where
         E: Endpoint,
     {
-        let mut url = self.env.make_url(&endpoint.relative_path());
+        Ok(self.execute_raw(endpoint, headers).await?.body)
+    }
+
+    /// Executes the given endpoint with the given headers, returning the response body along its metadata.
+    ///
+    /// Behaves like [Client::execute_ext].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "paypal.execute",
+            skip_all,
+            fields(
+                method = %endpoint.method(),
+                path = %endpoint.relative_path(),
+                status,
+                debug_id,
+                latency_ms,
+            ),
+            err(Display)
+        )
+    )]
+    pub async fn execute_raw<E>(
+        &self,
+        endpoint: &E,
+        mut headers: HeaderParams,
+    ) -> Result<RawResponse<E::Response>, ResponseError>
+    where
+        E: Endpoint,
+    {
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+
+        if self.generate_request_id && endpoint.method() == reqwest::Method::POST {
+            headers = headers.with_generated_request_id();
+        }
+
+        let res = self.send_authorized(endpoint, &headers).await?;
+
+        #[cfg(feature = "tracing")]
+        record_response(&res, started);
+
+        let status = res.status();
+        let response_headers = res.headers().clone();
+
+        let body = if status.is_success() {
+            let body = res.bytes().await?;
+            // Endpoints returning nothing use `()` as the response, which is zero sized, and ignore the body.
+            if body.iter().all(u8::is_ascii_whitespace) || std::mem::size_of::<E::Response>() == 0 {
+                serde_json::from_value(serde_json::Value::Null).map_err(ResponseError::ParseError)?
+            } else {
+                endpoint.parse_response(body)?
+            }
+        } else {
+            return Err(error_response(res).await);
+        };
+
+        Ok(RawResponse {
+            body,
+            status,
+            debug_id: response_headers
+                .get("Paypal-Debug-Id")
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string),
+            headers: response_headers,
+            request_id: headers.request_id,
+        })
+    }
+
+    /// Sends the request of the given endpoint, getting the access token first if needed, and sending it again with a
+    /// new token if paypal rejects the current one, when [Client::auto_refresh] is enabled.
+    async fn send_authorized<E>(&self, endpoint: &E, headers: &HeaderParams) -> Result<reqwest::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        if self.auto_refresh {
+            self.get_access_token().await?;
+        }
+
+        let sent_token = self.auth.authorization();
+        let res = self.send_with_retries(endpoint, headers).await?;
+
+        if self.auto_refresh && res.status() == reqwest::StatusCode::UNAUTHORIZED {
+            let status = res.status();
+            let body = res.bytes().await?;
+            if !is_invalid_token(&body) {
+                return Err(parse_api_error(status, &body));
+            }
+            self.replace_rejected_token(sent_token).await?;
+            return self.send_with_retries(endpoint, headers).await;
+        }
+        Ok(res)
+    }
+
+    /// Sends the request of the given endpoint, retrying it as configured in the [RetryPolicy].
+    async fn send_with_retries<E>(
+        &self,
+        endpoint: &E,
+        headers: &HeaderParams,
+    ) -> Result<reqwest::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let mut info = RequestInfo {
+            endpoint: endpoint_name::<E>(),
+            method: endpoint.method(),
+            path: endpoint.relative_path().into_owned(),
+            attempt: 1,
+        };
+        loop {
+            let trial = match &self.circuit_breaker {
+                Some(circuit_breaker) => circuit_breaker
+                    .acquire()
+                    .map_err(|retry_after| ResponseError::ServiceUnavailable { retry_after })?,
+                None => None,
+            };
+
+            if let Some(observer) = &self.observer {
+                observer.on_request(&info);
+            }
+
+            let started = Instant::now();
+            let res = self.send(endpoint, headers.clone()).await;
+
+            if let Some(observer) = &self.observer {
+                observer.on_response(&info, res.as_ref().ok().map(|res| res.status()), started.elapsed());
+            }
+
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                let success = match &res {
+                    Ok(res) => !res.status().is_server_error(),
+                    Err(ResponseError::HttpError(e)) => !(e.is_connect() || e.is_timeout()),
+                    Err(_) => true,
+                };
+                circuit_breaker.record(trial, success);
+            }
+
+            let retry = match &res {
+                Ok(res) => (self.retry_policy.retry_on)(res.status()),
+                Err(ResponseError::HttpError(e)) => e.is_connect() || e.is_timeout(),
+                Err(_) => false,
+            };
+
+            if !retry || info.attempt >= self.retry_policy.max_attempts {
+                return res;
+            }
+
+            let mut delay = self.retry_policy.delay(info.attempt);
+            if let Some(retry_after) = res.as_ref().ok().and_then(retry_after) {
+                // Waiting less than paypal asks would fail again, give up if it is too long.
+                if retry_after > self.retry_policy.max_delay {
+                    return res;
+                }
+                delay = retry_after;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(attempt = info.attempt, status = ?res.as_ref().ok().map(|res| res.status()), "retrying paypal request");
+
+            if let Some(observer) = &self.observer {
+                observer.on_retry(&info, delay);
+            }
+
+            tokio::time::sleep(delay).await;
+            info.attempt += 1;
+        }
+    }
+
+    /// Builds and sends the request of the given endpoint.
+    async fn send<E>(&self, endpoint: &E, headers: HeaderParams) -> Result<reqwest::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let mut url = self.make_url(endpoint.base_url().as_deref(), &endpoint.relative_path());
 
         if let Some(query) = endpoint.query() {
-            let query_string = serde_qs::to_string(&query).expect("serialize the query correctly");
-            url.push_str(&query_string);
+            let query_string = query_string(&query);
+            if !query_string.is_empty() {
+                url.push('?');
+                url.push_str(&query_string);
+            }
         }
 
         let mut request = self.client.request(endpoint.method(), url);
+        if let Some(timeout) = headers.timeout {
+            request = request.timeout(timeout);
+        }
         request = self.setup_headers(request, headers).await?;
 
         if let Some(body) = endpoint.body() {
             request = request.json(&body);
+        } else if let Some(form) = endpoint.form() {
+            request = request.multipart(form);
         }
 
-        let res = request.send().await?;
+        let request = request.build()?;
+
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::TRACE) {
+            let body = endpoint
+                .body()
+                .and_then(|body| serde_json::to_value(body).ok())
+                .map(|mut body| {
+                    redact(&mut body);
+                    body
+                });
+            tracing::trace!(url = %request.url(), headers = ?request.headers(), body = ?body, "sending paypal request");
+        }
+
+        self.send_request(request).await
+    }
+
+    /// Sends a request through the middlewares.
+    async fn send_request(&self, request: reqwest::Request) -> Result<reqwest::Response, ResponseError> {
+        Next {
+            client: &self.client,
+            middlewares: &self.middlewares,
+        }
+        .run(request)
+        .await
+    }
+
+    /// Executes the given paginated endpoint and the following pages, as a stream of pages.
+    ///
+    /// The stream ends after the last page or the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use paypal_rs::{api::invoice::ListInvoices, Client, PaypalEnv};
+    ///
+    /// # async fn run(client: Client) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let pages: Vec<_> = client.execute_paginated(ListInvoices::default()).try_collect().await?;
+    /// let invoices = pages.into_iter().flat_map(|page| page.items);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_paginated<E>(
+        &self,
+        endpoint: E,
+    ) -> impl futures_util::Stream<Item = Result<E::Response, ResponseError>> + '_
+    where
+        E: PageableEndpoint + 'static,
+    {
+        futures_util::stream::unfold(Some(endpoint), move |endpoint| async move {
+            let endpoint = endpoint?;
+            match self.execute(&endpoint).await {
+                Ok(page) => {
+                    let next = endpoint.next_page(&page);
+                    Some((Ok(page), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// Executes the given list endpoint, parsing the items of the response one by one as it is read.
+    ///
+    /// Only the item being read is held in memory instead of the whole response, which cuts the memory used by large
+    /// pages such as transaction searches. The other fields of the response, such as the links, are ignored.
+    ///
+    /// The stream ends after the last item or the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures_util::TryStreamExt;
+    /// use paypal_rs::{
+    ///     api::transactions::{ListTransactions, ListTransactionsQueryBuilder},
+    ///     Client,
+    /// };
+    ///
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let query = ListTransactionsQueryBuilder::default()
+    ///     .start_date(chrono::Utc::now() - chrono::Duration::days(31))
+    ///     .end_date(chrono::Utc::now())
+    ///     .page_size(500)
+    ///     .build()?;
+    /// let transactions = client.execute_items(ListTransactions::new(query));
+    /// futures_util::pin_mut!(transactions);
+    /// while let Some(transaction) = transactions.try_next().await? {
+    ///     println!("{:?}", transaction.transaction_info.transaction_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_items<E>(&self, endpoint: E) -> impl futures_util::Stream<Item = Result<E::Item, ResponseError>> + '_
+    where
+        E: ItemsEndpoint + 'static,
+    {
+        enum State<E> {
+            Start(E),
+            Reading(Box<reqwest::Response>, JsonItems),
+            Done,
+        }
+
+        futures_util::stream::unfold(State::Start(endpoint), move |state| async move {
+            let (mut res, mut items) = match state {
+                State::Start(endpoint) => match self.send_items_request(&endpoint).await {
+                    Ok(res) => (Box::new(res), JsonItems::new(E::ITEMS_FIELD)),
+                    Err(e) => return Some((Err(e), State::Done)),
+                },
+                State::Reading(res, items) => (res, items),
+                State::Done => return None,
+            };
+            loop {
+                if let Some(item) = items.next_item() {
+                    let item = serde_json::from_slice(&item).map_err(ResponseError::ParseError);
+                    return Some((item, State::Reading(res, items)));
+                }
+                match res.chunk().await {
+                    Ok(Some(chunk)) => items.push(&chunk),
+                    Ok(None) => {
+                        return items
+                            .finish()
+                            .err()
+                            .map(|e| (Err(ResponseError::ParseError(e)), State::Done))
+                    }
+                    Err(e) => return Some((Err(e.into()), State::Done)),
+                }
+            }
+        })
+    }
+
+    /// Sends the request of a list endpoint, returning the response before reading its body.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "paypal.execute",
+            skip_all,
+            fields(
+                method = %endpoint.method(),
+                path = %endpoint.relative_path(),
+                status,
+                debug_id,
+                latency_ms,
+            ),
+            err(Display)
+        )
+    )]
+    async fn send_items_request<E>(&self, endpoint: &E) -> Result<reqwest::Response, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let mut headers = HeaderParams::default();
+        if self.generate_request_id && endpoint.method() == reqwest::Method::POST {
+            headers = headers.with_generated_request_id();
+        }
+
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+        let res = self.send_authorized(endpoint, &headers).await?;
+        #[cfg(feature = "tracing")]
+        record_response(&res, started);
 
         if res.status().is_success() {
-            // code to debug responses when parse fails.
-            //let resp_text = res.text().await?;
-            //dbg!(&resp_text);
-            //let mut f = std::fs::File::create("output.txt").unwrap();
-            //f.write_all(resp_text.as_bytes()).ok();
-            //let response_body: E::Response = serde_json::from_str(&resp_text).unwrap();
-            let response_body = res.json::<E::Response>().await?;
-            Ok(response_body)
+            Ok(res)
         } else {
-            Err(ResponseError::ApiError(res.json::<PaypalError>().await?))
+            Err(error_response(res).await)
         }
     }
 
     /// Executes the given endpoints with the default headers.
     ///
-    /// You must remember to call [Client::get_access_token] first or this may fail due to not being authed.
+    /// If [Client::auto_refresh] is disabled, you must remember to call [Client::get_access_token] first or this may fail due to not being authed.
     pub async fn execute<E>(&self, endpoint: &E) -> Result<E::Response, ResponseError>
     where
         E: Endpoint,
     {
         self.execute_ext(endpoint, HeaderParams::default()).await
     }
+
+    /// Executes the given endpoints with the default headers, running up to `concurrency` of them at the same time.
+    ///
+    /// The results are in the same order as the endpoints, a failed call does not stop the others.
+    /// The access token is fetched once before starting, if needed, instead of on each call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use paypal_rs::{api::invoice::SendInvoice, data::invoice::SendInvoicePayload, Client};
+    ///
+    /// # async fn run(client: Client, invoice_ids: Vec<String>) {
+    /// let endpoints: Vec<_> = invoice_ids
+    ///     .iter()
+    ///     .map(|id| SendInvoice::new(id, SendInvoicePayload::default()))
+    ///     .collect();
+    /// for result in client.execute_all(&endpoints, 8).await {
+    ///     if let Err(e) = result {
+    ///         eprintln!("failed to send an invoice: {}", e);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn execute_all<E>(&self, endpoints: &[E], concurrency: usize) -> Vec<Result<E::Response, ResponseError>>
+    where
+        E: Endpoint,
+    {
+        if self.auto_refresh {
+            // If this fails, each call tries again and returns its own error.
+            let _ = self.get_access_token().await;
+        }
+
+        futures_util::stream::iter(endpoints)
+            .map(|endpoint| self.execute(endpoint))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+/// Checks whether a 401 response body says the access token is invalid or expired.
+fn is_invalid_token(body: &[u8]) -> bool {
+    #[derive(Deserialize)]
+    struct TokenError {
+        error: String,
+    }
+
+    serde_json::from_slice::<TokenError>(body).is_ok_and(|e| e.error == "invalid_token")
+}
+
+/// Parses the `Retry-After` header, which is either a number of seconds or a http date.
+fn retry_after(res: &reqwest::Response) -> Option<Duration> {
+    let value = res.headers().get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().ok()
+}
+
+/// Reads the paypal api error of a failed response.
+async fn api_error(res: reqwest::Response) -> ResponseError {
+    let status = res.status();
+    match res.bytes().await {
+        Ok(body) => parse_api_error(status, &body),
+        Err(e) => ResponseError::HttpError(e),
+    }
+}
+
+/// Reads the error of a failed response.
+async fn error_response(res: reqwest::Response) -> ResponseError {
+    if res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = retry_after(&res);
+        let error = match api_error(res).await {
+            ResponseError::ApiError(e) => Some(e),
+            _ => None,
+        };
+        ResponseError::RateLimited { retry_after, error }
+    } else {
+        api_error(res).await
+    }
+}
+
+/// Parses the paypal api error of a failed response body, keeping the raw body if it isn't one.
+fn parse_api_error(status: reqwest::StatusCode, body: &[u8]) -> ResponseError {
+    match serde_json::from_slice::<PaypalError>(body) {
+        Ok(mut error) => {
+            error.status = Some(status);
+            ResponseError::ApiError(error)
+        }
+        Err(_) => ResponseError::Unexpected {
+            status,
+            body: String::from_utf8_lossy(body).into_owned(),
+        },
+    }
+}
+
+/// Returns the name of an endpoint type, without its module path.
+fn endpoint_name<E>() -> &'static str {
+    let name = std::any::type_name::<E>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Records the status, paypal debug id and latency of a response on the current span.
+#[cfg(feature = "tracing")]
+fn record_response(res: &reqwest::Response, started: Instant) {
+    let span = tracing::Span::current();
+    span.record("status", res.status().as_u16());
+    if let Some(debug_id) = res.headers().get("Paypal-Debug-Id").and_then(|v| v.to_str().ok()) {
+        span.record("debug_id", debug_id);
+    }
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+}
+
+/// Masks the card data of a request body so it can be traced.
+#[cfg(feature = "tracing")]
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if matches!(key.as_str(), "number" | "security_code" | "cvv" | "cvv2" | "expiry") {
+                    *value = serde_json::Value::String("[REDACTED]".to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
 }
@@ -0,0 +1,224 @@
+//! Paypal object definitions used by the billing agreements api.
+
+use super::common::{LinkDescription, LinkRel, Links};
+use super::date_time::Rfc3339;
+use super::ids::BillingAgreementId;
+use crate::countries::Country;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// The type of plan of a billing agreement.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum AgreementPlanType {
+    /// The merchant charges the payer, for one or more merchant accounts.
+    #[default]
+    MerchantInitiatedBilling,
+    /// The merchant charges the payer, for a single merchant account.
+    MerchantInitiatedBillingSingleAgreement,
+    /// A partner charges the payer on behalf of merchants.
+    ChannelInitiatedBilling,
+    /// A partner charges the payer on behalf of a single merchant.
+    ChannelInitiatedBillingSingleAgreement,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The payment types the payer can pay the agreement with.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum AcceptedPaymentType {
+    /// Only instant payments.
+    Instant,
+    /// Instant and delayed payments, such as echecks.
+    Any,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The state of a billing agreement.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum AgreementState {
+    /// The agreement can be charged.
+    Active,
+    /// The agreement was cancelled.
+    Cancelled,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The urls the payer is redirected to and how the agreement is approved.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct MerchantPreferences {
+    /// The url the payer is redirected to after approving the agreement.
+    pub return_url: Option<String>,
+    /// The url the payer is redirected to after cancelling the approval.
+    pub cancel_url: Option<String>,
+    /// The url notified about the agreement.
+    pub notify_url: Option<String>,
+    /// The payment types the payer can pay the agreement with.
+    pub accepted_pymt_type: Option<AcceptedPaymentType>,
+    /// Whether to skip asking the payer for a shipping address.
+    pub skip_shipping_address: Option<bool>,
+    /// Whether the payer can't change the shipping address given in the agreement token.
+    pub immutable_shipping_address: Option<bool>,
+}
+
+/// The plan of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct AgreementPlan {
+    /// The type of plan.
+    #[serde(rename = "type")]
+    pub plan_type: AgreementPlanType,
+    /// The urls the payer is redirected to and how the agreement is approved.
+    pub merchant_preferences: Option<MerchantPreferences>,
+}
+
+/// The payer information of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementPayerInfo {
+    /// The email of the payer.
+    pub email: Option<String>,
+    /// The first name of the payer.
+    pub first_name: Option<String>,
+    /// The last name of the payer.
+    pub last_name: Option<String>,
+    /// The paypal id of the payer.
+    pub payer_id: Option<String>,
+    /// The phone number of the payer.
+    pub phone: Option<String>,
+    /// The country of the payer.
+    pub country_code: Option<Country>,
+}
+
+/// The payer of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementPayer {
+    /// The payment method of the payer, `PAYPAL`.
+    pub payment_method: String,
+    /// The payer information, returned once the agreement is created.
+    pub payer_info: Option<AgreementPayerInfo>,
+}
+
+impl Default for AgreementPayer {
+    fn default() -> Self {
+        Self {
+            payment_method: "PAYPAL".to_string(),
+            payer_info: None,
+        }
+    }
+}
+
+/// A shipping address, in the format of the v1 apis.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct AgreementShippingAddress {
+    /// The name of the recipient.
+    pub recipient_name: Option<String>,
+    /// The first line of the address.
+    pub line1: Option<String>,
+    /// The second line of the address.
+    pub line2: Option<String>,
+    /// The city.
+    pub city: Option<String>,
+    /// The state or province.
+    pub state: Option<String>,
+    /// The postal code.
+    pub postal_code: Option<String>,
+    /// The country.
+    pub country_code: Option<Country>,
+}
+
+/// The agreement token to create, for the payer to approve.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct AgreementTokenPayload {
+    /// The description of the agreement, shown to the payer.
+    pub description: Option<String>,
+    /// The payer.
+    pub payer: AgreementPayer,
+    /// The plan of the agreement.
+    pub plan: AgreementPlan,
+    /// The shipping address of the payer.
+    pub shipping_address: Option<AgreementShippingAddress>,
+}
+
+/// An agreement token, for the payer to approve.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementToken {
+    /// The id of the token.
+    pub token_id: String,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl AgreementToken {
+    /// Returns the url the payer must visit to approve the agreement.
+    pub fn approval_url(&self) -> Option<&str> {
+        self.href(LinkRel::ApprovalUrl)
+    }
+}
+
+/// The agreement to create from an approved agreement token.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BillingAgreementPayload {
+    /// The id of the approved agreement token.
+    pub token_id: String,
+}
+
+/// A billing agreement, to charge the payer without their presence.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BillingAgreement {
+    /// The id of the agreement.
+    pub id: BillingAgreementId,
+    /// The state of the agreement.
+    pub state: Option<AgreementState>,
+    /// The description of the agreement.
+    pub description: Option<String>,
+    /// The payer.
+    pub payer: Option<AgreementPayer>,
+    /// The plan of the agreement.
+    pub plan: Option<AgreementPlan>,
+    /// The shipping address of the payer.
+    pub shipping_address: Option<AgreementShippingAddress>,
+    /// The merchant of the agreement.
+    pub merchant: Option<serde_json::Value>,
+    /// The date and time when the agreement was created.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the agreement was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The reason to cancel a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CancelAgreementPayload {
+    /// The reason of the cancellation.
+    pub description: Option<String>,
+}
+
+impl_links!(AgreementToken, BillingAgreement);
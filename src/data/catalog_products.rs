@@ -0,0 +1,104 @@
+//! Paypal object definitions used by the catalog products api.
+
+use super::common::LinkDescription;
+use super::date_time::Rfc3339;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// The type of a product.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ProductType {
+    /// Physical goods.
+    #[default]
+    Physical,
+    /// Digital goods.
+    Digital,
+    /// A service, such as a technical support.
+    Service,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The product to create.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ProductPayload {
+    /// The id of the product, generated by paypal if omitted.
+    pub id: Option<String>,
+    /// The product name.
+    pub name: String,
+    /// The product description.
+    pub description: Option<String>,
+    /// The product type.
+    pub r#type: ProductType,
+    /// The product category, such as `SOFTWARE`.
+    pub category: Option<String>,
+    /// The image URL for the product.
+    pub image_url: Option<String>,
+    /// The home page URL for the product.
+    pub home_url: Option<String>,
+}
+
+impl ProductPayload {
+    /// Creates a product payload with the given name and type.
+    pub fn new(name: impl ToString, r#type: ProductType) -> Self {
+        Self {
+            name: name.to_string(),
+            r#type,
+            ..Default::default()
+        }
+    }
+}
+
+/// A product of the catalog.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Product {
+    /// The id of the product.
+    pub id: String,
+    /// The product name.
+    pub name: String,
+    /// The product description.
+    pub description: Option<String>,
+    /// The product type.
+    pub r#type: Option<ProductType>,
+    /// The product category.
+    pub category: Option<String>,
+    /// The image URL for the product.
+    pub image_url: Option<String>,
+    /// The home page URL for the product.
+    pub home_url: Option<String>,
+    /// The date and time when the product was created.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the product was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A page of the products of the catalog.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProductList {
+    /// The products, with only their id, name, description and creation time.
+    #[serde(default)]
+    pub products: Vec<Product>,
+    /// The total number of products, only returned when `total_required` is set.
+    pub total_items: Option<i32>,
+    /// The total number of pages, only returned when `total_required` is set.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(Product, ProductList);
@@ -1,6 +1,7 @@
 //! Common paypal object definitions used by 2 or more APIs
 
-use crate::errors::InvalidCurrencyError;
+use crate::countries::Country;
+use crate::errors::{AddressError, InvalidCurrencyError, InvalidLocaleError, MoneyError};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -9,20 +10,25 @@ use std::str::FromStr;
 /// The phone type.
 ///
 /// <https://developer.paypal.com/docs/api/orders/v2/#definition-phone_with_type>
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum PhoneType {
     Fax,
     Home,
     Mobile,
     Other,
     Pager,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The non-portable additional address details
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct AddressDetails {
     /// The street number.
     pub street_number: Option<String>,
@@ -41,7 +47,7 @@ pub struct AddressDetails {
 
 /// The address of the payer.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Address {
     /// The first line of the address. For example, number or street. For example, 173 Drury Lane.
@@ -57,11 +63,103 @@ pub struct Address {
     /// The postal code, which is the zip code or equivalent. Typically required for countries with a postal code or an equivalent.
     pub postal_code: Option<String>,
     /// The two-character [ISO 3166-1](https://developer.paypal.com/docs/api/reference/country-codes/) code that identifies the country or region.
-    pub country_code: Option<String>,
+    pub country_code: Option<Country>,
     /// The non-portable additional address details that are sometimes needed for compliance, risk, or other scenarios where fine-grain address information might be needed.
     pub address_details: Option<AddressDetails>,
 }
 
+impl Address {
+    /// Checks the address has the country, postal code and admin area the country requires, in the expected format.
+    ///
+    /// Only the major paypal markets are checked, the postal code of other countries is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::{countries::Country, data::common::Address};
+    ///
+    /// let address = Address {
+    ///     admin_area_1: Some("CA".to_string()),
+    ///     postal_code: Some("95131".to_string()),
+    ///     country_code: Some(Country::US),
+    ///     ..Default::default()
+    /// };
+    /// assert!(address.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), AddressError> {
+        let country = self
+            .country_code
+            .as_ref()
+            .ok_or(AddressError::MissingField("country_code"))?;
+
+        if let Some(formats) = postal_code_formats(country) {
+            let postal_code = self
+                .postal_code
+                .as_deref()
+                .ok_or(AddressError::MissingField("postal_code"))?;
+            if !formats.iter().any(|format| matches_format(postal_code, format)) {
+                return Err(AddressError::InvalidPostalCode {
+                    country: country.clone(),
+                    postal_code: postal_code.to_string(),
+                });
+            }
+        }
+
+        if let Some(formats) = admin_area_formats(country) {
+            let admin_area = self
+                .admin_area_1
+                .as_deref()
+                .ok_or(AddressError::MissingField("admin_area_1"))?;
+            if !formats.is_empty() && !formats.iter().any(|format| matches_format(admin_area, format)) {
+                return Err(AddressError::InvalidAdminArea {
+                    country: country.clone(),
+                    admin_area: admin_area.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The postal code formats of the country, where `9` is a digit, `A` a letter and other characters are literal.
+fn postal_code_formats(country: &Country) -> Option<&'static [&'static str]> {
+    let formats: &'static [&'static str] = match country {
+        Country::US => &["99999", "99999-9999"],
+        Country::CA => &["A9A 9A9", "A9A9A9"],
+        Country::GB => &[
+            "A9 9AA", "A99 9AA", "AA9 9AA", "AA99 9AA", "A9A 9AA", "AA9A 9AA", "A99AA", "A999AA", "AA99AA", "AA999AA",
+            "A9A9AA", "AA9A9AA",
+        ],
+        Country::DE | Country::FR | Country::IT | Country::ES | Country::MX => &["99999"],
+        Country::BR => &["99999-999", "99999999"],
+        Country::JP => &["999-9999", "9999999"],
+        Country::AU => &["9999"],
+        Country::NL => &["9999 AA", "9999AA"],
+        Country::CN | Country::IN => &["999999"],
+        _ => return None,
+    };
+    Some(formats)
+}
+
+/// The formats of the state or province code, if paypal requires it in the country. Empty if any name is accepted.
+fn admin_area_formats(country: &Country) -> Option<&'static [&'static str]> {
+    match country {
+        Country::US | Country::CA | Country::BR => Some(&["AA"]),
+        Country::AU => Some(&["AA", "AAA"]),
+        Country::JP | Country::MX | Country::CN | Country::IN => Some(&[]),
+        _ => None,
+    }
+}
+
+fn matches_format(value: &str, format: &str) -> bool {
+    value.len() == format.len()
+        && value.bytes().zip(format.bytes()).all(|(v, f)| match f {
+            b'9' => v.is_ascii_digit(),
+            b'A' => v.is_ascii_alphabetic(),
+            f => v == f,
+        })
+}
+
 /// Represents money
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
 #[builder(setter(strip_option, into))]
@@ -95,11 +193,187 @@ impl Money {
     impl_money!(cny, Currency::CNY);
     impl_money!(czk, Currency::CZK);
     impl_money!(jpy, Currency::JPY);
+
+    /// Creates a instance of Money from a decimal, rounded to the decimal places of the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::common::{Currency, Money};
+    /// use rust_decimal::Decimal;
+    ///
+    /// let money = Money::from_decimal(Currency::USD, Decimal::new(10005, 3));
+    /// assert_eq!(money.value, "10.01");
+    /// assert_eq!(Money::from_decimal(Currency::EUR, Decimal::new(10, 0)).value, "10.00");
+    /// assert_eq!(Money::from_decimal(Currency::JPY, Decimal::new(1005, 1)).value, "101");
+    /// assert_eq!(money.decimal().unwrap(), Decimal::new(1001, 2));
+    /// ```
+    #[cfg(feature = "decimal")]
+    pub fn from_decimal(currency_code: Currency, value: rust_decimal::Decimal) -> Self {
+        let decimal_places = currency_code.exponent();
+        let value = value.round_dp_with_strategy(decimal_places, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+        Self {
+            currency_code,
+            value: format!("{:.*}", decimal_places as usize, value),
+        }
+    }
+
+    /// Parses the value as a decimal.
+    #[cfg(feature = "decimal")]
+    pub fn decimal(&self) -> Result<rust_decimal::Decimal, rust_decimal::Error> {
+        self.value.parse()
+    }
+
+    /// Checks the value is a number with at most the decimal places of the currency, as paypal requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert!(Money::usd("10.00").validate().is_ok());
+    /// assert!(Money::jpy("10.00").validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), MoneyError> {
+        let digits = self.value.strip_prefix('-').unwrap_or(&self.value);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let is_number = self.value.len() <= 32
+            && !digits.is_empty()
+            && integer.bytes().all(|b| b.is_ascii_digit())
+            && fraction.bytes().all(|b| b.is_ascii_digit())
+            && (!digits.contains('.') || !fraction.is_empty());
+        if !is_number {
+            return Err(MoneyError::InvalidValue(self.value.clone()));
+        }
+
+        let exponent = self.currency_code.exponent();
+        if fraction.len() > exponent as usize {
+            return Err(MoneyError::InvalidPrecision {
+                value: self.value.clone(),
+                currency: self.currency_code.clone(),
+                exponent,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds two amounts of the same currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// let total = Money::usd("10.50").checked_add(&Money::usd("0.75")).unwrap();
+    /// assert_eq!(total, Money::usd("11.25"));
+    /// assert!(Money::usd("10").checked_add(&Money::eur("10")).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.check_currency(other)?;
+        let value = self.minor_units()?.checked_add(other.minor_units()?);
+        Ok(Money::from_minor(
+            self.currency_code.clone(),
+            value.ok_or(MoneyError::Overflow)?,
+        ))
+    }
+
+    /// Subtracts an amount of the same currency from this one.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.check_currency(other)?;
+        let value = self.minor_units()?.checked_sub(other.minor_units()?);
+        Ok(Money::from_minor(
+            self.currency_code.clone(),
+            value.ok_or(MoneyError::Overflow)?,
+        ))
+    }
+
+    /// Multiplies this amount by a quantity, such as the unit amount of an item.
+    pub fn mul_quantity(&self, quantity: u32) -> Result<Money, MoneyError> {
+        let value = self.minor_units()?.checked_mul(quantity.into());
+        Ok(Money::from_minor(
+            self.currency_code.clone(),
+            value.ok_or(MoneyError::Overflow)?,
+        ))
+    }
+
+    /// Creates an amount from its value in the minor unit of the currency, such as cents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::common::{Currency, Money};
+    ///
+    /// assert_eq!(Money::from_minor_units(Currency::USD, 1050), Money::usd("10.50"));
+    /// assert_eq!(Money::from_minor_units(Currency::JPY, 1050), Money::jpy("1050"));
+    /// assert_eq!(Money::from_minor_units(Currency::KWD, -1050).value, "-1.050");
+    /// ```
+    pub fn from_minor_units(currency_code: Currency, value: i64) -> Money {
+        Money::from_minor(currency_code, value.into())
+    }
+
+    /// Returns the value in the minor unit of the currency, such as cents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert_eq!(Money::usd("10.5").to_minor_units().unwrap(), 1050);
+    /// assert_eq!(Money::jpy("1050").to_minor_units().unwrap(), 1050);
+    /// assert!(Money::usd("10.505").to_minor_units().is_err());
+    /// ```
+    pub fn to_minor_units(&self) -> Result<i64, MoneyError> {
+        self.minor_units()?.try_into().map_err(|_| MoneyError::Overflow)
+    }
+
+    fn check_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency_code != other.currency_code {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: self.currency_code.clone(),
+                found: other.currency_code.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The value in the minor unit of the currency, such as cents.
+    pub(crate) fn minor_units(&self) -> Result<i128, MoneyError> {
+        self.validate()?;
+        let (digits, negative) = match self.value.strip_prefix('-') {
+            Some(digits) => (digits, true),
+            None => (self.value.as_str(), false),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let exponent = self.currency_code.exponent() as usize;
+        let digits = format!("{}{:0<exponent$}", integer, fraction);
+        let value: i128 = digits.parse().map_err(|_| MoneyError::Overflow)?;
+        Ok(if negative { -value } else { value })
+    }
+
+    pub(crate) fn from_minor(currency_code: Currency, value: i128) -> Money {
+        let exponent = currency_code.exponent();
+        let sign = if value < 0 { "-" } else { "" };
+        let scale = 10u128.pow(exponent);
+        let (integer, fraction) = (value.unsigned_abs() / scale, value.unsigned_abs() % scale);
+        let value = if exponent == 0 {
+            format!("{}{}", sign, integer)
+        } else {
+            format!("{}{}.{:0>width$}", sign, integer, fraction, width = exponent as usize)
+        };
+        Money { currency_code, value }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value, self.currency_code)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum LinkMethod {
     Get,
     Post,
@@ -109,6 +383,9 @@ pub enum LinkMethod {
     Connect,
     Options,
     Patch,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// A HTOAES link
@@ -118,132 +395,561 @@ pub struct LinkDescription {
     /// The complete target URL.
     pub href: String,
     /// The link relation type, which serves as an ID for a link that unambiguously describes the semantics of the link.
-    pub rel: Option<String>,
+    pub rel: Option<LinkRel>,
     /// The HTTP method required to make the related call.
     pub method: Option<LinkMethod>,
+    /// A description of the link, sent by some apis.
+    pub description: Option<String>,
 }
 
-/// ISO-4217 currency codes.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
-pub enum Currency {
-    /// Australian dollar
-    AUD,
-    /// Brazilian real, supported for in country paypal accounts only.
-    BRL,
-    /// Canadian dollar
-    CAD,
-    /// Chinese Renmenbi
-    CNY,
-    /// Czech koruna
-    CZK,
-    /// Danish krone
-    DKK,
-    /// Euro
-    EUR,
-    /// Hong Kong dollar
-    HKD,
-    /// Hungarian forint, does not support decimals.
-    HUF,
-    /// Indian rupee, supported for in country paypal india accounts only.
-    INR,
-    /// Israeli new shekel
-    ILS,
-    /// Japanese yen, does not support decimals.
-    JPY,
-    /// Malaysian ringgit
-    MYR,
-    /// Mexican peso
-    MXN,
-    /// New Taiwan dollar, does not support decimals.
-    TWD,
-    /// New Zealand dollar
-    NZD,
-    /// Norwegian krone
-    NOK,
-    /// Philippine peso
-    PHP,
-    /// Polish złoty
-    PLN,
-    /// Pound sterling
-    GBP,
-    /// Russian ruble
-    RUB,
-    /// Singapore dollar
-    SGD,
-    /// Swedish krona
-    SEK,
-    /// Swiss franc
-    CHF,
-    /// Thai baht
-    THB,
-    /// United States dollar
-    USD,
-}
-
-impl Default for Currency {
-    fn default() -> Self {
-        Self::EUR
+string_enum! {
+    /// The relation type of a HATEOAS link.
+    pub enum LinkRel {
+        /// A value not known by this crate.
+        Unknown(String),
+        /// The resource itself.
+        SelfLink = "self",
+        /// The url the payer must visit to approve the payment.
+        Approve = "approve",
+        /// The url the payer must visit to complete a payer action, such as the 3D Secure authentication.
+        PayerAction = "payer-action",
+        /// The url to send the payer back to, as when the payment source was declined.
+        Redirect = "redirect",
+        /// The parent resource.
+        Up = "up",
+        /// Captures the payment.
+        Capture = "capture",
+        /// Refunds the payment.
+        Refund = "refund",
+        /// Voids the authorization.
+        Void = "void",
+        /// Reauthorizes the authorization.
+        Reauthorize = "reauthorize",
+        /// The next page of a list.
+        Next = "next",
+        /// The previous page of a list.
+        Prev = "prev",
+        /// The first page of a list.
+        First = "first",
+        /// The last page of a list.
+        Last = "last",
+        /// The url the seller must visit to complete the onboarding.
+        ActionUrl = "action_url",
+        /// The url the payer must visit to approve a billing agreement.
+        ApprovalUrl = "approval_url",
     }
 }
 
-impl std::fmt::Display for Currency {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(&self, f)
+/// Implemented by the responses carrying HATEOAS links, to look them up by their relation type.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::data::common::{LinkDescription, LinkRel, Links};
+///
+/// let links = vec![LinkDescription {
+///     href: "https://www.paypal.com/checkoutnow?token=5O190127TN364715T".to_string(),
+///     rel: Some(LinkRel::Approve),
+///     ..Default::default()
+/// }];
+/// assert_eq!(
+///     links.href(LinkRel::Approve),
+///     Some("https://www.paypal.com/checkoutnow?token=5O190127TN364715T")
+/// );
+/// assert!(links.find_rel(LinkRel::Next).is_none());
+/// ```
+pub trait Links {
+    /// The links of the response.
+    fn links(&self) -> &[LinkDescription];
+
+    /// Returns the first link with the given relation type.
+    fn find_rel(&self, rel: LinkRel) -> Option<&LinkDescription> {
+        self.links().iter().find(|link| link.rel.as_ref() == Some(&rel))
+    }
+
+    /// Returns the url of the first link with the given relation type.
+    fn href(&self, rel: LinkRel) -> Option<&str> {
+        self.find_rel(rel).map(|link| link.href.as_str())
+    }
+}
+
+impl Links for [LinkDescription] {
+    fn links(&self) -> &[LinkDescription] {
+        self
+    }
+}
+
+string_enum! {
+    /// ISO-4217 currency codes.
+    #[derive(Default)]
+    pub enum Currency {
+        /// A currency code not known by this crate.
+        Unknown(String),
+        /// UAE dirham
+        AED = "AED",
+        /// Afghan afghani
+        AFN = "AFN",
+        /// Albanian lek
+        ALL = "ALL",
+        /// Armenian dram
+        AMD = "AMD",
+        /// Netherlands Antillean guilder
+        ANG = "ANG",
+        /// Angolan kwanza
+        AOA = "AOA",
+        /// Argentine peso
+        ARS = "ARS",
+        /// Australian dollar
+        AUD = "AUD",
+        /// Aruban florin
+        AWG = "AWG",
+        /// Azerbaijani manat
+        AZN = "AZN",
+        /// Bosnia and Herzegovina convertible mark
+        BAM = "BAM",
+        /// Barbados dollar
+        BBD = "BBD",
+        /// Bangladeshi taka
+        BDT = "BDT",
+        /// Bulgarian lev
+        BGN = "BGN",
+        /// Bahraini dinar
+        BHD = "BHD",
+        /// Burundian franc
+        BIF = "BIF",
+        /// Bermudian dollar
+        BMD = "BMD",
+        /// Brunei dollar
+        BND = "BND",
+        /// Boliviano
+        BOB = "BOB",
+        /// Brazilian real, supported for in country paypal accounts only.
+        BRL = "BRL",
+        /// Bahamian dollar
+        BSD = "BSD",
+        /// Bhutanese ngultrum
+        BTN = "BTN",
+        /// Botswana pula
+        BWP = "BWP",
+        /// Belarusian ruble
+        BYN = "BYN",
+        /// Belize dollar
+        BZD = "BZD",
+        /// Canadian dollar
+        CAD = "CAD",
+        /// Congolese franc
+        CDF = "CDF",
+        /// Swiss franc
+        CHF = "CHF",
+        /// Chilean peso
+        CLP = "CLP",
+        /// Chinese Renmenbi
+        CNY = "CNY",
+        /// Colombian peso
+        COP = "COP",
+        /// Costa Rican colón
+        CRC = "CRC",
+        /// Cuban peso
+        CUP = "CUP",
+        /// Cape Verdean escudo
+        CVE = "CVE",
+        /// Czech koruna
+        CZK = "CZK",
+        /// Djiboutian franc
+        DJF = "DJF",
+        /// Danish krone
+        DKK = "DKK",
+        /// Dominican peso
+        DOP = "DOP",
+        /// Algerian dinar
+        DZD = "DZD",
+        /// Egyptian pound
+        EGP = "EGP",
+        /// Eritrean nakfa
+        ERN = "ERN",
+        /// Ethiopian birr
+        ETB = "ETB",
+        /// Euro
+        #[default]
+        EUR = "EUR",
+        /// Fiji dollar
+        FJD = "FJD",
+        /// Falkland Islands pound
+        FKP = "FKP",
+        /// Pound sterling
+        GBP = "GBP",
+        /// Georgian lari
+        GEL = "GEL",
+        /// Ghanaian cedi
+        GHS = "GHS",
+        /// Gibraltar pound
+        GIP = "GIP",
+        /// Gambian dalasi
+        GMD = "GMD",
+        /// Guinean franc
+        GNF = "GNF",
+        /// Guatemalan quetzal
+        GTQ = "GTQ",
+        /// Guyanese dollar
+        GYD = "GYD",
+        /// Hong Kong dollar
+        HKD = "HKD",
+        /// Honduran lempira
+        HNL = "HNL",
+        /// Haitian gourde
+        HTG = "HTG",
+        /// Hungarian forint, does not support decimals.
+        HUF = "HUF",
+        /// Indonesian rupiah
+        IDR = "IDR",
+        /// Israeli new shekel
+        ILS = "ILS",
+        /// Indian rupee, supported for in country paypal india accounts only.
+        INR = "INR",
+        /// Iraqi dinar
+        IQD = "IQD",
+        /// Iranian rial
+        IRR = "IRR",
+        /// Icelandic króna
+        ISK = "ISK",
+        /// Jamaican dollar
+        JMD = "JMD",
+        /// Jordanian dinar
+        JOD = "JOD",
+        /// Japanese yen, does not support decimals.
+        JPY = "JPY",
+        /// Kenyan shilling
+        KES = "KES",
+        /// Kyrgyzstani som
+        KGS = "KGS",
+        /// Cambodian riel
+        KHR = "KHR",
+        /// Comoro franc
+        KMF = "KMF",
+        /// North Korean won
+        KPW = "KPW",
+        /// South Korean won
+        KRW = "KRW",
+        /// Kuwaiti dinar
+        KWD = "KWD",
+        /// Cayman Islands dollar
+        KYD = "KYD",
+        /// Kazakhstani tenge
+        KZT = "KZT",
+        /// Lao kip
+        LAK = "LAK",
+        /// Lebanese pound
+        LBP = "LBP",
+        /// Sri Lankan rupee
+        LKR = "LKR",
+        /// Liberian dollar
+        LRD = "LRD",
+        /// Lesotho loti
+        LSL = "LSL",
+        /// Libyan dinar
+        LYD = "LYD",
+        /// Moroccan dirham
+        MAD = "MAD",
+        /// Moldovan leu
+        MDL = "MDL",
+        /// Malagasy ariary
+        MGA = "MGA",
+        /// Macedonian denar
+        MKD = "MKD",
+        /// Myanmar kyat
+        MMK = "MMK",
+        /// Mongolian tögrög
+        MNT = "MNT",
+        /// Macanese pataca
+        MOP = "MOP",
+        /// Mauritanian ouguiya
+        MRU = "MRU",
+        /// Mauritian rupee
+        MUR = "MUR",
+        /// Maldivian rufiyaa
+        MVR = "MVR",
+        /// Malawian kwacha
+        MWK = "MWK",
+        /// Mexican peso
+        MXN = "MXN",
+        /// Malaysian ringgit
+        MYR = "MYR",
+        /// Mozambican metical
+        MZN = "MZN",
+        /// Namibian dollar
+        NAD = "NAD",
+        /// Nigerian naira
+        NGN = "NGN",
+        /// Nicaraguan córdoba
+        NIO = "NIO",
+        /// Norwegian krone
+        NOK = "NOK",
+        /// Nepalese rupee
+        NPR = "NPR",
+        /// New Zealand dollar
+        NZD = "NZD",
+        /// Omani rial
+        OMR = "OMR",
+        /// Panamanian balboa
+        PAB = "PAB",
+        /// Peruvian sol
+        PEN = "PEN",
+        /// Papua New Guinean kina
+        PGK = "PGK",
+        /// Philippine peso
+        PHP = "PHP",
+        /// Pakistani rupee
+        PKR = "PKR",
+        /// Polish złoty
+        PLN = "PLN",
+        /// Paraguayan guaraní
+        PYG = "PYG",
+        /// Qatari riyal
+        QAR = "QAR",
+        /// Romanian leu
+        RON = "RON",
+        /// Serbian dinar
+        RSD = "RSD",
+        /// Russian ruble
+        RUB = "RUB",
+        /// Rwandan franc
+        RWF = "RWF",
+        /// Saudi riyal
+        SAR = "SAR",
+        /// Solomon Islands dollar
+        SBD = "SBD",
+        /// Seychelles rupee
+        SCR = "SCR",
+        /// Sudanese pound
+        SDG = "SDG",
+        /// Swedish krona
+        SEK = "SEK",
+        /// Singapore dollar
+        SGD = "SGD",
+        /// Saint Helena pound
+        SHP = "SHP",
+        /// Sierra Leonean leone
+        SLE = "SLE",
+        /// Somali shilling
+        SOS = "SOS",
+        /// Surinamese dollar
+        SRD = "SRD",
+        /// South Sudanese pound
+        SSP = "SSP",
+        /// São Tomé and Príncipe dobra
+        STN = "STN",
+        /// Salvadoran colón
+        SVC = "SVC",
+        /// Syrian pound
+        SYP = "SYP",
+        /// Swazi lilangeni
+        SZL = "SZL",
+        /// Thai baht
+        THB = "THB",
+        /// Tajikistani somoni
+        TJS = "TJS",
+        /// Turkmenistan manat
+        TMT = "TMT",
+        /// Tunisian dinar
+        TND = "TND",
+        /// Tongan paʻanga
+        TOP = "TOP",
+        /// Turkish lira
+        TRY = "TRY",
+        /// Trinidad and Tobago dollar
+        TTD = "TTD",
+        /// New Taiwan dollar, does not support decimals.
+        TWD = "TWD",
+        /// Tanzanian shilling
+        TZS = "TZS",
+        /// Ukrainian hryvnia
+        UAH = "UAH",
+        /// Ugandan shilling
+        UGX = "UGX",
+        /// United States dollar
+        USD = "USD",
+        /// Uruguayan peso
+        UYU = "UYU",
+        /// Uzbekistani sum
+        UZS = "UZS",
+        /// Venezuelan bolívar
+        VES = "VES",
+        /// Vietnamese đồng
+        VND = "VND",
+        /// Vanuatu vatu
+        VUV = "VUV",
+        /// Samoan tala
+        WST = "WST",
+        /// Central African CFA franc
+        XAF = "XAF",
+        /// East Caribbean dollar
+        XCD = "XCD",
+        /// West African CFA franc
+        XOF = "XOF",
+        /// CFP franc
+        XPF = "XPF",
+        /// Yemeni rial
+        YER = "YER",
+        /// South African rand
+        ZAR = "ZAR",
+        /// Zambian kwacha
+        ZMW = "ZMW",
+        /// Zimbabwean dollar
+        ZWL = "ZWL",
+    }
+}
+
+impl Currency {
+    /// The number of decimal places paypal accepts in amounts of this currency, its minor unit exponent.
+    ///
+    /// Currency codes not known by this crate are assumed to have 2.
+    pub fn exponent(&self) -> u32 {
+        match self {
+            // Paypal does not accept decimals for these, even if they have minor units.
+            Currency::HUF | Currency::TWD => 0,
+            Currency::BIF
+            | Currency::CLP
+            | Currency::DJF
+            | Currency::GNF
+            | Currency::ISK
+            | Currency::JPY
+            | Currency::KMF
+            | Currency::KRW
+            | Currency::PYG
+            | Currency::RWF
+            | Currency::UGX
+            | Currency::VND
+            | Currency::VUV
+            | Currency::XAF
+            | Currency::XOF
+            | Currency::XPF => 0,
+            Currency::BHD
+            | Currency::IQD
+            | Currency::JOD
+            | Currency::KWD
+            | Currency::LYD
+            | Currency::OMR
+            | Currency::TND => 3,
+            _ => 2,
+        }
     }
 }
 
 impl FromStr for Currency {
     type Err = InvalidCurrencyError;
 
+    /// Parses a currency code, codes not known by this crate are kept in [Currency::Unknown].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 3 && s.bytes().all(|b| b.is_ascii_uppercase()) {
+            Ok(Currency::from(s))
+        } else {
+            Err(InvalidCurrencyError(s.to_owned()))
+        }
+    }
+}
+
+/// A BCP 47 locale made of a language and an optional region, such as `en-US` or `fr`.
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::data::common::Locale;
+///
+/// let locale: Locale = "pt-BR".parse().unwrap();
+/// assert_eq!(locale.language(), "pt");
+/// assert_eq!(locale.region(), Some("BR"));
+/// assert!("english".parse::<Locale>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Locale {
+    language: String,
+    region: Option<String>,
+}
+
+impl Locale {
+    /// The language, a lowercase ISO 639 code.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The region, an uppercase ISO 3166-1 country code or a UN M.49 area code.
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+}
+
+impl FromStr for Locale {
+    type Err = InvalidLocaleError;
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "AUD" => Ok(Self::AUD),
-            "BRL" => Ok(Self::BRL),
-            "CAD" => Ok(Self::CAD),
-            "CNY" => Ok(Self::CNY),
-            "CZK" => Ok(Self::CZK),
-            "DKK" => Ok(Self::DKK),
-            "EUR" => Ok(Self::EUR),
-            "HKD" => Ok(Self::HKD),
-            "HUF" => Ok(Self::HUF),
-            "INR" => Ok(Self::INR),
-            "ILS" => Ok(Self::ILS),
-            "JPY" => Ok(Self::JPY),
-            "MYR" => Ok(Self::MYR),
-            "MXN" => Ok(Self::MXN),
-            "NOK" => Ok(Self::NOK),
-            "PHP" => Ok(Self::PHP),
-            "PLN" => Ok(Self::PLN),
-            "GBP" => Ok(Self::GBP),
-            "RUB" => Ok(Self::RUB),
-            "SGD" => Ok(Self::SGD),
-            "SEK" => Ok(Self::SGD),
-            "CHF" => Ok(Self::CHF),
-            "THB" => Ok(Self::THB),
-            "USD" => Ok(Self::USD),
-            cur => Err(InvalidCurrencyError(cur.to_owned())),
+        let (language, region) = match s.split_once('-') {
+            Some((language, region)) => (language, Some(region)),
+            None => (s, None),
+        };
+        let valid_language = (2..=3).contains(&language.len()) && language.bytes().all(|b| b.is_ascii_lowercase());
+        let valid_region = region.is_none_or(|region| {
+            (region.len() == 2 && region.bytes().all(|b| b.is_ascii_uppercase()))
+                || (region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit()))
+        });
+        if !valid_language || !valid_region {
+            return Err(InvalidLocaleError(s.to_owned()));
+        }
+        Ok(Locale {
+            language: language.to_owned(),
+            region: region.map(str::to_owned),
+        })
+    }
+}
+
+impl TryFrom<String> for Locale {
+    type Error = InvalidLocaleError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Locale> for String {
+    fn from(locale: Locale) -> Self {
+        locale.to_string()
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.region {
+            Some(region) => write!(f, "{}-{}", self.language, region),
+            None => f.write_str(&self.language),
         }
     }
 }
 
 /// Details about the status of the authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AuthorizationStatusDetails {
     /// The reason why the authorized status is PENDING.
     pub reason: AuthorizationStatusDetailsReason,
 }
 
 /// Authorization status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum AuthorizationStatusDetailsReason {
     /// Authorization is pending manual review.
     PendingReview,
+    /// Risk filter set by the payee failed for the transaction.
+    DeclinedByRiskFraudFilters,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Indicates whether the transaction is eligible for seller protection.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum SellerProtectionStatus {
     /// Your PayPal balance remains intact if the customer claims that they did not receive an item or the account holder claims that they did not authorize the payment.
     Eligible,
@@ -251,16 +957,23 @@ pub enum SellerProtectionStatus {
     PartiallyEligible,
     /// This transaction is not eligible for seller protection.
     NotEligible,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The condition that is covered for the transaction.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum DisputeCategory {
     /// The payer paid for an item that they did not receive.
     ItemNotReceived,
     /// The payer did not authorize the payment.
     UnauthorizedTransaction,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
@@ -268,6 +981,77 @@ pub enum DisputeCategory {
 pub struct SellerProtection {
     /// Indicates whether the transaction is eligible for seller protection.
     pub status: SellerProtectionStatus,
-    /// An array of conditions that are covered for the transaction.
+    /// An array of conditions that are covered for the transaction, empty if paypal omits it.
+    #[serde(default)]
     pub dispute_categories: Vec<DisputeCategory>,
 }
+
+/// The operation to complete in a JSON patch request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PatchOp {
+    /// Adds a value at the target location.
+    Add,
+    /// Removes the value at the target location.
+    Remove,
+    /// Replaces the value at the target location.
+    Replace,
+    /// Removes the value at the `from` location and adds it to the target location.
+    Move,
+    /// Copies the value at the `from` location to the target location.
+    Copy,
+    /// Tests that the value at the target location is equal to the given value.
+    Test,
+}
+
+/// A JSON patch operation, as described in [RFC 6902](https://tools.ietf.org/html/rfc6902).
+///
+/// Used by the endpoints that support partial updates.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Patch {
+    /// The operation.
+    pub op: PatchOp,
+    /// The JSON pointer to the target document location at which to complete the operation.
+    #[builder(default)]
+    pub path: Option<String>,
+    /// The value to apply. The remove operation does not require a value.
+    #[builder(default)]
+    pub value: Option<serde_json::Value>,
+    /// The JSON pointer to the target document location from which to move the value. Required for the move operation.
+    #[builder(default)]
+    pub from: Option<String>,
+}
+
+impl Patch {
+    /// Creates an add operation.
+    pub fn add(path: impl ToString, value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            op: PatchOp::Add,
+            path: Some(path.to_string()),
+            value: Some(value.into()),
+            from: None,
+        }
+    }
+
+    /// Creates a replace operation.
+    pub fn replace(path: impl ToString, value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            op: PatchOp::Replace,
+            path: Some(path.to_string()),
+            value: Some(value.into()),
+            from: None,
+        }
+    }
+
+    /// Creates a remove operation.
+    pub fn remove(path: impl ToString) -> Self {
+        Self {
+            op: PatchOp::Remove,
+            path: Some(path.to_string()),
+            value: None,
+            from: None,
+        }
+    }
+}
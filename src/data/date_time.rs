@@ -0,0 +1,92 @@
+//! Serde helpers for the date and date time formats paypal uses, to be used with [serde_as](serde_with::serde_as).
+//!
+//! Paypal expects [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6) date times with the seconds and at most
+//! the milliseconds, and sends them in UTC or with a zone offset. Plain dates are sent as `YYYY-MM-DD`.
+//!
+//! # Examples
+//!
+//! ```
+//! use paypal_rs::data::date_time::{Date, Rfc3339};
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde_as(as = "Rfc3339")]
+//!     time: chrono::DateTime<chrono::Utc>,
+//!     #[serde_as(as = "Option<Date>")]
+//!     date: Option<chrono::NaiveDate>,
+//! }
+//!
+//! let event: Event = serde_json::from_str(r#"{"time": "2024-03-01T14:30:00.123456-07:00"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&event).unwrap(),
+//!     r#"{"time":"2024-03-01T21:30:00.123Z","date":null}"#
+//! );
+//! ```
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use serde::{de, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// A date time in the RFC 3339 format paypal accepts.
+///
+/// Serialized in UTC with the seconds, and the milliseconds only if there are any. Deserializes any RFC 3339 date time,
+/// with a `Z` or a zone offset, which may lack its colon.
+#[derive(Debug, Clone, Copy)]
+pub struct Rfc3339;
+
+impl SerializeAs<DateTime<Utc>> for Rfc3339 {
+    fn serialize_as<S: Serializer>(source: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        let format = if source.timestamp_subsec_millis() == 0 {
+            SecondsFormat::Secs
+        } else {
+            SecondsFormat::Millis
+        };
+        serializer.serialize_str(&source.to_rfc3339_opts(format, true))
+    }
+}
+
+impl<'de> DeserializeAs<'de, DateTime<Utc>> for Rfc3339 {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_date_time(&value).ok_or_else(|| de::Error::custom(format!("{:?} is not a RFC 3339 date time", value)))
+    }
+}
+
+/// A date without time, serialized as `YYYY-MM-DD`.
+///
+/// Also deserializes the dates paypal sends followed by a time zone name, such as `2024-03-01 PST`, and date times,
+/// keeping the date in their own offset.
+#[derive(Debug, Clone, Copy)]
+pub struct Date;
+
+impl SerializeAs<NaiveDate> for Date {
+    fn serialize_as<S: Serializer>(source: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&source.format("%Y-%m-%d"))
+    }
+}
+
+impl<'de> DeserializeAs<'de, NaiveDate> for Date {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        parse_date(&value).ok_or_else(|| de::Error::custom(format!("{:?} is not a date", value)))
+    }
+}
+
+fn parse_date_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        // The reporting apis send the offset without a colon, as in `+0000`.
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|date_time| date_time.with_timezone(&Utc))
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Some(date_time.date_naive());
+    }
+    let date = value.split_once(' ').map_or(value, |(date, _zone)| date);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
@@ -0,0 +1,51 @@
+//! Paypal object definitions used by the identity api.
+
+use std::fmt;
+
+/// A scope requested during the Log in with PayPal consent flow.
+///
+/// <https://developer.paypal.com/docs/log-in-with-paypal/integrate/reference/#scope-attributes>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Required to use Log in with PayPal, returns the user id.
+    OpenId,
+    /// The user's full name.
+    Profile,
+    /// The user's email address.
+    Email,
+    /// The user's street address.
+    Address,
+    /// The user's phone number.
+    Phone,
+    /// Additional account information such as the account verification status.
+    PaypalAttributes,
+    /// Allows the user to complete a checkout with their PayPal account.
+    ExpressCheckout,
+    /// Allows creating and managing invoices on behalf of the user.
+    Invoicing,
+    /// Any other scope, given as is.
+    Custom(String),
+}
+
+impl Scope {
+    /// Returns the scope value expected by paypal.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scope::OpenId => "openid",
+            Scope::Profile => "profile",
+            Scope::Email => "email",
+            Scope::Address => "address",
+            Scope::Phone => "phone",
+            Scope::PaypalAttributes => "https://uri.paypal.com/services/paypalattributes",
+            Scope::ExpressCheckout => "https://uri.paypal.com/services/expresscheckout",
+            Scope::Invoicing => "https://uri.paypal.com/services/invoicing",
+            Scope::Custom(scope) => scope.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1,12 +1,19 @@
 //! Paypal object definitions used in the invoice api.
 
-use crate::{data::common::LinkDescription, data::common::*};
+use crate::{
+    data::common::LinkDescription,
+    data::common::*,
+    data::date_time::{Date, Rfc3339},
+    data::ids::InvoiceId,
+};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{serde_as, skip_serializing_none};
 
 /// Paypal File reference
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct FileReference {
     /// The ID of the referenced file.
     pub id: String,
@@ -15,6 +22,7 @@ pub struct FileReference {
     /// Content type
     pub content_type: String,
     /// The date and time when the file was created
+    #[serde_as(as = "Rfc3339")]
     pub create_time: chrono::DateTime<chrono::Utc>,
     /// The size of the file, in bytes.
     pub size: String,
@@ -23,33 +31,47 @@ pub struct FileReference {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// The payment term type.
+#[non_exhaustive]
 pub enum PaymentTermType {
     /// The payment for the invoice is due upon receipt of the invoice.
     DueOnReceipt,
     /// The payment for the invoice is due on the date specified in the invoice.
     DueOnDateSpecified,
     /// The payment for the invoice is due in 10 days.
+    #[serde(rename = "NET_10")]
     Net10,
     /// The payment for the invoice is due in 15 days.
+    #[serde(rename = "NET_15")]
     Net15,
     /// The payment for the invoice is due in 30 days.
+    #[serde(rename = "NET_30")]
     Net30,
     /// The payment for the invoice is due in 45 days.
+    #[serde(rename = "NET_45")]
     Net45,
     /// The payment for the invoice is due in 60 days.
+    #[serde(rename = "NET_60")]
     Net60,
     /// The payment for the invoice is due in 90 days.
+    #[serde(rename = "NET_90")]
     Net90,
     /// The invoice has no payment due date.
     NoDueDate,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The payment due date for the invoice.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct PaymentTerm {
     /// The payment term. Payment can be due upon receipt, a specified date, or in a set number of days
     pub term_type: PaymentTermType,
     /// The date when the invoice payment is due,
+    #[builder(default)]
+    #[serde_as(as = "Option<Date>")]
     pub due_date: Option<chrono::NaiveDate>,
 }
 
@@ -57,6 +79,7 @@ pub struct PaymentTerm {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// The flow variation
+#[non_exhaustive]
 pub enum FlowType {
     /// The invoice sent to multiple recipients.
     MultipleRecipientsGroup,
@@ -64,27 +87,36 @@ pub enum FlowType {
     Batch,
     /// The regular invoice sent to single recipient.
     RegularSingle,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Metadata about a resource
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
 pub struct Metadata {
     /// The date and time when the resource was created
+    #[serde_as(as = "Option<Rfc3339>")]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The email address of the account that created the resource.
     pub created_by: Option<String>,
     /// The date and time when the resource was last edited
+    #[serde_as(as = "Option<Rfc3339>")]
     pub last_update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The email address of the account that last edited the resource.
-    pub last_updated_by: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_updated_by: Option<String>,
     /// The date and time when the resource was canceled
+    #[serde_as(as = "Option<Rfc3339>")]
     pub cancel_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The actor who canceled the resource.
-    pub cancelled_by: Option<chrono::DateTime<chrono::Utc>>,
+    pub cancelled_by: Option<String>,
     /// The date and time when the resource was first sent
+    #[serde_as(as = "Option<Rfc3339>")]
     pub first_sent_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The date and time when the resource was last sent
+    #[serde_as(as = "Option<Rfc3339>")]
     pub last_sent_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The email address of the account that last sent the resource.
     pub last_sent_by: Option<String>,
@@ -97,8 +129,9 @@ pub struct Metadata {
 }
 
 /// The details of the invoice. Includes the invoice number, date, payment terms, and audit metadata.
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option), default)]
 pub struct InvoiceDetail {
     /// The reference data. Includes a post office (PO) number.
@@ -109,6 +142,8 @@ pub struct InvoiceDetail {
     pub note: Option<String>,
     /// The general terms of the invoice. Can include return or cancellation policy and other terms and conditions.
     pub terms_and_conditions: Option<String>,
+    /// The terms shown on the invoice, such as the refund policy.
+    pub term: Option<String>,
     /// A private bookkeeping memo for the user.
     pub memo: Option<String>,
     /// An array of PayPal IDs for the files that are attached to an invoice.
@@ -116,6 +151,7 @@ pub struct InvoiceDetail {
     /// The invoice number. Default is the number that is auto-incremented number from the last number.
     pub invoice_number: Option<String>,
     /// The invoice date as specificed by the sender
+    #[serde_as(as = "Option<Date>")]
     pub invoice_date: Option<chrono::NaiveDate>,
     /// The payment due date for the invoice.
     pub payment_term: Option<PaymentTerm>,
@@ -123,9 +159,17 @@ pub struct InvoiceDetail {
     pub metadata: Option<Metadata>,
 }
 
+impl InvoiceDetail {
+    /// Adds an uploaded file to the invoice attachments.
+    pub fn add_attachment(&mut self, file: FileReference) -> &mut Self {
+        self.attachments.get_or_insert_with(Vec::new).push(file);
+        self
+    }
+}
+
 /// A name to be used as recipient, etc.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Name {
     /// The prefix, or title, to the party's name.
@@ -149,7 +193,8 @@ pub struct Name {
 
 /// Phone information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct PhoneDetail {
     /// The country calling code (CC), in its canonical international E.164 numbering plan format.
     pub country_code: String,
@@ -163,7 +208,7 @@ pub struct PhoneDetail {
 
 /// The invoicer information.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option), default)]
 pub struct InvoicerInfo {
     /// Required. The business name of the party.
@@ -187,7 +232,8 @@ pub struct InvoicerInfo {
 
 /// Billing information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct BillingInfo {
     /// Required. The business name of the party.
     pub business_name: String,
@@ -202,24 +248,28 @@ pub struct BillingInfo {
     /// Any additional information about the recipient. Maximum length: 40.
     pub additional_info: Option<String>,
     /// The language in which to show the invoice recipient's email message. Used only when the recipient does not have a PayPal account
-    pub language: Option<String>,
+    pub language: Option<Locale>,
 }
 
 /// Contact information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct ContactInformation {
     /// Required. The business name of the party.
     pub business_name: String,
     /// The first and Last name of the recipient.
+    #[builder(default)]
     pub name: Option<Name>,
     /// The address of the recipient.
+    #[builder(default)]
     pub address: Option<Address>,
 }
 
 /// Recipient information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct RecipientInfo {
     /// The billing information for the invoice recipient. Includes name, address, email, phone, and language.
     pub billing_info: Option<BillingInfo>,
@@ -229,18 +279,20 @@ pub struct RecipientInfo {
 
 /// Tax information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct Tax {
     /// The name of the tax applied on the invoice items.
     pub name: String,
     /// The tax rate. Value is from 0 to 100. Supports up to five decimal places.
     pub percent: String,
     /// The calculated tax amount. The tax amount is added to the item total.
+    #[builder(default)]
     pub amount: Option<Money>,
 }
 
 /// Discount information
-#[derive(Debug, Serialize, Deserialize, Clone, Builder, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder, Default)]
 #[builder(setter(strip_option, into), default)]
 pub struct Discount {
     /// The discount as a percentage value. Value is from 0 to 100. Supports up to five decimal places.
@@ -252,6 +304,7 @@ pub struct Discount {
 /// The unit of measure for the invoiced item.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum UnitOfMeasure {
     /// The unit of measure is quantity. This invoice template is typically used for physical goods.
     Quantity,
@@ -259,11 +312,15 @@ pub enum UnitOfMeasure {
     Hours,
     /// The unit of measure is amount. This invoice template is typically used when only amount is required.
     Amount,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Item information
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Item {
     /// The ID of the invoice line item.
@@ -284,6 +341,7 @@ pub struct Item {
     pub tax: Option<Tax>,
     /// The date when the item or service was provided, in Internet date and time format.
     #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
     pub item_date: Option<chrono::DateTime<chrono::Utc>>,
     /// Discount as a percent or amount at invoice level. The invoice discount amount is subtracted from the item total.
     #[builder(default)]
@@ -295,7 +353,7 @@ pub struct Item {
 
 /// The partial payment details.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct PartialPayment {
     /// Indicates whether the invoice allows a partial payment. If false, the invoice must be paid in full. If true, the invoice allows partial payments.
@@ -306,7 +364,7 @@ pub struct PartialPayment {
 
 /// The invoice configuration details. Includes partial payment, tip, and tax calculated after discount.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Configuration {
     /// Indicates whether the tax is calculated before or after a discount. If false, the tax is calculated before a discount. If true, the tax is calculated after a discount.
@@ -324,7 +382,7 @@ pub struct Configuration {
 
 /// The discount
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct AggregatedDiscount {
     /// The discount as a percent or amount at invoice level. The invoice discount amount is subtracted from the item total.
@@ -334,7 +392,7 @@ pub struct AggregatedDiscount {
 }
 
 /// The shipping fee
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct ShippingCost {
     /// The shipping amount. Value is from 0 to 1000000. Supports up to two decimal places.
@@ -345,7 +403,7 @@ pub struct ShippingCost {
 
 /// The custom amount to apply to an invoice
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct CustomAmount {
     /// The label to the custom amount of the invoice.
@@ -357,7 +415,7 @@ pub struct CustomAmount {
 
 /// The breakdown of the amount. Breakdown provides details such as total item amount, total tax amount, custom amount, shipping and discounts, if any.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Breakdown {
     /// The subtotal for all items. Must equal the sum of (items[].unit_amount * items[].quantity) for all items.
@@ -374,7 +432,7 @@ pub struct Breakdown {
 
 /// Represents an amount of money.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Amount {
     /// The [three-character ISO-4217 currency code](https://developer.paypal.com/docs/integration/direct/rest/currency-codes/) that identifies the currency.
@@ -402,18 +460,23 @@ impl Amount {
 }
 
 /// The payment type in an invoicing flow
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum PaymentType {
     /// The payment type is PayPal.
     Paypal,
     /// The payment type is an external cash or a check payment.
     External,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The payment mode or method through which the invoicer can accept the payment.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum PaymentMethod {
     /// Payments can be received through bank transfers.
     BankTransfer,
@@ -426,22 +489,21 @@ pub enum PaymentMethod {
     /// Payments can be received through debit card payments.
     DebitCard,
     /// Payments can be received through paypal payments.
+    #[default]
     Paypal,
     /// Payments can be received through wire transfer.
     WireTransfer,
     /// Payments can be received through other modes.
     Other,
-}
-
-impl Default for PaymentMethod {
-    fn default() -> Self {
-        PaymentMethod::Paypal
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Payment detail
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct PaymentDetail {
     /// The payment type in an invoicing flow which can be PayPal or an external cash or check payment.
@@ -452,6 +514,7 @@ pub struct PaymentDetail {
     pub payment_id: Option<String>,
     /// The date when the invoice was paid, in Internet date and time format.
     #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
     pub payment_date: Option<chrono::DateTime<chrono::Utc>>,
     /// The payment mode or method through which the invoicer can accept the payment.
     pub method: PaymentMethod,
@@ -468,7 +531,7 @@ pub struct PaymentDetail {
 
 /// Payments registered against the invoice
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Payments {
     /// The aggregated payment amounts against this invoice.
@@ -480,8 +543,9 @@ pub struct Payments {
 }
 
 /// Refund details
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct RefundDetail {
     /// The PayPal refund type. Indicates whether the refund was paid through PayPal or externally in the invoicing flow.
@@ -492,6 +556,7 @@ pub struct RefundDetail {
     pub refund_id: Option<String>,
     /// The date when the invoice was refunded, in Internet date format.
     #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
     pub refund_date: Option<chrono::DateTime<chrono::Utc>>,
     /// The amount to record as refunded. If you omit the amount, the total invoice paid amount is recorded as refunded.
     #[builder(default)]
@@ -500,9 +565,16 @@ pub struct RefundDetail {
     pub method: PaymentMethod,
 }
 
+/// The response of recording an external refund.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordRefundResponse {
+    /// The ID for the invoice refund.
+    pub refund_id: String,
+}
+
 /// List of refunds
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct Refunds {
     /// The aggregated refund amounts.
@@ -514,8 +586,9 @@ pub struct Refunds {
 }
 
 /// The status of the invoice
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum Status {
     ///  The invoice is in draft state. It is not yet sent to the payer.
     Draft,
@@ -541,11 +614,14 @@ pub enum Status {
     Unpaid,
     /// The invoicer is yet to receive the payment for the invoice. It is under pending review.
     PaymentPending,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An invoice payload
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option), default)]
 pub struct InvoicePayload {
     /// The details of the invoice. Includes the invoice number, date, payment terms, and audit metadata.
@@ -571,11 +647,11 @@ pub struct InvoicePayload {
 
 /// Definition: <https://developer.paypal.com/docs/api/invoicing/v2/#invoices_get>
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Invoice {
     /// The ID of the invoice.
-    pub id: String,
+    pub id: InvoiceId,
     /// The parent ID to an invoice that defines the group invoice to which the invoice is related.
     #[builder(default)]
     pub parent_id: Option<String>,
@@ -619,22 +695,101 @@ pub struct Invoice {
 }
 
 /// A invoice list
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(into))]
 pub struct InvoiceList {
     /// Total items
+    #[serde(default)]
     pub total_items: i32,
     /// Total pages
+    #[serde(default)]
     pub total_pages: i32,
     /// The invoices
+    #[serde(default)]
     pub items: Vec<Invoice>,
     /// HATEOAS links
+    #[serde(default)]
     pub links: Vec<LinkDescription>,
 }
 
+/// A range of amounts, used to filter invoices by their total amount.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct AmountRange {
+    /// The lower limit of the amount range.
+    pub lower_amount: Money,
+    /// The upper limit of the amount range.
+    pub upper_amount: Money,
+}
+
+/// A range of dates, used to filter invoices by their invoice or due date.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct DateRange {
+    /// The start date of the range.
+    #[serde_as(as = "Date")]
+    pub start: chrono::NaiveDate,
+    /// The end date of the range.
+    #[serde_as(as = "Date")]
+    pub end: chrono::NaiveDate,
+}
+
+/// A range of date and times, used to filter invoices by their payment or creation time.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct DateTimeRange {
+    /// The start date and time of the range.
+    #[serde_as(as = "Rfc3339")]
+    pub start: chrono::DateTime<chrono::Utc>,
+    /// The end date and time of the range.
+    #[serde_as(as = "Rfc3339")]
+    pub end: chrono::DateTime<chrono::Utc>,
+}
+
+/// The invoice search filters. Only invoices matching all the given filters are returned.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SearchInvoicesPayload {
+    /// Filters the search by the email address.
+    pub recipient_email: Option<String>,
+    /// Filters the search by the recipient first name.
+    pub recipient_first_name: Option<String>,
+    /// Filters the search by the recipient last name.
+    pub recipient_last_name: Option<String>,
+    /// Filters the search by the recipient business name.
+    pub recipient_business_name: Option<String>,
+    /// Filters the search by the invoice number.
+    pub invoice_number: Option<String>,
+    /// An array of status values.
+    pub status: Option<Vec<Status>>,
+    /// The reference data, such as a PO number.
+    pub reference: Option<String>,
+    /// The three-character ISO-4217 currency code that identifies the currency.
+    pub currency_code: Option<Currency>,
+    /// A private bookkeeping memo for the user.
+    pub memo: Option<String>,
+    /// Filters the search by the total amount.
+    pub total_amount_range: Option<AmountRange>,
+    /// Filters the search by a date range for the invoice.
+    pub invoice_date_range: Option<DateRange>,
+    /// Filters the search by a due date range for the invoice.
+    pub due_date_range: Option<DateRange>,
+    /// Filters the search by a payment date range for the invoice.
+    pub payment_date_range: Option<DateTimeRange>,
+    /// Filters the search by a creation date range for the invoice.
+    pub creation_date_range: Option<DateTimeRange>,
+    /// Indicates whether to list merchant-archived invoices in the response.
+    pub archived: Option<bool>,
+    /// A list of fields to include in the response.
+    pub fields: Option<Vec<String>>,
+}
+
 /// Cancel invoice reason
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct CancelReason {
     /// The subject of the email that is sent as a notification to the recipient.
@@ -656,7 +811,9 @@ pub const QR_ACTION_PAY: &str = "pay";
 pub const QR_ACTION_DETAILS: &str = "details";
 
 /// QR creation parameters
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct QRCodeParams {
     /// The width, in pixels, of the QR code image. Value is from 150 to 500.
     pub width: i32,
@@ -668,37 +825,99 @@ pub struct QRCodeParams {
     pub action: Option<String>,
 }
 
+/// A generated QR code.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct QrCode {
+    /// The QR code as a PNG image.
+    pub image: Vec<u8>,
+}
+
+impl QrCode {
+    /// Parses the QR code from the generate QR code response body.
+    ///
+    /// Paypal returns the PNG image base64 encoded, sometimes wrapped in a multipart body.
+    pub fn from_response(body: &[u8]) -> Result<Self, base64::DecodeError> {
+        use base64::Engine;
+
+        let text = String::from_utf8_lossy(body);
+        let mut encoded = text.trim();
+
+        if let Some(boundary) = encoded.lines().next().filter(|line| line.starts_with("--")) {
+            let boundary = boundary.trim();
+            // The part content starts after the part headers and ends at the next boundary.
+            let part = encoded[boundary.len()..].split(boundary).next().unwrap_or_default();
+            encoded = part
+                .split_once("\r\n\r\n")
+                .or_else(|| part.split_once("\n\n"))
+                .map(|(_, content)| content)
+                .unwrap_or(part)
+                .trim();
+        }
+
+        let encoded: String = encoded.chars().filter(|c| !c.is_whitespace()).collect();
+        let image = base64::engine::general_purpose::STANDARD.decode(encoded)?;
+        Ok(Self { image })
+    }
+}
+
 /// Used to record a payment.
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct RecordPaymentPayload {
-    /// The payment id.
+    /// The ID for a PayPal payment transaction. Required for the PAYPAL payment type.
     pub payment_id: Option<String>,
-    /// The payment date
+    /// The date when the invoice was paid.
+    #[serde_as(as = "Option<Rfc3339>")]
     pub payment_date: Option<chrono::DateTime<chrono::Utc>>,
-    /// The payment method.
+    /// The payment mode or method through which the invoicer can accept the payment.
     pub method: PaymentMethod,
-    /// A note.
+    /// A note associated with an external cash or check payment.
     pub note: Option<String>,
-    /// The amount.
-    pub amount: Amount,
-    /// The shipping info.
+    /// The payment amount to record against the invoice. If you omit this parameter, the total invoice amount is marked as paid.
+    pub amount: Option<Money>,
+    /// The recipient's shipping information. Includes the user's contact information, which includes name and address.
     pub shipping_info: Option<ContactInformation>,
 }
 
+/// The response of recording an external payment.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordPaymentResponse {
+    /// The ID for the invoice payment.
+    pub payment_id: String,
+}
+
+/// An invoice number.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InvoiceNumber {
+    /// The invoice number.
+    pub invoice_number: String,
+}
+
 /// Send Invoice Payload
+///
+/// Also used as the notification when sending an invoice reminder.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Builder, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Builder, Clone, PartialEq)]
 pub struct SendInvoicePayload {
     /// An array of one or more CC: emails to which notifications are sent.
     /// If you omit this parameter, a notification is sent to all CC: email addresses that are part of the invoice.
+    #[builder(default)]
     pub additional_recipients: Option<Vec<String>>,
     /// A note to the payer.
+    #[builder(default)]
     pub note: Option<String>,
     /// Indicates whether to send a copy of the email to the merchant.
+    #[builder(default)]
     pub send_to_invoicer: Option<bool>,
     /// Indicates whether to send a copy of the email to the recipient.
+    #[builder(default)]
     pub send_to_recipient: Option<bool>,
     /// The subject of the email that is sent as a notification to the recipient.
+    #[builder(default)]
     pub subject: Option<String>,
 }
+
+impl_links!(InvoiceList);
+impl_links!(optional Invoice);
@@ -0,0 +1,160 @@
+//! Paypal object definitions used in the invoice templates api.
+
+use crate::data::{
+    common::{Address, LinkDescription, Money},
+    invoice::{Amount, Configuration, InvoiceDetail, InvoicerInfo, Item, RecipientInfo, UnitOfMeasure},
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The template details. Includes invoicer business information, invoice recipients, items, and configuration.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct TemplateInfo {
+    /// The details of the invoice created from this template. Includes the currency, note, terms and memo.
+    pub detail: Option<InvoiceDetail>,
+    /// The invoicer business information that appears on the invoice.
+    pub invoicer: Option<InvoicerInfo>,
+    /// The billing and shipping information. Includes name, email, address, phone and language.
+    pub primary_recipients: Option<Vec<RecipientInfo>>,
+    /// An array of one or more CC: emails to which notifications are sent.
+    pub additional_recipients: Option<Vec<String>>,
+    /// An array of invoice line item information.
+    pub items: Option<Vec<Item>>,
+    /// The invoice configuration details. Includes partial payment, tip, and tax calculated after discount.
+    pub configuration: Option<Configuration>,
+    /// The invoice amount summary of item total, discount, tax total and shipping.
+    pub amount: Option<Amount>,
+    /// The amount due for the invoice.
+    pub due_amount: Option<Money>,
+}
+
+/// The display preference of a template field.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Builder)]
+#[builder(default)]
+pub struct TemplateDisplayPreference {
+    /// Indicates whether to show or hide this field.
+    pub hidden: bool,
+}
+
+impl TemplateDisplayPreference {
+    /// Shows the field.
+    pub fn shown() -> Self {
+        Self { hidden: false }
+    }
+
+    /// Hides the field.
+    pub fn hidden() -> Self {
+        Self { hidden: true }
+    }
+}
+
+/// The template setting of an item field.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct TemplateItemSetting {
+    /// The field name in `items` for which to set the display preference, for example `items.date`.
+    pub field_name: String,
+    /// The display preference.
+    pub display_preference: Option<TemplateDisplayPreference>,
+}
+
+impl TemplateItemSetting {
+    /// Creates a setting for the given item field with the given visibility.
+    pub fn new(field_name: impl ToString, hidden: bool) -> Self {
+        Self {
+            field_name: field_name.to_string(),
+            display_preference: Some(TemplateDisplayPreference { hidden }),
+        }
+    }
+}
+
+/// The template setting of a subtotal field.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct TemplateSubtotalSetting {
+    /// The field name in `amount` for which to set the display preference, for example `discount`.
+    pub field_name: String,
+    /// The display preference.
+    pub display_preference: Option<TemplateDisplayPreference>,
+}
+
+impl TemplateSubtotalSetting {
+    /// Creates a setting for the given subtotal field with the given visibility.
+    pub fn new(field_name: impl ToString, hidden: bool) -> Self {
+        Self {
+            field_name: field_name.to_string(),
+            display_preference: Some(TemplateDisplayPreference { hidden }),
+        }
+    }
+}
+
+/// The template settings. Sets a template as the default template or edit template.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct TemplateSettings {
+    /// The template item headers display preference.
+    pub template_item_settings: Option<Vec<TemplateItemSetting>>,
+    /// The template subtotal headers display preference.
+    pub template_subtotal_settings: Option<Vec<TemplateSubtotalSetting>>,
+}
+
+/// An invoice template. The template determines the layout of an invoice.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct Template {
+    /// The ID of the template.
+    pub id: Option<String>,
+    /// The template name.
+    pub name: String,
+    /// Indicates whether this template is the default template. A invoicer can have one default template.
+    pub default_template: Option<bool>,
+    /// The template details. Includes invoicer business information, invoice recipients, items, and configuration.
+    pub template_info: Option<TemplateInfo>,
+    /// The template settings. Describes which fields to show or hide when you create an invoice.
+    pub settings: Option<TemplateSettings>,
+    /// The unit of measure for the template.
+    pub unit_of_measure: Option<UnitOfMeasure>,
+    /// Indicates whether this template is a invoicer-created custom template. The system generates non-custom templates.
+    pub standard_template: Option<bool>,
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl Template {
+    /// Returns the invoice configuration of this template, referencing it by `template_id`.
+    ///
+    /// Use it as the [InvoicePayload](crate::data::invoice::InvoicePayload) configuration to create an invoice with this template layout.
+    pub fn configuration(&self) -> Configuration {
+        let mut configuration = self
+            .template_info
+            .as_ref()
+            .and_then(|info| info.configuration.clone())
+            .unwrap_or_default();
+        configuration.template_id = self.id.clone();
+        configuration
+    }
+}
+
+/// A list of invoice templates.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TemplateList {
+    /// The invoicer's addresses.
+    pub addresses: Option<Vec<Address>>,
+    /// The invoicer's email addresses.
+    pub emails: Option<Vec<String>>,
+    /// An array of details for each template.
+    #[serde(default)]
+    pub templates: Vec<Template>,
+    /// An array of request-related HATEOAS links.
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+impl_links!(optional Template, TemplateList);
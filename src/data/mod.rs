@@ -1,6 +1,19 @@
 //! This module contains the data structures used in the api endpoints.
 
+pub mod billing_agreements;
+pub mod catalog_products;
 pub mod common;
+pub mod date_time;
+pub mod identity;
+pub mod ids;
 pub mod invoice;
+pub mod invoice_template;
 pub mod orders;
+pub mod partner_referrals;
 pub mod payment;
+pub mod payouts;
+pub mod subscriptions;
+pub mod transactions;
+pub mod vault;
+pub mod web_profiles;
+pub mod webhooks;
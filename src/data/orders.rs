@@ -1,15 +1,20 @@
 //! Paypal object definitions used by the orders api.
 
 use super::common::*;
+use super::date_time::Rfc3339;
+use super::ids::{AuthorizationId, BillingAgreementId, CaptureId, OrderId, RefundId};
+use crate::errors::MoneyError;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{serde_as, skip_serializing_none};
 
 /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum Intent {
     /// The merchant intends to capture payment immediately after the customer makes a payment.
+    #[default]
     Capture,
     /// The merchant intends to authorize a payment and place funds on hold after the customer makes a payment.
     /// Authorized payments are guaranteed for up to three days but are available to capture for up to 29 days.
@@ -17,18 +22,16 @@ pub enum Intent {
     /// You must make a separate request to capture payments on demand.
     /// This intent is not supported when you have more than one `purchase_unit` within your order.
     Authorize,
-}
-
-impl Default for Intent {
-    fn default() -> Self {
-        Intent::Capture
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Represents a payer name.
 ///
 /// <https://developer.paypal.com/docs/api/orders/v2/#definition-payer.name>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct PayerName {
     /// When the party is a person, the party's given, or first, name.
     pub given_name: String,
@@ -50,28 +53,33 @@ pub struct PhoneNumber {
 /// The phone number of the customer. Available only when you enable the
 /// Contact Telephone Number option in the Profile & Settings for the merchant's PayPal account.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct Phone {
     /// The phone type.
+    #[builder(default)]
     pub phone_type: Option<PhoneType>,
     /// The phone number
     pub phone_number: PhoneNumber,
 }
 
 /// The customer's tax ID type. Supported for the PayPal payment method only.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(non_camel_case_types)]
+#[non_exhaustive]
 pub enum TaxIdType {
     /// The individual tax ID type.
     BR_CPF,
     /// The business tax ID type.
     BR_CNPJ,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The tax information of the payer.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct TaxInfo {
     /// The customer's tax ID. Supported for the PayPal payment method only.
@@ -85,7 +93,7 @@ pub struct TaxInfo {
 ///
 /// <https://developer.paypal.com/docs/api/orders/v2/#definition-payer>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option), default)]
 pub struct Payer {
     /// The name of the payer.
@@ -107,29 +115,36 @@ pub struct Payer {
 
 /// Breakdown provides details such as total item amount, total tax amount, shipping, handling, insurance, and discounts, if any.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Breakdown {
     /// The subtotal for all items. Required if the request includes purchase_units[].items[].unit_amount.
     /// Must equal the sum of (items[].unit_amount * items[].quantity) for all items.
+    #[builder(default)]
     pub item_total: Option<Money>,
     /// The shipping fee for all items within a given purchase_unit.
+    #[builder(default)]
     pub shipping: Option<Money>,
     /// The handling fee for all items within a given purchase_unit.
+    #[builder(default)]
     pub handling: Option<Money>,
     /// The total tax for all items. Required if the request includes purchase_units.items.tax. Must equal the sum of (items[].tax * items[].quantity) for all items.
+    #[builder(default)]
     pub tax_total: Option<Money>,
     /// The insurance fee for all items within a given purchase_unit.
+    #[builder(default)]
     pub insurance: Option<Money>,
     /// The shipping discount for all items within a given purchase_unit.
+    #[builder(default)]
     pub shipping_discount: Option<Money>,
     /// The discount for all items within a given purchase_unit.
+    #[builder(default)]
     pub discount: Option<Money>,
 }
 
 /// Represents an amount of money.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct Amount {
     /// The [three-character ISO-4217 currency code](https://developer.paypal.com/docs/integration/direct/rest/currency-codes/) that identifies the currency.
@@ -141,6 +156,7 @@ pub struct Amount {
     /// For the required number of decimal places for a currency code, see [Currency Codes](https://developer.paypal.com/docs/api/reference/currency-codes/).
     pub value: String,
     /// The breakdown of the amount.
+    #[builder(default)]
     pub breakdown: Option<Breakdown>,
 }
 
@@ -175,79 +191,83 @@ impl Amount {
 
 /// The merchant who receives payment for this transaction.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Payee {
     /// The email address of merchant.
+    #[builder(default)]
     pub email_address: Option<String>,
     /// The encrypted PayPal account ID of the merchant.
+    #[builder(default)]
     pub merchant_id: Option<String>,
 }
 
 /// Fees, commissions, tips, or donations
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct PlatformFee {
     /// The fee for this transaction.
     pub amount: Money,
 
     /// The merchant who receives payment for this transaction.
+    #[builder(default)]
     pub payee: Option<Payee>,
 }
 
 /// The funds that are held on behalf of the merchant
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum DisbursementMode {
     /// The funds are released to the merchant immediately.
+    #[default]
     Instant,
     /// The funds are held for a finite number of days. The actual duration depends on the region and type of integration.
     /// You can release the funds through a referenced payout.
     /// Otherwise, the funds disbursed automatically after the specified duration.
     Delayed,
-}
-
-impl Default for DisbursementMode {
-    fn default() -> Self {
-        DisbursementMode::Instant
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Any additional payment instructions for PayPal Commerce Platform customers.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct PaymentInstruction {
     /// An array of various fees, commissions, tips, or donations.
+    #[builder(default)]
     pub platform_fees: Option<Vec<PlatformFee>>,
     /// The funds that are held on behalf of the merchant.
+    #[builder(default)]
     pub disbursement_mode: Option<DisbursementMode>,
 }
 
 /// The item category type.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum ItemCategoryType {
     /// Goods that are stored, delivered, and used in their electronic format.
     /// This value is not currently supported for API callers that leverage
     /// the [PayPal for Commerce Platform](https://www.paypal.com/us/webapps/mpp/commerce-platform) product.
+    #[default]
     DigitalGoods,
     /// A tangible item that can be shipped with proof of delivery.
     PhysicalGoods,
-
     /// A contribution or gift for which no good or service is exchanged, usually to a not for profit organization.
     Donation,
-}
-
-impl Default for ItemCategoryType {
-    fn default() -> Self {
-        ItemCategoryType::DigitalGoods
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The name of the person to whom to ship the items.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct ShippingDetailName {
     /// The name of the person to whom to ship the items. Supports only the full_name property.
     pub full_name: String,
@@ -255,18 +275,20 @@ pub struct ShippingDetailName {
 
 /// The name and address of the person to whom to ship the items.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct ShippingDetail {
     /// The name of the person to whom to ship the items. Supports only the full_name property.
+    #[builder(default)]
     pub name: Option<ShippingDetailName>,
     /// The address of the person to whom to ship the items.
+    #[builder(default)]
     pub address: Option<Address>,
 }
 
 /// Represents an item.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into))]
 pub struct Item {
     /// The item name or title.
@@ -275,20 +297,25 @@ pub struct Item {
     /// If you specify unit_amount, purchase_units[].amount.breakdown.item_total is required. Must equal unit_amount * quantity for all items.
     pub unit_amount: Money,
     /// The item tax for each unit. If tax is specified, purchase_units[].amount.breakdown.tax_total is required. Must equal tax * quantity for all items.
+    #[builder(default)]
     pub tax: Option<Money>,
     /// The item quantity. Must be a whole number.
     pub quantity: String,
     /// The detailed item description.
+    #[builder(default)]
     pub description: Option<String>,
     /// The stock keeping unit (SKU) for the item.
+    #[builder(default)]
     pub sku: Option<String>,
     /// The item category type
+    #[builder(default)]
     pub category: Option<ItemCategoryType>,
 }
 
 /// The status of the payment authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum AuthorizationStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
     Created,
@@ -306,20 +333,64 @@ pub enum AuthorizationStatus {
     Voided,
     /// The created authorization is in pending state. For more information, see status.details.
     Pending,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// A payment authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AuthorizationWithData {
+    /// The PayPal-generated ID for the authorized payment.
+    pub id: Option<AuthorizationId>,
     /// The status for the authorized payment.
     pub status: AuthorizationStatus,
     /// The details of the authorized order pending status.
-    pub status_details: AuthorizationStatusDetails,
+    pub status_details: Option<AuthorizationStatusDetails>,
+    /// The amount for this authorized payment.
+    pub amount: Option<Money>,
+    /// The API caller-provided external invoice number for this order.
+    pub invoice_id: Option<String>,
+    /// The API caller-provided external ID.
+    pub custom_id: Option<String>,
+    /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
+    pub seller_protection: Option<SellerProtection>,
+    /// The processor information, such as the AVS and CVV checks results.
+    pub processor_response: Option<ProcessorResponse>,
+    /// The date and time when the authorized payment expires.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub expiration_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction occurred.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The processor information of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ProcessorResponse {
+    /// The address verification code for Visa, Discover, Mastercard, or American Express transactions.
+    pub avs_code: Option<String>,
+    /// The card verification value code for Visa, Discover, Mastercard, or American Express.
+    pub cvv_code: Option<String>,
+    /// Processor response code for the non-PayPal payment processor errors.
+    pub response_code: Option<String>,
+    /// The declined payment transactions might have payment advice codes.
+    pub payment_advice_code: Option<String>,
 }
 
 /// The capture status.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum CaptureStatus {
     /// The funds for this captured payment were credited to the payee's PayPal account.
     Completed,
@@ -331,11 +402,17 @@ pub enum CaptureStatus {
     Pending,
     /// An amount greater than or equal to this captured payment's amount was refunded to the payer.
     Refunded,
+    /// There was an error while capturing payment.
+    Failed,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Capture status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum CaptureStatusDetailsReason {
     /// The payer initiated a dispute for this captured payment with PayPal.
     BuyerComplaint,
@@ -363,55 +440,144 @@ pub enum CaptureStatusDetailsReason {
     Unilateral,
     /// The payee's PayPal account is not verified.
     VerificationRequired,
+    /// Risk filter set by the payee failed for the transaction.
+    DeclinedByRiskFraudFilters,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Details about the captured payment status.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct CaptureStatusDetails {
     /// The reason why the captured payment status is PENDING or DENIED.
     pub reason: CaptureStatusDetailsReason,
 }
 
 /// A captured payment.
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct Capture {
+    /// The PayPal-generated ID for the captured payment.
+    #[builder(default)]
+    pub id: Option<CaptureId>,
     /// The status of the captured payment.
     pub status: CaptureStatus,
     /// The details of the captured payment status.
+    #[builder(default)]
     pub status_details: Option<CaptureStatusDetails>,
+    /// The amount for this captured payment.
+    #[builder(default)]
+    pub amount: Option<Money>,
+    /// The API caller-provided external invoice number for this order.
+    #[builder(default)]
+    pub invoice_id: Option<String>,
+    /// The API caller-provided external ID.
+    #[builder(default)]
+    pub custom_id: Option<String>,
+    /// Whether no additional captures can be made against the authorized payment.
+    #[builder(default)]
+    pub final_capture: Option<bool>,
+    /// The funds that are held on behalf of the merchant.
+    #[builder(default)]
+    pub disbursement_mode: Option<DisbursementMode>,
+    /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
+    #[builder(default)]
+    pub seller_protection: Option<SellerProtection>,
+    /// The detailed breakdown of the captured payment.
+    #[builder(default)]
+    pub seller_receivable_breakdown: Option<SellerReceivableBreakdown>,
+    /// The date and time when the transaction occurred.
+    #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction was last updated.
+    #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of related HATEOAS links.
+    #[builder(default)]
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The detailed breakdown of a captured payment.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct SellerReceivableBreakdown {
+    /// The amount for this captured payment in the currency of the transaction.
+    pub gross_amount: Money,
+    /// The applicable fee for this captured payment in the currency of the transaction.
+    pub paypal_fee: Option<Money>,
+    /// The applicable fee for this captured payment in the receivable currency.
+    pub paypal_fee_in_receivable_currency: Option<Money>,
+    /// The net amount that the payee receives for this captured payment in their PayPal account.
+    pub net_amount: Option<Money>,
+    /// The net amount that is credited to the payee's PayPal account, in the receivable currency.
+    pub receivable_amount: Option<Money>,
+    /// The exchange rate that determines the amount that is credited to the payee's PayPal account.
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+impl SellerReceivableBreakdown {
+    /// Returns the net amount the payee receives, in the currency of the transaction or the receivable currency.
+    ///
+    /// To get it in another currency, [convert](ExchangeRate::convert) the net amount with your own rate.
+    pub fn net_in(&self, currency: &Currency) -> Result<Money, MoneyError> {
+        [&self.net_amount, &self.receivable_amount]
+            .into_iter()
+            .flatten()
+            .find(|amount| amount.currency_code == *currency)
+            .cloned()
+            .ok_or_else(|| MoneyError::CurrencyMismatch {
+                expected: currency.clone(),
+                found: self.gross_amount.currency_code.clone(),
+            })
+    }
 }
 
 /// The status of the refund
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum RefundStatus {
     /// The refund was cancelled.
     Cancelled,
+    /// The refund could not be processed.
+    Failed,
     /// The refund is pending. For more information, see status_details.reason.
     Pending,
     /// The funds for this transaction were debited to the customer's account.
     Completed,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Refund status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum RefundStatusDetailsReason {
     /// The customer's account is funded through an eCheck, which has not yet cleared.
     Echeck,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Details about the status of the refund.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct RefundStatusDetails {
     /// The reason why the refund has the PENDING or FAILED status.
     pub reason: RefundStatusDetailsReason,
 }
 
 /// Exchange rate.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct ExchangeRate {
     /// The source currency from which to convert an amount.
     pub source_currency: Currency,
@@ -421,8 +587,69 @@ pub struct ExchangeRate {
     pub value: String,
 }
 
+impl ExchangeRate {
+    /// Converts an amount of the source currency to the target currency, rounding half away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::data::{common::{Currency, Money}, orders::ExchangeRate};
+    ///
+    /// let rate = ExchangeRate {
+    ///     source_currency: Currency::USD,
+    ///     target_currency: Currency::JPY,
+    ///     value: "151.235".to_string(),
+    /// };
+    /// assert_eq!(rate.convert(&Money::usd("10.05")).unwrap(), Money::jpy("1520"));
+    /// assert_eq!(rate.to_string(), "1 USD = 151.235 JPY");
+    /// assert!(rate.convert(&Money::eur("10.00")).is_err());
+    /// ```
+    pub fn convert(&self, amount: &Money) -> Result<Money, MoneyError> {
+        if amount.currency_code != self.source_currency {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: self.source_currency.clone(),
+                found: amount.currency_code.clone(),
+            });
+        }
+
+        let (integer, fraction) = self.value.split_once('.').unwrap_or((&self.value, ""));
+        let is_number = !integer.is_empty()
+            && fraction.len() <= 15
+            && integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit());
+        let rate: i128 = match format!("{}{}", integer, fraction).parse() {
+            Ok(rate) if is_number => rate,
+            _ => return Err(MoneyError::InvalidValue(self.value.clone())),
+        };
+
+        // value * rate, from the minor units of the source currency to the ones of the target currency.
+        let pow = |exponent: u32| 10i128.checked_pow(exponent).ok_or(MoneyError::Overflow);
+        let numerator = amount
+            .minor_units()?
+            .checked_mul(rate)
+            .and_then(|value| value.checked_mul(pow(self.target_currency.exponent()).ok()?))
+            .ok_or(MoneyError::Overflow)?;
+        let denominator = pow(fraction.len() as u32 + self.source_currency.exponent())?;
+        let (mut value, remainder) = (numerator / denominator, numerator % denominator);
+        if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+            value += numerator.signum();
+        }
+        Ok(Money::from_minor(self.target_currency.clone(), value))
+    }
+}
+
+impl std::fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "1 {} = {} {}",
+            self.source_currency, self.value, self.target_currency
+        )
+    }
+}
+
 /// The net breakdown of the refund.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct NetAmountBreakdown {
     /// The converted payable amount.
     pub converted_amount: Money,
@@ -432,8 +659,19 @@ pub struct NetAmountBreakdown {
     pub payable_amount: Money,
 }
 
+/// Renders both amounts and the rate, as in `-10.00 USD = -9.12 EUR (1 USD = 0.912 EUR)`.
+impl std::fmt::Display for NetAmountBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} = {} ({})",
+            self.payable_amount, self.converted_amount, self.exchange_rate
+        )
+    }
+}
+
 /// The breakdown of the refund.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct SellerPayableBreakdown {
     /// The amount that the payee refunded to the payer.
@@ -441,43 +679,70 @@ pub struct SellerPayableBreakdown {
     /// The net amount that the payee's account is debited in the transaction currency. The net amount is calculated as gross_amount minus paypal_fee minus platform_fees.
     pub net_amount: Money,
     /// An array of breakdown values for the net amount. Returned when the currency of the refund is different from the currency of the PayPal account where the payee holds their funds.
+    #[builder(default)]
     pub net_amount_breakdown: Option<Vec<NetAmountBreakdown>>,
     /// The net amount that the payee's account is debited in the receivable currency. Returned only in cases when the receivable currency is different from transaction currency. Example 'CNY'.
+    #[builder(default)]
     pub net_amount_in_receivable_currency: Option<Money>,
     /// The PayPal fee that was refunded to the payer in the currency of the transaction. This fee might not match the PayPal fee that the payee paid when the payment was captured.
     pub paypal_fee: Money,
     /// The PayPal fee that was refunded to the payer in the receivable currency. Returned only in cases when the receivable currency is different from transaction currency. Example 'CNY'.
+    #[builder(default)]
     pub paypal_fee_in_receivable_currency: Option<Money>,
     /// An array of platform or partner fees, commissions, or brokerage fees for the refund.
+    #[builder(default)]
     pub platform_fees: Option<Vec<PlatformFee>>,
     /// The total amount refunded from the original capture to date. For example, if a payer makes a $100 purchase and was refunded $20 a week ago and was refunded $30 in this refund, the gross_amount is $30 for this refund and the total_refunded_amount is $50.
     pub total_refunded_amount: Money,
 }
 
+impl SellerPayableBreakdown {
+    /// Returns the net amount debited from the payee, in the currency of the transaction, the receivable currency or
+    /// the currency of a net amount breakdown.
+    ///
+    /// To get it in another currency, [convert](ExchangeRate::convert) the net amount with your own rate.
+    pub fn net_in(&self, currency: &Currency) -> Result<Money, MoneyError> {
+        let breakdown = self.net_amount_breakdown.iter().flatten();
+        [&self.net_amount]
+            .into_iter()
+            .chain(&self.net_amount_in_receivable_currency)
+            .chain(breakdown.map(|breakdown| &breakdown.converted_amount))
+            .find(|amount| amount.currency_code == *currency)
+            .cloned()
+            .ok_or_else(|| MoneyError::CurrencyMismatch {
+                expected: currency.clone(),
+                found: self.net_amount.currency_code.clone(),
+            })
+    }
+}
+
 /// A refund
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct Refund {
     /// The status of the refund.
     pub status: RefundStatus,
     /// The details of the refund status.
+    #[builder(default)]
     pub status_details: Option<RefundStatusDetails>,
     /// The PayPal-generated ID for the refund.
-    pub id: String,
+    pub id: RefundId,
     /// The amount that the payee refunded to the payer.
     pub amount: Money,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
+    #[builder(default)]
     pub invoice_id: Option<String>,
     /// An array of related HATEOAS links.
     pub links: Vec<LinkDescription>,
     /// The reason for the refund. Appears in both the payer's transaction history and the emails that the payer receives.
+    #[builder(default)]
     pub note_to_payer: Option<String>,
     /// The breakdown of the refund.
     pub seller_payable_breakdown: SellerPayableBreakdown,
 }
 
 /// The comprehensive history of payments for the purchase unit.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct PaymentCollection {
     /// An array of authorized payments for a purchase unit. A purchase unit can have zero or more authorized payments.
@@ -493,7 +758,7 @@ pub struct PaymentCollection {
 
 /// Represents either a full or partial order that the payer intends to purchase from the payee.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct PurchaseUnit {
     /// The API caller-provided external ID for the purchase unit. Required for multiple purchase units when you must update the order through PATCH.
@@ -549,8 +814,9 @@ impl PurchaseUnit {
 }
 
 /// The type of landing page to show on the PayPal site for customer checkout.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum LandingPage {
     /// When the customer clicks PayPal Checkout, the customer is redirected to a page to log in to PayPal and approve the payment.
     Login,
@@ -560,74 +826,70 @@ pub enum LandingPage {
     /// When the customer clicks PayPal Checkout, the customer is redirected to either a page to log in to PayPal and approve
     /// the payment or to a page to enter credit or debit card and other relevant billing information required to complete the purchase,
     /// depending on their previous interaction with PayPal.
+    #[default]
     NoPreference,
-}
-
-impl Default for LandingPage {
-    fn default() -> Self {
-        LandingPage::NoPreference
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The shipping preference
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum ShippingPreference {
     /// Use the customer-provided shipping address on the PayPal site.
+    #[default]
     GetFromFile,
     /// Redact the shipping address from the PayPal site. Recommended for digital goods.
     NoShipping,
     ///  Use the merchant-provided address. The customer cannot change this address on the PayPal site.
     SetProvidedAddress,
-}
-
-impl Default for ShippingPreference {
-    fn default() -> Self {
-        ShippingPreference::GetFromFile
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Configures a Continue or Pay Now checkout flow.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum UserAction {
     /// After you redirect the customer to the PayPal payment page, a Continue button appears. Use this option when
     /// the final amount is not known when the checkout flow is initiated and you want to redirect the customer
     /// to the merchant page without processing the payment.
+    #[default]
     Continue,
     /// After you redirect the customer to the PayPal payment page, a Pay Now button appears.
     /// Use this option when the final amount is known when the checkout is initiated and you want to
     /// process the payment immediately when the customer clicks Pay Now.
     PayNow,
-}
-
-impl Default for UserAction {
-    fn default() -> Self {
-        UserAction::Continue
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The merchant-preferred payment sources.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum PayeePreferred {
     /// Accepts any type of payment from the customer.
+    #[default]
     Unrestricted,
     /// Accepts only immediate payment from the customer.
     /// For example, credit card, PayPal balance, or instant ACH.
     /// Ensures that at the time of capture, the payment does not have the `pending` status.
     ImmediatePaymentRequired,
-}
-
-impl Default for PayeePreferred {
-    fn default() -> Self {
-        PayeePreferred::Unrestricted
-    }
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// A payment method.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct PaymentMethod {
     /// The customer-selected payment method on the merchant site.
     pub payer_selected: Option<String>,
@@ -637,7 +899,7 @@ pub struct PaymentMethod {
 
 /// Customize the payer experience during the approval process for the payment with PayPal.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option, into), default)]
 pub struct ApplicationContext {
     /// The label that overrides the business name in the PayPal account on the PayPal site.
@@ -645,7 +907,7 @@ pub struct ApplicationContext {
     /// The BCP 47-formatted locale of pages that the PayPal payment experience shows. PayPal supports a five-character code.
     ///
     /// For example, da-DK, he-IL, id-ID, ja-JP, no-NO, pt-BR, ru-RU, sv-SE, th-TH, zh-CN, zh-HK, or zh-TW.
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// The type of landing page to show on the PayPal site for customer checkout
     pub landing_page: Option<LandingPage>,
     /// The shipping preference
@@ -661,23 +923,58 @@ pub struct ApplicationContext {
 }
 
 /// A card used in payment sources.
+///
+/// Either the card details or the `vault_id` of a card saved with the vault api must be given.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
-#[builder(setter(into))]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct PaymentCard {
     /// The card number.
-    pub number: String,
+    pub number: Option<String>,
     /// The expiry date.
-    pub expiry: String,
+    pub expiry: Option<String>,
     /// The card owner name.
-    pub name: String,
+    pub name: Option<String>,
     /// The billing address.
-    pub billing_address: Address,
+    pub billing_address: Option<Address>,
+    /// The PayPal-generated id of a vaulted card, see [crate::api::vault].
+    pub vault_id: Option<String>,
+}
+
+impl PaymentCard {
+    /// Creates a card payment source charging a card saved with the vault api.
+    pub fn vaulted(vault_id: impl ToString) -> Self {
+        Self {
+            vault_id: Some(vault_id.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A PayPal wallet used in payment sources.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaypalWallet {
+    /// The PayPal-generated id of a vaulted PayPal wallet, see [crate::api::vault].
+    pub vault_id: Option<String>,
+    /// The email address of the PayPal account holder.
+    pub email_address: Option<String>,
+}
+
+impl PaypalWallet {
+    /// Creates a PayPal payment source charging a wallet saved with the vault api.
+    pub fn vaulted(vault_id: impl ToString) -> Self {
+        Self {
+            vault_id: Some(vault_id.to_string()),
+            ..Default::default()
+        }
+    }
 }
 
 /// A transaction reference.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(into))]
 pub struct TransactionReference {
     /// The transaction id.
@@ -688,7 +985,7 @@ pub struct TransactionReference {
 
 /// A stored credential.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(into))]
 pub struct StoredCredential {
     /// The payment initiator, e.g "MERCHANT"
@@ -705,20 +1002,81 @@ pub struct StoredCredential {
 // TODO: this only appears in the example body, not documented.
 // https://developer.paypal.com/docs/api/orders/v2/#orders_create
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option), default)]
 pub struct OrderPaymentSource {
     /// The card used in the payment.
-    pub card: PaymentCard,
+    pub card: Option<PaymentCard>,
+    /// The PayPal wallet used in the payment.
+    pub paypal: Option<PaypalWallet>,
     /// A stored credential.
     // TODO: figure out what is this.
-    #[builder(default)]
     pub stored_credential: Option<StoredCredential>,
+    /// A tokenized payment source, such as a billing agreement.
+    pub token: Option<PaymentSourceToken>,
+}
+
+impl OrderPaymentSource {
+    /// Creates a payment source charging a card saved with the vault api.
+    ///
+    /// Used for merchant-initiated transactions on stored payment methods.
+    pub fn vaulted_card(vault_id: impl ToString) -> Self {
+        Self {
+            card: Some(PaymentCard::vaulted(vault_id)),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a payment source charging a PayPal wallet saved with the vault api.
+    pub fn vaulted_paypal(vault_id: impl ToString) -> Self {
+        Self {
+            paypal: Some(PaypalWallet::vaulted(vault_id)),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a payment source charging a billing agreement of the reference transactions program.
+    ///
+    /// Used for merchant-initiated transactions, without the payer approving the order.
+    pub fn billing_agreement(agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            token: Some(PaymentSourceToken::billing_agreement(agreement_id)),
+            ..Default::default()
+        }
+    }
+}
+
+/// The tokenization method that generated a payment source token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PaymentTokenType {
+    /// A billing agreement, created with the billing agreements api.
+    BillingAgreement,
+}
+
+/// A tokenized payment source to fund a payment.
+#[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq, Eq)]
+pub struct PaymentSourceToken {
+    /// The PayPal-generated ID for the token.
+    pub id: String,
+    /// The tokenization method that generated the ID.
+    pub r#type: PaymentTokenType,
+}
+
+impl PaymentSourceToken {
+    /// A token charging the given billing agreement.
+    pub fn billing_agreement(agreement_id: impl Into<BillingAgreementId>) -> Self {
+        Self {
+            id: agreement_id.into().into_inner(),
+            r#type: PaymentTokenType::BillingAgreement,
+        }
+    }
 }
 
 /// A order payload to be used when creating an order.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct OrderPayload {
     /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
@@ -738,8 +1096,9 @@ pub struct OrderPayload {
 }
 
 /// The card brand or network.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum CardBrand {
     /// Visa card.
     Visa,
@@ -773,20 +1132,28 @@ pub enum CardBrand {
     Cetelem,
     /// China union pay credit card.
     ChinaUnionPay,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum CardType {
     Credit,
     Debit,
     Prepaid,
     Unknown,
+    /// A value not known by this crate. Not named `Unknown` like the other catch-all variants, since paypal sends
+    /// [CardType::Unknown] when it doesn't know the card type.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// The payment card to use to fund a payment.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CardResponse {
     /// The last digits of the payment card.
     pub last_digits: String,
@@ -798,14 +1165,15 @@ pub struct CardResponse {
 }
 
 /// The customer's wallet used to fund the transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct WalletResponse {
     /// Apple Pay Wallet response information.
     pub apple_pay: CardResponse,
 }
 
 /// The paypal account used to fund the transaction.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into))]
 pub struct PaypalPaymentSourceResponse {
     /// The name of the payer.
     pub name: PayerName,
@@ -816,7 +1184,7 @@ pub struct PaypalPaymentSourceResponse {
 }
 
 /// The payment source used to fund the payment.
-#[derive(Debug, Serialize, Deserialize, Builder, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Builder, Default, Clone, PartialEq)]
 #[builder(setter(strip_option), default)]
 pub struct PaymentSourceResponse {
     /// The payment card to use to fund a payment. Card can be a credit or debit card
@@ -829,8 +1197,9 @@ pub struct PaymentSourceResponse {
 }
 
 /// The status of an order.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum OrderStatus {
     /// The order was created with the specified context.
     Created,
@@ -843,27 +1212,42 @@ pub enum OrderStatus {
     Voided,
     /// The payment was authorized or the authorized payment was captured for the order.
     Completed,
+    /// The order requires an action from the payer, such as the 3D Secure authentication.
+    /// Redirect the payer to the `payer-action` link.
+    PayerActionRequired,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An order represents a payment between two or more parties.
+#[serde_as]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
 #[builder(setter(strip_option))]
 pub struct Order {
     /// The date and time when the transaction occurred.
+    #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The date and time when the transaction was last updated.
+    #[builder(default)]
+    #[serde_as(as = "Option<Rfc3339>")]
     pub update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The ID of the order.
-    pub id: String,
+    pub id: OrderId,
     /// The payment source used to fund the payment.
+    #[builder(default)]
     pub payment_source: Option<PaymentSourceResponse>,
     /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
+    #[builder(default)]
     pub intent: Option<Intent>,
     /// The customer who approves and pays for the order. The customer is also known as the payer.
+    #[builder(default)]
     pub payer: Option<Payer>,
     /// An array of purchase units. Each purchase unit establishes a contract between a customer and merchant.
     /// Each purchase unit represents either a full or partial order that the customer intends to purchase from the merchant.
+    #[builder(default)]
     pub purchase_units: Option<Vec<PurchaseUnit>>,
     /// The order status.
     pub status: OrderStatus,
@@ -871,9 +1255,7 @@ pub struct Order {
     pub links: Vec<LinkDescription>,
 }
 
-/// An invoice number.
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct InvoiceNumber {
-    /// The invoice number.
-    pub invoice_number: String,
-}
+// Moved to the invoice module, re-exported to keep existing imports working.
+pub use super::invoice::InvoiceNumber;
+
+impl_links!(AuthorizationWithData, Capture, Refund, Order);
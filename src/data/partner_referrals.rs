@@ -0,0 +1,417 @@
+//! Paypal object definitions used by the partner referrals api.
+
+use super::common::{LinkDescription, LinkRel, Links};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The operation to enable for the customer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum Operation {
+    /// Integrate with PayPal's REST APIs.
+    #[default]
+    ApiIntegration,
+    /// Add a bank account to the PayPal account.
+    BankAddition,
+    /// Create a billing agreement with the customer.
+    BillingAgreement,
+    /// Consent to contextual marketing.
+    ContextualMarketingConsent,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The integration method.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum IntegrationMethod {
+    /// Integrate through PayPal.
+    #[default]
+    Paypal,
+    /// Integrate through Braintree.
+    Braintree,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of integration.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum IntegrationType {
+    /// The seller integrates with its own credentials.
+    FirstParty,
+    /// The partner calls the apis on behalf of the seller.
+    #[default]
+    ThirdParty,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A feature that the partner can use on behalf of the seller.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum Feature {
+    /// Accept payments.
+    Payment,
+    /// Refund payments.
+    Refund,
+    /// Make future payments.
+    FuturePayment,
+    /// Process card payments directly.
+    DirectPayment,
+    /// Collect a partner fee.
+    PartnerFee,
+    /// Delay the disbursement of funds.
+    DelayFundsDisbursement,
+    /// Read the seller disputes.
+    ReadSellerDispute,
+    /// Update the seller disputes.
+    UpdateSellerDispute,
+    /// Read the buyer disputes.
+    DisputeReadBuyer,
+    /// Update customer disputes.
+    UpdateCustomerDisputes,
+    /// Search the transactions.
+    AdvancedTransactionsSearch,
+    /// Sweep funds to an external sink.
+    SweepFundsExternalSink,
+    /// Access the merchant information.
+    AccessMerchantInformation,
+    /// Read and write shipment tracking information.
+    TrackingShipmentReadwrite,
+    /// Read and write invoices.
+    InvoiceReadWrite,
+    /// Vault payment methods.
+    Vault,
+    /// Create billing agreements.
+    BillingAgreement,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The details of a first party integration.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct FirstPartyDetails {
+    /// The features the seller enables.
+    pub features: Vec<Feature>,
+    /// The nonce used to exchange the seller credentials.
+    pub seller_nonce: Option<String>,
+}
+
+/// The details of a third party integration.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(into), default)]
+pub struct ThirdPartyDetails {
+    /// The features the partner can use on behalf of the seller.
+    pub features: Vec<Feature>,
+}
+
+/// The REST api integration details.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct RestApiIntegration {
+    /// The integration method.
+    pub integration_method: IntegrationMethod,
+    /// The type of integration.
+    pub integration_type: IntegrationType,
+    /// The details of a first party integration.
+    pub first_party_details: Option<FirstPartyDetails>,
+    /// The details of a third party integration.
+    pub third_party_details: Option<ThirdPartyDetails>,
+}
+
+/// The integration preference of an operation.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct IntegrationDetails {
+    /// The REST api integration details.
+    pub rest_api_integration: Option<RestApiIntegration>,
+}
+
+/// An operation to enable for the customer.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct OperationRequest {
+    /// The operation to enable.
+    pub operation: Operation,
+    /// The integration preference, required for the API_INTEGRATION operation.
+    pub api_integration_preference: Option<IntegrationDetails>,
+}
+
+/// A PayPal product to enable for the customer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum Product {
+    /// PayPal Checkout.
+    ExpressCheckout,
+    /// PayPal Plus.
+    Ppplus,
+    /// Website Payments Pro.
+    WebsitePaymentPro,
+    /// PayPal Complete Payments.
+    Ppcp,
+    /// Advanced vaulting.
+    AdvancedVaulting,
+    /// Alternative payment methods.
+    PaymentMethods,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of legal consent.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum LegalConsentType {
+    /// The consent to share the seller data with the partner.
+    #[default]
+    ShareDataConsent,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A consent given by the customer.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(into), default)]
+pub struct LegalConsent {
+    /// The type of consent.
+    pub r#type: LegalConsentType,
+    /// Whether the customer has granted the consent.
+    pub granted: bool,
+}
+
+/// Overrides the partner configuration for this referral.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PartnerConfigOverride {
+    /// The partner logo shown during onboarding.
+    pub partner_logo_url: Option<String>,
+    /// The url the seller is redirected to after onboarding.
+    pub return_url: Option<String>,
+    /// The description of the return url.
+    pub return_url_description: Option<String>,
+    /// The url used to renew the action url if it expires.
+    pub action_renewal_url: Option<String>,
+    /// Whether to show the add credit card page.
+    pub show_add_credit_card: Option<bool>,
+}
+
+/// The partner referral data used to onboard a seller.
+///
+/// <https://developer.paypal.com/docs/api/partner-referrals/v2/#partner-referrals_create>
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PartnerReferralPayload {
+    /// The email address of the seller.
+    pub email: Option<String>,
+    /// The preferred language code of the seller, for example `en-US`.
+    pub preferred_language_code: Option<String>,
+    /// The partner's unique identifier for this seller, used to look up the seller after onboarding.
+    pub tracking_id: Option<String>,
+    /// Overrides the partner configuration for this referral.
+    pub partner_config_override: Option<PartnerConfigOverride>,
+    /// The operations to enable for the seller.
+    #[serde(default)]
+    pub operations: Vec<OperationRequest>,
+    /// The PayPal products to enable for the seller.
+    pub products: Option<Vec<Product>>,
+    /// The consents given by the seller.
+    #[serde(default)]
+    pub legal_consents: Vec<LegalConsent>,
+}
+
+/// The links returned when creating a partner referral.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PartnerReferralLinks {
+    /// An array of request-related HATEOAS links. Use the `action_url` link to onboard the seller.
+    pub links: Vec<LinkDescription>,
+}
+
+impl PartnerReferralLinks {
+    /// Returns the url the seller must visit to complete the onboarding.
+    pub fn action_url(&self) -> Option<&str> {
+        self.href(LinkRel::ActionUrl)
+    }
+
+    /// Returns the id of the created referral, taken from the `self` link.
+    pub fn partner_referral_id(&self) -> Option<&str> {
+        self.href(LinkRel::SelfLink).and_then(|href| href.rsplit('/').next())
+    }
+}
+
+/// The referral data shared by the API caller.
+///
+/// <https://developer.paypal.com/docs/api/partner-referrals/v2/#partner-referrals_get>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReferralData {
+    /// The ID of the partner referral.
+    pub partner_referral_id: String,
+    /// The payer ID of the partner who submitted the referral.
+    pub submitter_payer_id: Option<String>,
+    /// The referral data as submitted when it was created.
+    pub referral_data: PartnerReferralPayload,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The status of a seller capability.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum CapabilityStatus {
+    /// The capability is active.
+    Active,
+    /// The capability is suspended.
+    Suspended,
+    /// The capability was revoked.
+    Revoked,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A capability of the seller, for example `CUSTOM_CARD_PROCESSING`.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Capability {
+    /// The name of the capability.
+    pub name: String,
+    /// The status of the capability.
+    pub status: CapabilityStatus,
+    /// The limits applied to the capability.
+    pub limits: Option<Vec<CapabilityLimit>>,
+}
+
+/// A limit applied to a capability of the seller.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CapabilityLimit {
+    /// The type of the limit, for example `GENERAL`.
+    pub r#type: String,
+}
+
+/// A product the seller is subscribed to.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ProductDetail {
+    /// The name of the product.
+    pub name: String,
+    /// The vetting status of the product, for example `SUBSCRIBED`.
+    pub vetting_status: Option<String>,
+    /// The capabilities granted by this product.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+}
+
+/// The credentials the partner was granted by the seller.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OauthThirdParty {
+    /// The client id of the partner.
+    pub partner_client_id: String,
+    /// The client id of the seller.
+    pub merchant_client_id: Option<String>,
+    /// The scopes granted to the partner.
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// An oauth integration between the partner and the seller.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OauthIntegration {
+    /// The type of integration, for example `OAUTH_THIRD_PARTY`.
+    pub integration_type: String,
+    /// The integration method.
+    pub integration_method: Option<IntegrationMethod>,
+    /// The third party credentials granted.
+    #[serde(default)]
+    pub oauth_third_party: Vec<OauthThirdParty>,
+}
+
+/// The onboarding status of a seller.
+///
+/// <https://developer.paypal.com/docs/multiparty/seller-onboarding/before-payment/#link-trackonboardingstatus>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MerchantIntegration {
+    /// The PayPal merchant id of the seller.
+    pub merchant_id: String,
+    /// The partner's unique identifier for this seller.
+    pub tracking_id: Option<String>,
+    /// The legal name of the seller.
+    pub legal_name: Option<String>,
+    /// The products the seller is subscribed to.
+    #[serde(default)]
+    pub products: Vec<ProductDetail>,
+    /// The capabilities of the seller.
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+    /// Whether the seller can receive payments.
+    #[serde(default)]
+    pub payments_receivable: bool,
+    /// The primary email of the seller.
+    pub primary_email: Option<String>,
+    /// Whether the seller confirmed their primary email.
+    #[serde(default)]
+    pub primary_email_confirmed: bool,
+    /// The oauth integrations between the partner and the seller.
+    #[serde(default)]
+    pub oauth_integrations: Vec<OauthIntegration>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl MerchantIntegration {
+    /// Whether the seller can receive payments and has confirmed their email,
+    /// which paypal requires before enabling payments for the seller.
+    pub fn is_ready(&self) -> bool {
+        self.payments_receivable && self.primary_email_confirmed
+    }
+
+    /// Returns the status of the given capability, if the seller has it.
+    pub fn capability(&self, name: &str) -> Option<CapabilityStatus> {
+        self.capabilities
+            .iter()
+            .find(|capability| capability.name == name)
+            .map(|capability| capability.status.clone())
+    }
+}
+
+/// The seller found when looking up a merchant integration by tracking id.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MerchantIntegrationLink {
+    /// The PayPal merchant id of the seller.
+    pub merchant_id: String,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(
+    PartnerReferralLinks,
+    ReferralData,
+    MerchantIntegration,
+    MerchantIntegrationLink
+);
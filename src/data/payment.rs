@@ -1,12 +1,17 @@
 //! Paypal object definitions used by the payments api.
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
 
 use super::common::{AuthorizationStatusDetails, LinkDescription, Money, SellerProtection};
+use super::date_time::Rfc3339;
+use super::ids::AuthorizationId;
 
 /// Payment Status
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum PaymentStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
     Created,
@@ -24,9 +29,13 @@ pub enum PaymentStatus {
     Voided,
     /// The created authorization is in pending state.
     Pending,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The authorized payment details.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AuthorizedPaymentDetails {
     /// The status for the authorized payment.
@@ -34,7 +43,7 @@ pub struct AuthorizedPaymentDetails {
     /// The details of the authorized order pending status.
     pub status_details: AuthorizationStatusDetails,
     /// The PayPal-generated ID for the authorized payment.
-    pub id: String,
+    pub id: AuthorizationId,
     /// The amount for this authorized payment.
     pub amount: Money,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
@@ -44,11 +53,31 @@ pub struct AuthorizedPaymentDetails {
     /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
     pub seller_protection: SellerProtection,
     /// The date and time when the authorized payment expires
+    #[serde_as(as = "Rfc3339")]
     pub expiration_time: chrono::DateTime<chrono::Utc>,
     /// An array of related HATEOAS links.
     pub links: Vec<LinkDescription>,
     /// The date and time when the transaction occurred
+    #[serde_as(as = "Rfc3339")]
     pub create_time: chrono::DateTime<chrono::Utc>,
     /// The date and time when the transaction was last updated
+    #[serde_as(as = "Rfc3339")]
     pub update_time: chrono::DateTime<chrono::Utc>,
 }
+
+/// The refund of a captured payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct RefundRequest {
+    /// The amount to refund, the remaining captured amount if omitted.
+    pub amount: Option<Money>,
+    /// The API caller-provided external invoice number for this order.
+    pub invoice_id: Option<String>,
+    /// The API caller-provided external ID.
+    pub custom_id: Option<String>,
+    /// The reason for the refund, shown to the payer.
+    pub note_to_payer: Option<String>,
+}
+
+impl_links!(AuthorizedPaymentDetails);
@@ -0,0 +1,218 @@
+//! Paypal object definitions used by the payouts api.
+
+use super::common::{Currency, LinkDescription, Money};
+use super::date_time::Rfc3339;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// The type of id identifying the receiver of a payout.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum RecipientType {
+    /// The receiver is identified by its email.
+    #[default]
+    Email,
+    /// The receiver is identified by its mobile phone number.
+    Phone,
+    /// The receiver is identified by its encrypted PayPal account number.
+    PaypalId,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The amount of a payout.
+///
+/// Unlike [Money], the payouts api names the currency field `currency`.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PayoutCurrency {
+    /// The currency of the amount.
+    pub currency: Currency,
+    /// The value of the amount.
+    pub value: String,
+}
+
+impl From<Money> for PayoutCurrency {
+    fn from(money: Money) -> Self {
+        Self {
+            currency: money.currency_code,
+            value: money.value,
+        }
+    }
+}
+
+impl From<PayoutCurrency> for Money {
+    fn from(amount: PayoutCurrency) -> Self {
+        Money {
+            currency_code: amount.currency,
+            value: amount.value,
+        }
+    }
+}
+
+/// The sender-provided header of a payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SenderBatchHeader {
+    /// The sender-specified id of the batch, rejected by paypal if it was used in the last 30 days.
+    pub sender_batch_id: Option<String>,
+    /// The subject line of the email paypal sends to the receivers.
+    pub email_subject: Option<String>,
+    /// The email message paypal sends to the receivers.
+    pub email_message: Option<String>,
+    /// The type of id of the receivers, unless set on each item.
+    pub recipient_type: Option<RecipientType>,
+}
+
+/// A payout to a receiver.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PayoutItem {
+    /// The type of id of the receiver.
+    pub recipient_type: Option<RecipientType>,
+    /// The amount to pay.
+    pub amount: PayoutCurrency,
+    /// The note to the receiver.
+    pub note: Option<String>,
+    /// The email, phone number or encrypted PayPal account number of the receiver.
+    pub receiver: String,
+    /// The sender-specified id of the item, which must be unique in the batch.
+    pub sender_item_id: Option<String>,
+    /// The wallet the receiver gets the payout in, `PAYPAL` by default.
+    pub recipient_wallet: Option<String>,
+}
+
+/// The payout batch to create.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PayoutBatchPayload {
+    /// The header of the batch.
+    pub sender_batch_header: SenderBatchHeader,
+    /// The payouts of the batch, at most 15000.
+    pub items: Vec<PayoutItem>,
+}
+
+/// The status of a payout batch.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum BatchStatus {
+    /// The batch was denied, no payout was made.
+    Denied,
+    /// The batch is waiting to be processed.
+    Pending,
+    /// The batch is being processed.
+    Processing,
+    /// The batch was processed, its items have their own status.
+    Success,
+    /// The batch was canceled.
+    Canceled,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The status of a payout item.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PayoutItemStatus {
+    /// The funds were credited to the receiver.
+    Success,
+    /// The payout failed.
+    Failed,
+    /// The payout is being processed.
+    Pending,
+    /// The receiver has no PayPal account, the funds are returned if unclaimed in 30 days.
+    Unclaimed,
+    /// The receiver did not claim the payout, the funds were returned to the sender.
+    Returned,
+    /// The payout is on hold.
+    Onhold,
+    /// The payout was blocked.
+    Blocked,
+    /// The payout was refunded.
+    Refunded,
+    /// The payout was reversed.
+    Reversed,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The header of a payout batch, as returned by paypal.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PayoutBatchHeader {
+    /// The id of the batch, generated by paypal.
+    pub payout_batch_id: String,
+    /// The status of the batch.
+    pub batch_status: BatchStatus,
+    /// The date and time when the processing of the batch started.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub time_created: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the processing of the batch completed.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub time_completed: Option<chrono::DateTime<chrono::Utc>>,
+    /// The header the batch was created with.
+    pub sender_batch_header: Option<SenderBatchHeader>,
+    /// The total amount of the batch.
+    pub amount: Option<PayoutCurrency>,
+    /// The total fees of the batch.
+    pub fees: Option<PayoutCurrency>,
+    /// The number of payouts of the batch, sent in webhook events.
+    pub payments: Option<i32>,
+}
+
+/// A payout of a batch, as returned by paypal.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PayoutItemDetails {
+    /// The id of the payout item, generated by paypal.
+    pub payout_item_id: String,
+    /// The id of the transaction of the payout.
+    pub transaction_id: Option<String>,
+    /// The status of the payout.
+    pub transaction_status: Option<PayoutItemStatus>,
+    /// The id of the batch of the payout.
+    pub payout_batch_id: Option<String>,
+    /// The sender-specified id of the batch of the payout, sent in webhook events.
+    pub sender_batch_id: Option<String>,
+    /// The fee of the payout.
+    pub payout_item_fee: Option<PayoutCurrency>,
+    /// The payout the batch was created with.
+    pub payout_item: PayoutItem,
+    /// The date and time when the payout was processed.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub time_processed: Option<chrono::DateTime<chrono::Utc>>,
+    /// Why the payout failed, if it did.
+    pub errors: Option<serde_json::Value>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PayoutBatch {
+    /// The header of the batch.
+    pub batch_header: PayoutBatchHeader,
+    /// The payouts of the batch, empty in the response creating it.
+    #[serde(default)]
+    pub items: Vec<PayoutItemDetails>,
+    /// The total number of items, only returned when `total_required` is set.
+    pub total_items: Option<i32>,
+    /// The total number of pages, only returned when `total_required` is set.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(PayoutItemDetails, PayoutBatch);
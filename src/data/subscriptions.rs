@@ -0,0 +1,270 @@
+//! Paypal object definitions used by the subscriptions api.
+
+use super::common::{LinkDescription, Money};
+use super::date_time::Rfc3339;
+use super::ids::SubscriptionId;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// The status of a plan.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PlanStatus {
+    /// The plan was created. You cannot create subscriptions for a plan in this state.
+    Created,
+    /// The plan is inactive.
+    Inactive,
+    /// The plan is active. You can only create subscriptions for a plan in this state.
+    #[default]
+    Active,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The unit of the interval at which a subscription is charged.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum IntervalUnit {
+    /// A daily billing cycle, up to 365 days.
+    Day,
+    /// A weekly billing cycle, up to 52 weeks.
+    Week,
+    /// A monthly billing cycle, up to 12 months.
+    #[default]
+    Month,
+    /// A yearly billing cycle, of 1 year.
+    Year,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The frequency of a billing cycle.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Frequency {
+    /// The unit of the interval.
+    pub interval_unit: IntervalUnit,
+    /// The number of intervals after which a subscriber is billed.
+    pub interval_count: u32,
+}
+
+/// The tenure type of a billing cycle.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TenureType {
+    /// A regular billing cycle.
+    #[default]
+    Regular,
+    /// A trial billing cycle, before the regular ones.
+    Trial,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The pricing scheme of a billing cycle.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PricingScheme {
+    /// The fixed amount to charge for the billing cycle.
+    pub fixed_price: Option<Money>,
+}
+
+/// A billing cycle of a plan.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct BillingCycle {
+    /// The frequency of the billing cycle.
+    pub frequency: Frequency,
+    /// The tenure type of the billing cycle.
+    pub tenure_type: TenureType,
+    /// The order in which this cycle runs among the other billing cycles, starting at 1.
+    pub sequence: u32,
+    /// The number of times this billing cycle gets executed, 0 for an infinite number of times.
+    pub total_cycles: Option<u32>,
+    /// The pricing scheme of the billing cycle, omitted for free trials.
+    pub pricing_scheme: Option<PricingScheme>,
+}
+
+/// The action to take if the initial payment for the setup fails.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SetupFeeFailureAction {
+    /// Activates the subscription anyway and adds the setup fee to the outstanding balance.
+    Continue,
+    /// Doesn't activate the subscription.
+    #[default]
+    Cancel,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The payment preferences of a plan.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaymentPreferences {
+    /// Whether to bill the outstanding amount in the next billing cycle.
+    pub auto_bill_outstanding: Option<bool>,
+    /// The initial set-up fee for the service.
+    pub setup_fee: Option<Money>,
+    /// The action to take if the initial payment for the setup fails.
+    pub setup_fee_failure_action: Option<SetupFeeFailureAction>,
+    /// The maximum number of payment failures before a subscription is suspended.
+    pub payment_failure_threshold: Option<u32>,
+}
+
+/// The taxes of a plan.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Taxes {
+    /// The tax percentage on the billing amount.
+    pub percentage: String,
+    /// Whether the tax was already included in the billing amount.
+    pub inclusive: bool,
+}
+
+/// The plan to create.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PlanPayload {
+    /// The id of the product the plan is for.
+    pub product_id: String,
+    /// The plan name.
+    pub name: String,
+    /// The initial status of the plan, active by default.
+    pub status: Option<PlanStatus>,
+    /// The plan description.
+    pub description: Option<String>,
+    /// The trial and regular billing cycles, at most 12.
+    pub billing_cycles: Vec<BillingCycle>,
+    /// The payment preferences of the plan.
+    pub payment_preferences: PaymentPreferences,
+    /// The taxes of the plan.
+    pub taxes: Option<Taxes>,
+    /// Whether you can subscribe to this plan by providing a quantity for the goods or service.
+    pub quantity_supported: Option<bool>,
+}
+
+/// A subscription plan.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Plan {
+    /// The id of the plan.
+    pub id: String,
+    /// The id of the product the plan is for.
+    pub product_id: Option<String>,
+    /// The plan name.
+    pub name: Option<String>,
+    /// The plan status.
+    pub status: Option<PlanStatus>,
+    /// The plan description.
+    pub description: Option<String>,
+    /// The trial and regular billing cycles.
+    #[serde(default)]
+    pub billing_cycles: Vec<BillingCycle>,
+    /// The payment preferences of the plan.
+    pub payment_preferences: Option<PaymentPreferences>,
+    /// The taxes of the plan.
+    pub taxes: Option<Taxes>,
+    /// Whether you can subscribe to this plan by providing a quantity for the goods or service.
+    pub quantity_supported: Option<bool>,
+    /// The date and time when the plan was created.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the plan was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A page of the plans.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlanList {
+    /// The plans.
+    #[serde(default)]
+    pub plans: Vec<Plan>,
+    /// The total number of plans, only returned when `total_required` is set.
+    pub total_items: Option<i32>,
+    /// The total number of pages, only returned when `total_required` is set.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The status of a subscription.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SubscriptionStatus {
+    /// The subscription was created but not yet approved by the buyer.
+    ApprovalPending,
+    /// The buyer approved the subscription.
+    Approved,
+    /// The subscription is active.
+    Active,
+    /// The subscription is suspended.
+    Suspended,
+    /// The subscription was cancelled.
+    Cancelled,
+    /// The subscription expired.
+    Expired,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A subscription to a plan.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Subscription {
+    /// The id of the subscription.
+    pub id: SubscriptionId,
+    /// The id of the plan.
+    pub plan_id: Option<String>,
+    /// The status of the subscription.
+    pub status: Option<SubscriptionStatus>,
+    /// The reason or notes for the status of the subscription.
+    pub status_change_note: Option<String>,
+    /// The date and time when the status of the subscription was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub status_update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the subscription started.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The quantity of the product in the subscription.
+    pub quantity: Option<String>,
+    /// The custom id of the subscription.
+    pub custom_id: Option<String>,
+    /// Whether the plan was overridden for this subscription.
+    pub plan_overridden: Option<bool>,
+    /// The subscriber.
+    pub subscriber: Option<serde_json::Value>,
+    /// The billing details of the subscription, such as the next billing time.
+    pub billing_info: Option<serde_json::Value>,
+    /// The date and time when the subscription was created.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the subscription was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(Plan, PlanList, Subscription);
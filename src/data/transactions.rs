@@ -0,0 +1,112 @@
+//! Paypal object definitions used by the transaction search api.
+
+use super::common::{LinkDescription, Money};
+use super::date_time::Rfc3339;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+/// The status of a transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum TransactionStatus {
+    /// PayPal or merchant rules denied the transaction.
+    #[serde(rename = "D")]
+    Denied,
+    /// The transaction is pending, it is processing and may not yet be visible in the account.
+    #[serde(rename = "P")]
+    Pending,
+    /// The transaction successfully completed without a denial and after any pending statuses.
+    #[serde(rename = "S")]
+    Success,
+    /// A successful transaction was reversed and funds were refunded to the original sender.
+    #[serde(rename = "V")]
+    Reversed,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The information of a transaction.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TransactionInfo {
+    /// The id of the PayPal account of the counterparty.
+    pub paypal_account_id: Option<String>,
+    /// The id of the transaction, the id of the capture for order payments.
+    pub transaction_id: Option<String>,
+    /// The id of a related transaction, such as the captured payment of a refund.
+    pub paypal_reference_id: Option<String>,
+    /// The type of the related transaction id.
+    pub paypal_reference_id_type: Option<String>,
+    /// A five-character transaction event code, such as `T0006` for checkout payments.
+    pub transaction_event_code: Option<String>,
+    /// The date and time when the transaction was initiated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub transaction_initiation_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction was last updated.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub transaction_updated_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The gross amount of the transaction, negative for money sent.
+    pub transaction_amount: Option<Money>,
+    /// The fee of the transaction, negative when charged.
+    pub fee_amount: Option<Money>,
+    /// The status of the transaction.
+    pub transaction_status: Option<TransactionStatus>,
+    /// The subject of the transaction.
+    pub transaction_subject: Option<String>,
+    /// The note of the transaction.
+    pub transaction_note: Option<String>,
+    /// The invoice id sent with the transaction.
+    pub invoice_id: Option<String>,
+    /// The merchant-provided custom text, the custom id of order payments.
+    pub custom_field: Option<String>,
+    /// Whether the transaction is eligible for protection.
+    pub protection_eligibility: Option<String>,
+}
+
+/// A transaction and its details.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TransactionDetail {
+    /// The information of the transaction.
+    pub transaction_info: TransactionInfo,
+    /// The payer information, only returned when requested with the `fields` parameter.
+    pub payer_info: Option<serde_json::Value>,
+    /// The shipping information, only returned when requested with the `fields` parameter.
+    pub shipping_info: Option<serde_json::Value>,
+    /// The cart information, only returned when requested with the `fields` parameter.
+    pub cart_info: Option<serde_json::Value>,
+}
+
+/// A page of the transactions of an account.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TransactionList {
+    /// The transactions.
+    #[serde(default)]
+    pub transaction_details: Vec<TransactionDetail>,
+    /// The merchant account number.
+    pub account_number: Option<String>,
+    /// The start date and time of the search.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The end date and time of the search.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the data was last refreshed, later transactions are not searchable yet.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub last_refreshed_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /// The page number.
+    pub page: Option<i32>,
+    /// The total number of transactions.
+    pub total_items: Option<i32>,
+    /// The total number of pages.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(TransactionList);
@@ -0,0 +1,339 @@
+//! Paypal object definitions used by the payment method tokens (vault v3) api.
+
+use super::common::{Address, LinkDescription, LinkRel, Links, Locale};
+use super::orders::{CardBrand, ShippingPreference};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The customer who owns the vaulted payment method.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct Customer {
+    /// The PayPal-generated customer id. If omitted when creating a setup token, paypal generates one.
+    pub id: Option<String>,
+    /// The merchant's own id for this customer.
+    pub merchant_customer_id: Option<String>,
+}
+
+/// A card to vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultCard {
+    /// The card number.
+    pub number: Option<String>,
+    /// The card expiration date, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The card holder's name as it appears on the card.
+    pub name: Option<String>,
+    /// The three or four digit security code of the card.
+    pub security_code: Option<String>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+}
+
+/// The pattern in which the vaulted payment method will be charged.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum UsagePattern {
+    /// On-demand instant payments, non-recurring, pre-paid, variable amount, variable frequency.
+    Immediate,
+    /// Pay after use, non-recurring, post-paid, variable amount, irregular frequency.
+    Deferred,
+    /// Pay upfront fixed or variable amount on a fixed date before the goods or service is delivered.
+    RecurringPrepaid,
+    /// Pay on a fixed date based on usage or consumption after the goods or service is delivered.
+    RecurringPostpaid,
+    /// Charge payer when the set amount is reached or a given threshold is met, before the goods are delivered.
+    ThresholdPrepaid,
+    /// Charge payer when the set amount is reached or a given threshold is met, after the goods are delivered.
+    ThresholdPostpaid,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Who will charge the vaulted payment method.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum UsageType {
+    /// The merchant charges the payment method.
+    #[default]
+    Merchant,
+    /// The platform charges the payment method on behalf of the merchant.
+    Platform,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The type of customer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum CustomerType {
+    /// A consumer.
+    #[default]
+    Consumer,
+    /// A business.
+    Business,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Customizes the payer experience during the vaulting approval.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+    /// The BCP 47-formatted locale of the pages shown to the payer.
+    pub locale: Option<Locale>,
+    /// The shipping preference.
+    pub shipping_preference: Option<ShippingPreference>,
+    /// The URL where the payer is redirected after approving.
+    pub return_url: Option<String>,
+    /// The URL where the payer is redirected after cancelling.
+    pub cancel_url: Option<String>,
+}
+
+/// A PayPal wallet to vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct VaultPaypal {
+    /// The description shown to the payer when approving the agreement.
+    pub description: Option<String>,
+    /// The pattern in which the payment method will be charged.
+    pub usage_pattern: Option<UsagePattern>,
+    /// Who will charge the payment method.
+    pub usage_type: Option<UsageType>,
+    /// The type of customer.
+    pub customer_type: Option<CustomerType>,
+    /// Whether multiple payment tokens can be created for the same payer.
+    pub permit_multiple_payment_tokens: Option<bool>,
+    /// Customizes the payer experience during the approval.
+    pub experience_context: Option<VaultExperienceContext>,
+}
+
+/// The payment method to vault.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SetupTokenPaymentSource {
+    /// A card to vault.
+    pub card: Option<VaultCard>,
+    /// A PayPal wallet to vault, which requires the payer approval.
+    pub paypal: Option<VaultPaypal>,
+}
+
+/// The setup token payload, used to save a payment method without a purchase.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct SetupTokenPayload {
+    /// The customer who owns the payment method.
+    pub customer: Option<Customer>,
+    /// The payment method to vault.
+    pub payment_source: SetupTokenPaymentSource,
+}
+
+/// The status of a setup token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SetupTokenStatus {
+    /// The setup token was created.
+    Created,
+    /// The payer must approve the setup token, through the `approve` link.
+    PayerActionRequired,
+    /// The payer approved the setup token, it can be converted to a payment token.
+    Approved,
+    /// The payment method was vaulted.
+    Vaulted,
+    /// The payment method was tokenized.
+    Tokenized,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A vaulted card.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VaultCardResponse {
+    /// The card holder's name.
+    pub name: Option<String>,
+    /// The last digits of the card.
+    pub last_digits: Option<String>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The card expiration date, in `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// The billing address of the card.
+    pub billing_address: Option<Address>,
+}
+
+/// A vaulted PayPal wallet.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VaultPaypalResponse {
+    /// The description of the agreement.
+    pub description: Option<String>,
+    /// The pattern in which the payment method will be charged.
+    pub usage_pattern: Option<UsagePattern>,
+    /// Who will charge the payment method.
+    pub usage_type: Option<UsageType>,
+    /// The type of customer.
+    pub customer_type: Option<CustomerType>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The PayPal-assigned ID for the payer.
+    pub payer_id: Option<String>,
+    /// The shipping address of the payer.
+    pub shipping: Option<super::orders::ShippingDetail>,
+}
+
+/// A vaulted payment method.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VaultPaymentSourceResponse {
+    /// A vaulted card.
+    pub card: Option<VaultCardResponse>,
+    /// A vaulted PayPal wallet.
+    pub paypal: Option<VaultPaypalResponse>,
+}
+
+/// A setup token, a temporary reference to a payment method pending approval.
+///
+/// <https://developer.paypal.com/docs/api/payment-tokens/v3/#setup-tokens_create>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SetupToken {
+    /// The PayPal-generated id of the setup token.
+    pub id: String,
+    /// The customer who owns the payment method.
+    pub customer: Option<Customer>,
+    /// The status of the setup token.
+    pub status: SetupTokenStatus,
+    /// The payment method to vault.
+    pub payment_source: Option<VaultPaymentSourceResponse>,
+    /// An array of request-related HATEOAS links. Use the `approve` link to get the payer approval.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl SetupToken {
+    /// Returns the url the payer must visit to approve the vaulting, if required.
+    pub fn approve_url(&self) -> Option<&str> {
+        self.href(LinkRel::Approve)
+    }
+
+    /// Whether the payer approved the setup token, so it can be exchanged for a payment token.
+    pub fn is_approved(&self) -> bool {
+        self.status == SetupTokenStatus::Approved
+    }
+}
+
+/// The type of token referenced when creating a payment token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum TokenType {
+    /// An approved setup token.
+    #[default]
+    SetupToken,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A reference to the token the payment token is created from.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(into))]
+pub struct TokenReference {
+    /// The id of the token.
+    pub id: String,
+    /// The type of the token.
+    #[builder(default)]
+    pub r#type: TokenType,
+}
+
+/// The source of a payment token.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaymentTokenSource {
+    /// The token the payment token is created from.
+    pub token: TokenReference,
+}
+
+/// The payment token payload.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaymentTokenPayload {
+    /// The customer who owns the payment method.
+    pub customer: Option<Customer>,
+    /// The source of the payment token.
+    pub payment_source: PaymentTokenSource,
+}
+
+impl PaymentTokenPayload {
+    /// Creates a payload that exchanges the given approved setup token.
+    pub fn from_setup_token(setup_token_id: impl ToString) -> Self {
+        Self {
+            customer: None,
+            payment_source: PaymentTokenSource {
+                token: TokenReference {
+                    id: setup_token_id.to_string(),
+                    r#type: TokenType::SetupToken,
+                },
+            },
+        }
+    }
+}
+
+/// A payment token, a permanent reference to a vaulted payment method.
+///
+/// Its id can be used as the `vault_id` when creating orders.
+///
+/// <https://developer.paypal.com/docs/api/payment-tokens/v3/#payment-tokens_create>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PaymentToken {
+    /// The PayPal-generated id of the payment token.
+    pub id: String,
+    /// The customer who owns the payment method.
+    pub customer: Option<Customer>,
+    /// The vaulted payment method.
+    pub payment_source: VaultPaymentSourceResponse,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A page of the payment tokens of a customer.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PaymentTokenList {
+    /// The customer who owns the payment tokens.
+    pub customer: Option<Customer>,
+    /// The payment tokens.
+    #[serde(default)]
+    pub payment_tokens: Vec<PaymentToken>,
+    /// The total number of payment tokens, only returned when `total_required` is set.
+    pub total_items: Option<i32>,
+    /// The total number of pages, only returned when `total_required` is set.
+    pub total_pages: Option<i32>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl_links!(SetupToken, PaymentToken, PaymentTokenList);
@@ -0,0 +1,107 @@
+//! Paypal object definitions used by the payment experience web profiles api.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// The type of landing page to show on the paypal site for customer checkout.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum LandingPageType {
+    /// Shows the non-PayPal account page.
+    Billing,
+    /// Shows the paypal account login page.
+    Login,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The HTTP method to use to redirect the user to a return URL.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ReturnUriHttpMethod {
+    /// Redirect with a GET request.
+    Get,
+    /// Redirect with a POST request.
+    Post,
+    /// A value not known by this crate.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Parameters for the flow configuration of the web experience profile.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct FlowConfig {
+    /// The type of landing page to show on the paypal site for customer checkout.
+    pub landing_page_type: Option<LandingPageType>,
+    /// The merchant site URL to display after a bank transfer payment.
+    pub bank_txn_pending_url: Option<String>,
+    /// Set to `commit` to show a Pay Now button instead of a Continue button in the checkout flow.
+    pub user_action: Option<String>,
+    /// The HTTP method to use to redirect the user to a return URL.
+    pub return_uri_http_method: Option<ReturnUriHttpMethod>,
+}
+
+/// Parameters for the input fields customization of the web experience profile.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct InputFields {
+    /// Indicates whether the buyer can enter a note to the merchant on the paypal page during checkout.
+    pub allow_note: Option<bool>,
+    /// Indicates whether paypal displays shipping address fields on the experience pages.
+    ///
+    /// `0` displays the fields, `1` hides them and `2` gets the address from the buyer's account.
+    pub no_shipping: Option<u8>,
+    /// Indicates whether to display the shipping address that is passed to this call rather than the one on file.
+    ///
+    /// `0` displays the address on file and `1` displays the supplied address.
+    pub address_override: Option<u8>,
+}
+
+/// Parameters for the style and presentation of the web experience profile.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct Presentation {
+    /// A label that overrides the business name in the merchant's paypal account on the checkout pages.
+    pub brand_name: Option<String>,
+    /// A URL to the logo image. A valid media type is .gif, .jpg, or .png.
+    pub logo_image: Option<String>,
+    /// The locale of pages that the paypal payment experience displays.
+    pub locale_code: Option<String>,
+    /// A label to use as hypertext for the return to merchant link.
+    pub return_url_label: Option<String>,
+    /// A label to use as the title for the note to seller field.
+    pub note_to_seller_label: Option<String>,
+}
+
+/// A payment web experience profile.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct WebProfile {
+    /// The ID of the web experience profile, set by paypal.
+    pub id: Option<String>,
+    /// The web experience profile name. Unique for a specified merchant's profiles.
+    pub name: String,
+    /// Indicates whether the profile persists for three hours or permanently.
+    pub temporary: Option<bool>,
+    /// Parameters for the flow configuration of the web experience profile.
+    pub flow_config: Option<FlowConfig>,
+    /// Parameters for the input fields customization of the web experience profile.
+    pub input_fields: Option<InputFields>,
+    /// Parameters for the style and presentation of the web experience profile.
+    pub presentation: Option<Presentation>,
+}
+
+/// The response of creating a web experience profile.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreatedWebProfile {
+    /// The ID of the created web experience profile.
+    pub id: String,
+}
@@ -0,0 +1,490 @@
+//! Paypal object definitions used by the webhooks api.
+
+use super::common::LinkDescription;
+use super::date_time::Rfc3339;
+use super::ids::{AuthorizationId, CaptureId, InvoiceId, OrderId, RefundId, SubscriptionId, WebhookId};
+use super::payouts::{PayoutBatch, PayoutItemDetails};
+use crate::errors::VerifyWebhookError;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use serde_with::{serde_as, skip_serializing_none};
+use std::convert::Infallible;
+use std::str::FromStr;
+
+string_enum! {
+    /// The type of a webhook event.
+    ///
+    /// <https://developer.paypal.com/api/rest/webhooks/event-names/>
+    pub enum EventType {
+        /// An event type not known by this crate.
+        Unknown(String),
+        /// Subscribes a webhook to all the events.
+        All = "*",
+        /// A buyer approved a checkout order.
+        CheckoutOrderApproved = "CHECKOUT.ORDER.APPROVED",
+        /// A checkout order was completed.
+        CheckoutOrderCompleted = "CHECKOUT.ORDER.COMPLETED",
+        /// A checkout order was saved.
+        CheckoutOrderSaved = "CHECKOUT.ORDER.SAVED",
+        /// A checkout order was voided.
+        CheckoutOrderVoided = "CHECKOUT.ORDER.VOIDED",
+        /// The payment approval of a checkout order was reversed because the order could not be completed in time.
+        CheckoutPaymentApprovalReversed = "CHECKOUT.PAYMENT-APPROVAL.REVERSED",
+        /// A payment authorization was created, approved, executed, or a future payment authorization was created.
+        PaymentAuthorizationCreated = "PAYMENT.AUTHORIZATION.CREATED",
+        /// A payment authorization was voided.
+        PaymentAuthorizationVoided = "PAYMENT.AUTHORIZATION.VOIDED",
+        /// A payment capture completed.
+        PaymentCaptureCompleted = "PAYMENT.CAPTURE.COMPLETED",
+        /// A payment capture was declined.
+        PaymentCaptureDeclined = "PAYMENT.CAPTURE.DECLINED",
+        /// A payment capture was denied.
+        PaymentCaptureDenied = "PAYMENT.CAPTURE.DENIED",
+        /// The state of a payment capture changed to pending.
+        PaymentCapturePending = "PAYMENT.CAPTURE.PENDING",
+        /// A merchant refunded a payment capture.
+        PaymentCaptureRefunded = "PAYMENT.CAPTURE.REFUNDED",
+        /// PayPal reversed a payment capture.
+        PaymentCaptureReversed = "PAYMENT.CAPTURE.REVERSED",
+        /// A refund was cancelled.
+        PaymentRefundCancelled = "PAYMENT.REFUND.CANCELLED",
+        /// A refund failed.
+        PaymentRefundFailed = "PAYMENT.REFUND.FAILED",
+        /// A refund is pending.
+        PaymentRefundPending = "PAYMENT.REFUND.PENDING",
+        /// A payouts batch was denied.
+        PaymentPayoutsBatchDenied = "PAYMENT.PAYOUTSBATCH.DENIED",
+        /// The state of a payouts batch changed to processing.
+        PaymentPayoutsBatchProcessing = "PAYMENT.PAYOUTSBATCH.PROCESSING",
+        /// A payouts batch completed successfully.
+        PaymentPayoutsBatchSuccess = "PAYMENT.PAYOUTSBATCH.SUCCESS",
+        /// A payouts item was blocked.
+        PaymentPayoutsItemBlocked = "PAYMENT.PAYOUTS-ITEM.BLOCKED",
+        /// A payouts item was cancelled.
+        PaymentPayoutsItemCanceled = "PAYMENT.PAYOUTS-ITEM.CANCELED",
+        /// A payouts item was denied.
+        PaymentPayoutsItemDenied = "PAYMENT.PAYOUTS-ITEM.DENIED",
+        /// A payouts item failed.
+        PaymentPayoutsItemFailed = "PAYMENT.PAYOUTS-ITEM.FAILED",
+        /// A payouts item is held.
+        PaymentPayoutsItemHeld = "PAYMENT.PAYOUTS-ITEM.HELD",
+        /// A payouts item was refunded.
+        PaymentPayoutsItemRefunded = "PAYMENT.PAYOUTS-ITEM.REFUNDED",
+        /// A payouts item was returned.
+        PaymentPayoutsItemReturned = "PAYMENT.PAYOUTS-ITEM.RETURNED",
+        /// A payouts item succeeded.
+        PaymentPayoutsItemSucceeded = "PAYMENT.PAYOUTS-ITEM.SUCCEEDED",
+        /// A payouts item is unclaimed.
+        PaymentPayoutsItemUnclaimed = "PAYMENT.PAYOUTS-ITEM.UNCLAIMED",
+        /// A billing plan was created.
+        BillingPlanCreated = "BILLING.PLAN.CREATED",
+        /// A billing plan was updated.
+        BillingPlanUpdated = "BILLING.PLAN.UPDATED",
+        /// A billing plan was activated.
+        BillingPlanActivated = "BILLING.PLAN.ACTIVATED",
+        /// A billing plan was deactivated.
+        BillingPlanDeactivated = "BILLING.PLAN.DEACTIVATED",
+        /// The pricing of a billing plan was updated.
+        BillingPlanPricingChangeActivated = "BILLING.PLAN.PRICING-CHANGE.ACTIVATED",
+        /// A subscription was created.
+        BillingSubscriptionCreated = "BILLING.SUBSCRIPTION.CREATED",
+        /// A subscription was activated.
+        BillingSubscriptionActivated = "BILLING.SUBSCRIPTION.ACTIVATED",
+        /// A subscription was updated.
+        BillingSubscriptionUpdated = "BILLING.SUBSCRIPTION.UPDATED",
+        /// A subscription expired.
+        BillingSubscriptionExpired = "BILLING.SUBSCRIPTION.EXPIRED",
+        /// A subscription was cancelled.
+        BillingSubscriptionCancelled = "BILLING.SUBSCRIPTION.CANCELLED",
+        /// A subscription was suspended.
+        BillingSubscriptionSuspended = "BILLING.SUBSCRIPTION.SUSPENDED",
+        /// A payment failed for a subscription.
+        BillingSubscriptionPaymentFailed = "BILLING.SUBSCRIPTION.PAYMENT.FAILED",
+        /// A payment was made on a subscription.
+        PaymentSaleCompleted = "PAYMENT.SALE.COMPLETED",
+        /// A payment on a subscription was refunded.
+        PaymentSaleRefunded = "PAYMENT.SALE.REFUNDED",
+        /// A payment on a subscription was reversed.
+        PaymentSaleReversed = "PAYMENT.SALE.REVERSED",
+        /// A product was created.
+        CatalogProductCreated = "CATALOG.PRODUCT.CREATED",
+        /// A product was updated.
+        CatalogProductUpdated = "CATALOG.PRODUCT.UPDATED",
+        /// A merchant or customer cancelled an invoice.
+        InvoicingInvoiceCancelled = "INVOICING.INVOICE.CANCELLED",
+        /// An invoice was created.
+        InvoicingInvoiceCreated = "INVOICING.INVOICE.CREATED",
+        /// An invoice was paid, partially paid, or payment was made and is pending.
+        InvoicingInvoicePaid = "INVOICING.INVOICE.PAID",
+        /// An invoice was refunded or partially refunded.
+        InvoicingInvoiceRefunded = "INVOICING.INVOICE.REFUNDED",
+        /// An invoice was scheduled.
+        InvoicingInvoiceScheduled = "INVOICING.INVOICE.SCHEDULED",
+        /// An invoice was updated.
+        InvoicingInvoiceUpdated = "INVOICING.INVOICE.UPDATED",
+        /// A dispute was created.
+        CustomerDisputeCreated = "CUSTOMER.DISPUTE.CREATED",
+        /// A dispute was resolved.
+        CustomerDisputeResolved = "CUSTOMER.DISPUTE.RESOLVED",
+        /// A dispute was updated.
+        CustomerDisputeUpdated = "CUSTOMER.DISPUTE.UPDATED",
+        /// A merchant completed the onboarding.
+        MerchantOnboardingCompleted = "MERCHANT.ONBOARDING.COMPLETED",
+        /// The consent granted to the partner was revoked.
+        MerchantPartnerConsentRevoked = "MERCHANT.PARTNER-CONSENT.REVOKED",
+        /// A payment method was saved in the vault.
+        VaultPaymentTokenCreated = "VAULT.PAYMENT-TOKEN.CREATED",
+        /// A payment token was deleted.
+        VaultPaymentTokenDeleted = "VAULT.PAYMENT-TOKEN.DELETED",
+        /// The deletion of a payment token was initiated.
+        VaultPaymentTokenDeletionInitiated = "VAULT.PAYMENT-TOKEN.DELETION-INITIATED",
+    }
+}
+
+impl FromStr for EventType {
+    type Err = Infallible;
+
+    /// Parses an event type, types not known by this crate are kept in [EventType::Unknown].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(EventType::from(s))
+    }
+}
+
+/// An event type a webhook subscribes to, as listed when creating a webhook.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WebhookEventType {
+    /// The unique event name.
+    pub name: EventType,
+    /// A human-readable description of the event.
+    pub description: Option<String>,
+    /// The status of a webhook event.
+    pub status: Option<String>,
+}
+
+impl From<EventType> for WebhookEventType {
+    fn from(name: EventType) -> Self {
+        WebhookEventType {
+            name,
+            description: None,
+            status: None,
+        }
+    }
+}
+
+/// The webhook to create.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WebhookPayload {
+    /// The URL that is configured to listen on localhost for incoming POST notification messages.
+    pub url: String,
+    /// The events the webhook subscribes to.
+    pub event_types: Vec<WebhookEventType>,
+}
+
+impl WebhookPayload {
+    /// A webhook listening at the url to the given events.
+    pub fn new(url: impl ToString, event_types: impl IntoIterator<Item = EventType>) -> Self {
+        Self {
+            url: url.to_string(),
+            event_types: event_types.into_iter().map(WebhookEventType::from).collect(),
+        }
+    }
+}
+
+/// A webhook subscribed to events of the account.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    /// The ID of the webhook.
+    pub id: WebhookId,
+    /// The URL the events are posted to.
+    pub url: String,
+    /// The events the webhook subscribes to.
+    #[serde(default)]
+    pub event_types: Vec<WebhookEventType>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The webhooks of the account.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WebhookList {
+    /// The webhooks.
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// A webhook event sent by paypal.
+///
+/// <https://developer.paypal.com/api/rest/webhooks/rest/#event-type-payload>
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct WebhookEvent {
+    /// The ID of the webhook event notification.
+    pub id: String,
+    /// The date and time when the webhook event notification was created.
+    #[serde_as(as = "Option<Rfc3339>")]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The name of the resource related to the webhook notification event, for example `capture`.
+    pub resource_type: Option<String>,
+    /// The event that triggered the webhook event notification.
+    pub event_type: EventType,
+    /// The event version in the webhook notification.
+    pub event_version: Option<String>,
+    /// A summary description for the event notification.
+    pub summary: Option<String>,
+    /// The resource version in the webhook notification.
+    pub resource_version: Option<String>,
+    /// The resource that triggered the webhook event notification, its model depends on the event type.
+    pub resource: serde_json::Value,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The kind of resource a webhook event is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResourceKind {
+    /// A checkout order.
+    Order,
+    /// A captured payment.
+    Capture,
+    /// An authorized payment.
+    Authorization,
+    /// A refund.
+    Refund,
+    /// A subscription.
+    Subscription,
+    /// A payment of a subscription.
+    Sale,
+    /// An invoice.
+    Invoice,
+    /// A payout batch.
+    PayoutBatch,
+    /// A payout of a batch.
+    PayoutItem,
+}
+
+/// The ids of the resources a webhook event relates to.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RelatedIds {
+    /// The id of the order.
+    pub order_id: Option<OrderId>,
+    /// The id of the captured payment.
+    pub capture_id: Option<CaptureId>,
+    /// The id of the authorized payment.
+    pub authorization_id: Option<AuthorizationId>,
+    /// The id of the refund.
+    pub refund_id: Option<RefundId>,
+    /// The id of the subscription.
+    pub subscription_id: Option<SubscriptionId>,
+    /// The id of the invoice.
+    pub invoice_id: Option<InvoiceId>,
+    /// The id of the payout batch.
+    pub payout_batch_id: Option<String>,
+    /// The id of the payout item.
+    pub payout_item_id: Option<String>,
+}
+
+impl WebhookEvent {
+    /// Returns the kind of the resource of the event, from its resource type or else its event type.
+    pub fn resource_kind(&self) -> Option<ResourceKind> {
+        let kind = match self.resource_type.as_deref() {
+            Some("checkout-order") => ResourceKind::Order,
+            Some("capture") => ResourceKind::Capture,
+            Some("authorization") => ResourceKind::Authorization,
+            Some("refund") => ResourceKind::Refund,
+            Some("subscription") => ResourceKind::Subscription,
+            Some("sale") => ResourceKind::Sale,
+            Some("invoices") => ResourceKind::Invoice,
+            Some("payouts") => ResourceKind::PayoutBatch,
+            Some("payouts_item") => ResourceKind::PayoutItem,
+            _ => {
+                // The resource of a refunded capture is the refund.
+                if self.event_type == EventType::PaymentCaptureRefunded {
+                    return Some(ResourceKind::Refund);
+                }
+                let event_type = self.event_type.as_str();
+                [
+                    ("CHECKOUT.ORDER.", ResourceKind::Order),
+                    ("PAYMENT.CAPTURE.", ResourceKind::Capture),
+                    ("PAYMENT.AUTHORIZATION.", ResourceKind::Authorization),
+                    ("PAYMENT.REFUND.", ResourceKind::Refund),
+                    ("BILLING.SUBSCRIPTION.", ResourceKind::Subscription),
+                    ("PAYMENT.SALE.", ResourceKind::Sale),
+                    ("INVOICING.INVOICE.", ResourceKind::Invoice),
+                    ("PAYMENT.PAYOUTSBATCH.", ResourceKind::PayoutBatch),
+                    ("PAYMENT.PAYOUTS-ITEM.", ResourceKind::PayoutItem),
+                ]
+                .into_iter()
+                .find(|(prefix, _)| event_type.starts_with(prefix))?
+                .1
+            }
+        };
+        Some(kind)
+    }
+
+    /// Returns the id of the resource of the event.
+    pub fn resource_id(&self) -> Option<&str> {
+        let resource = match self.resource_kind() {
+            // Invoice events wrap the invoice.
+            Some(ResourceKind::Invoice) if self.resource.get("invoice").is_some() => &self.resource["invoice"],
+            Some(ResourceKind::PayoutBatch) => return self.resource["batch_header"]["payout_batch_id"].as_str(),
+            Some(ResourceKind::PayoutItem) => return self.resource["payout_item_id"].as_str(),
+            _ => &self.resource,
+        };
+        resource.get("id")?.as_str()
+    }
+
+    /// Returns the ids of the resource of the event and of the resources it relates to.
+    ///
+    /// They are taken from the resource itself, its supplementary data, such as the order of a capture, and the links
+    /// of the resource, such as the capture a refund is for. Subscription payments relate to their subscription.
+    pub fn related_ids(&self) -> RelatedIds {
+        let mut ids = RelatedIds::default();
+        if let Some(id) = self.resource_id() {
+            match self.resource_kind() {
+                Some(ResourceKind::Order) => ids.order_id = Some(id.into()),
+                Some(ResourceKind::Capture) => ids.capture_id = Some(id.into()),
+                Some(ResourceKind::Authorization) => ids.authorization_id = Some(id.into()),
+                Some(ResourceKind::Refund) => ids.refund_id = Some(id.into()),
+                Some(ResourceKind::Subscription) => ids.subscription_id = Some(id.into()),
+                Some(ResourceKind::Invoice) => ids.invoice_id = Some(id.into()),
+                Some(ResourceKind::PayoutBatch) => ids.payout_batch_id = Some(id.to_string()),
+                Some(ResourceKind::PayoutItem) => ids.payout_item_id = Some(id.to_string()),
+                Some(ResourceKind::Sale) | None => {}
+            }
+        }
+
+        let string = |value: &serde_json::Value| value.as_str().map(str::to_string);
+        let related = &self.resource["supplementary_data"]["related_ids"];
+        ids.order_id = ids.order_id.or_else(|| string(&related["order_id"]).map(OrderId::new));
+        ids.capture_id = ids
+            .capture_id
+            .or_else(|| string(&related["capture_id"]).map(CaptureId::new));
+        ids.authorization_id = ids
+            .authorization_id
+            .or_else(|| string(&related["authorization_id"]).map(AuthorizationId::new));
+        ids.subscription_id = ids
+            .subscription_id
+            .or_else(|| string(&self.resource["billing_agreement_id"]).map(SubscriptionId::new));
+        // Payout items carry the id of their batch.
+        ids.payout_batch_id = ids
+            .payout_batch_id
+            .or_else(|| string(&self.resource["payout_batch_id"]));
+
+        let links = self.resource["links"].as_array().into_iter().flatten();
+        for href in links.filter_map(|link| link["href"].as_str()) {
+            if let Some(id) = path_id(href, "/v2/checkout/orders/") {
+                ids.order_id.get_or_insert_with(|| id.into());
+            } else if let Some(id) = path_id(href, "/v2/payments/captures/") {
+                ids.capture_id.get_or_insert_with(|| id.into());
+            } else if let Some(id) = path_id(href, "/v2/payments/authorizations/") {
+                ids.authorization_id.get_or_insert_with(|| id.into());
+            } else if let Some(id) = path_id(href, "/v1/billing/subscriptions/") {
+                ids.subscription_id.get_or_insert_with(|| id.into());
+            }
+        }
+        ids
+    }
+
+    /// Parses the payout batch of a `PAYMENT.PAYOUTSBATCH.*` event, such as a batch that was processed.
+    ///
+    /// Returns `None` for the other events. The batch of an event has no items, they have their own events.
+    pub fn payout_batch(&self) -> Result<Option<PayoutBatch>, serde_json::Error> {
+        if self.resource_kind() != Some(ResourceKind::PayoutBatch) {
+            return Ok(None);
+        }
+        PayoutBatch::deserialize(&self.resource).map(Some)
+    }
+
+    /// Parses the payout of a `PAYMENT.PAYOUTS-ITEM.*` event, such as a payout that succeeded, was unclaimed or
+    /// returned.
+    ///
+    /// Returns `None` for the other events.
+    pub fn payout_item(&self) -> Result<Option<PayoutItemDetails>, serde_json::Error> {
+        if self.resource_kind() != Some(ResourceKind::PayoutItem) {
+            return Ok(None);
+        }
+        PayoutItemDetails::deserialize(&self.resource).map(Some)
+    }
+}
+
+/// Returns the id following the prefix in the path of the url.
+fn path_id<'a>(href: &'a str, prefix: &str) -> Option<&'a str> {
+    let (_, rest) = href.split_once(prefix)?;
+    let id = rest.split(['/', '?']).next()?;
+    (!id.is_empty()).then_some(id)
+}
+
+/// The transmission headers paypal signs a webhook event with.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WebhookTransmission {
+    /// The algorithm of the signature, from the `PAYPAL-AUTH-ALGO` header, for example `SHA256withRSA`.
+    pub auth_algo: String,
+    /// The url of the certificate to verify the signature with, from the `PAYPAL-CERT-URL` header.
+    pub cert_url: String,
+    /// The ID of the transmission, from the `PAYPAL-TRANSMISSION-ID` header.
+    pub transmission_id: String,
+    /// The base64 encoded signature, from the `PAYPAL-TRANSMISSION-SIG` header.
+    pub transmission_sig: String,
+    /// The date and time of the transmission, from the `PAYPAL-TRANSMISSION-TIME` header, as sent.
+    pub transmission_time: String,
+}
+
+impl WebhookTransmission {
+    /// Reads the transmission headers of a webhook event request.
+    #[allow(clippy::result_large_err)]
+    pub fn from_headers(headers: &HeaderMap) -> Result<Self, VerifyWebhookError> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+                .ok_or(VerifyWebhookError::MissingHeader(name))
+        };
+        Ok(Self {
+            auth_algo: header("PAYPAL-AUTH-ALGO")?,
+            cert_url: header("PAYPAL-CERT-URL")?,
+            transmission_id: header("PAYPAL-TRANSMISSION-ID")?,
+            transmission_sig: header("PAYPAL-TRANSMISSION-SIG")?,
+            transmission_time: header("PAYPAL-TRANSMISSION-TIME")?,
+        })
+    }
+}
+
+/// The webhook event to verify the signature of.
+#[derive(Debug, Serialize, Clone)]
+pub struct VerifyWebhookSignaturePayload {
+    /// The transmission headers of the event.
+    #[serde(flatten)]
+    pub transmission: WebhookTransmission,
+    /// The ID of the webhook the event was sent to.
+    pub webhook_id: WebhookId,
+    /// The event, as received, since paypal verifies the signature of its exact body.
+    pub webhook_event: Box<RawValue>,
+}
+
+/// The status of a webhook signature verification.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum VerificationStatus {
+    /// The signature is valid.
+    Success,
+    /// The signature is not valid.
+    Failure,
+}
+
+/// The result of a webhook signature verification.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct VerifyWebhookSignatureResponse {
+    /// The status of the signature verification.
+    pub verification_status: VerificationStatus,
+}
+
+impl_links!(Webhook, WebhookEvent);
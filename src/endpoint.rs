@@ -1,19 +1,33 @@
 //! This module contains the endpoint trait used to implemented api endpoints.
 
-use serde::{de::DeserializeOwned, Serialize};
+use crate::{
+    data::common::{LinkDescription, LinkRel, Links},
+    errors::ResponseError,
+};
+use serde::{de::DeserializeOwned, Serialize, Serializer};
 use std::borrow::Cow;
 
 /// A trait implemented by api endpoints.
 pub trait Endpoint {
     /// The serializable query type.
+    ///
+    /// Sequences are sent as repeated parameters (`a=1&a=2`), use [serialize_comma_separated] for the parameters
+    /// taking comma-separated lists instead.
     type Query: Serialize;
     /// The serializable body type.
-    type Body: Serialize;
+    type Body: Serialize + Clone;
     /// The deserializable response type.
     type Response: DeserializeOwned;
 
-    /// The endpoint relative path. Must start with a `/`
-    fn relative_path(&self) -> Cow<str>;
+    /// The endpoint relative path, including the api version. Must start with a `/`
+    fn relative_path(&self) -> Cow<'_, str>;
+
+    /// The url this endpoint is sent to, overriding the one of the client.
+    ///
+    /// Used by the apis served from another host or gateway, the path prefix of the url is kept.
+    fn base_url(&self) -> Option<Cow<'_, str>> {
+        None
+    }
 
     /// The request method of this endpoint.
     fn method(&self) -> reqwest::Method;
@@ -24,7 +38,107 @@ pub trait Endpoint {
     }
 
     /// The body to be used when calling this endpoint.
-    fn body(&self) -> Option<Self::Body> {
+    ///
+    /// Borrow the payload with [Cow::Borrowed] to avoid cloning it on every request.
+    fn body(&self) -> Option<Cow<'_, Self::Body>> {
+        None
+    }
+
+    /// The multipart form to be sent when calling this endpoint, used to upload files.
+    ///
+    /// Only used if [Endpoint::body] returns `None`.
+    fn form(&self) -> Option<reqwest::multipart::Form> {
         None
     }
+
+    /// Parses the body of a successful response.
+    ///
+    /// Defaults to deserializing it as json, endpoints returning other content types should override it.
+    #[allow(clippy::result_large_err)]
+    fn parse_response(&self, body: bytes::Bytes) -> Result<Self::Response, ResponseError> {
+        serde_json::from_slice(&body).map_err(ResponseError::ParseError)
+    }
+}
+
+/// A trait implemented by endpoints returning paginated lists.
+///
+/// Used by [Client::execute_paginated](crate::Client::execute_paginated) to fetch all the pages.
+pub trait PageableEndpoint: Endpoint + Clone {
+    /// Returns the endpoint fetching the page after the given one, or `None` if it was the last page.
+    fn next_page(&self, response: &Self::Response) -> Option<Self>;
+}
+
+/// A trait implemented by endpoints returning large lists, whose items can be parsed one by one as the response is
+/// read.
+///
+/// Used by [Client::execute_items](crate::Client::execute_items) to stream the items.
+pub trait ItemsEndpoint: Endpoint {
+    /// The type of the items.
+    type Item: DeserializeOwned;
+
+    /// The name of the field of the response holding the array of items.
+    const ITEMS_FIELD: &'static str;
+}
+
+/// Returns the page after `page` if the response links to a next page or there are more pages in total.
+pub(crate) fn next_page_number(page: Option<i32>, total_pages: Option<i32>, links: &[LinkDescription]) -> Option<i32> {
+    let page = page.unwrap_or(1);
+    let has_next = links.find_rel(LinkRel::Next).is_some() || total_pages.is_some_and(|total_pages| page < total_pages);
+    has_next.then_some(page + 1)
+}
+
+/// Serializes a list as a single comma-separated value, as the `fields` parameters of some apis expect.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct ListQuery {
+///     #[serde(serialize_with = "paypal_rs::endpoint::serialize_comma_separated")]
+///     fields: Option<Vec<String>>,
+/// }
+///
+/// let query = ListQuery { fields: Some(vec!["id".to_string(), "status".to_string()]) };
+/// assert_eq!(serde_json::to_string(&query).unwrap(), r#"{"fields":"id,status"}"#);
+/// ```
+pub fn serialize_comma_separated<S, T>(values: &Option<Vec<T>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: std::fmt::Display,
+{
+    match values {
+        Some(values) => {
+            let value = values.iter().map(ToString::to_string).collect::<Vec<_>>().join(",");
+            serializer.serialize_str(&value)
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serializes the query of an endpoint, sending sequences as repeated parameters.
+pub(crate) fn query_string<Q: Serialize>(query: &Q) -> String {
+    let query_string = serde_qs::to_string(query).expect("serialize the query correctly");
+    query_string
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => format!("{}={}", strip_index(key), value),
+            None => strip_index(pair).to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Removes the index serde_qs adds to the keys of sequence items (`a[0]`), which paypal does not understand.
+fn strip_index(key: &str) -> &str {
+    let stripped = key
+        .strip_suffix("%5D")
+        .and_then(|key| key.rsplit_once("%5B"))
+        .or_else(|| key.strip_suffix(']').and_then(|key| key.rsplit_once('[')));
+    match stripped {
+        Some((name, index)) if !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) => name,
+        _ => key,
+    }
 }
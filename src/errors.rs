@@ -1,29 +1,158 @@
 //! Errors created by this crate.
-use crate::data::common::LinkDescription;
+use crate::countries::Country;
+use crate::data::common::{Currency, LinkDescription, Money};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
+
+string_enum! {
+    /// The name of a paypal api error, which identifies what went wrong.
+    ///
+    /// <https://developer.paypal.com/api/rest/responses/>
+    pub enum ErrorName {
+        /// An error name not known by this crate.
+        Unknown(String),
+        /// The request is not well-formed, is syntactically incorrect, or violates schema.
+        InvalidRequest = "INVALID_REQUEST",
+        /// Authentication failed due to missing authorization header, or invalid authentication credentials.
+        AuthenticationFailure = "AUTHENTICATION_FAILURE",
+        /// The client is not authorized to access this resource.
+        NotAuthorized = "NOT_AUTHORIZED",
+        /// The specified resource does not exist.
+        ResourceNotFound = "RESOURCE_NOT_FOUND",
+        /// The server does not implement the requested HTTP method.
+        MethodNotSupported = "METHOD_NOT_SUPPORTED",
+        /// The server does not implement the media type that would be acceptable to the client.
+        MediaTypeNotAcceptable = "MEDIA_TYPE_NOT_ACCEPTABLE",
+        /// The server does not support the request payload's media type.
+        UnsupportedMediaType = "UNSUPPORTED_MEDIA_TYPE",
+        /// The requested action could not be performed, semantically incorrect, or failed business validation.
+        UnprocessableEntity = "UNPROCESSABLE_ENTITY",
+        /// Too many requests. Blocked due to rate limiting.
+        RateLimitReached = "RATE_LIMIT_REACHED",
+        /// An internal server error has occurred.
+        InternalServerError = "INTERNAL_SERVER_ERROR",
+        /// Service Unavailable.
+        ServiceUnavailable = "SERVICE_UNAVAILABLE",
+    }
+}
+
+impl Default for ErrorName {
+    fn default() -> Self {
+        ErrorName::Unknown(String::new())
+    }
+}
+
+string_enum! {
+    /// A frequent issue of the [details](ErrorDetails::issue) of a paypal api error.
+    ///
+    /// <https://developer.paypal.com/api/rest/reference/orders/v2/errors/>
+    pub enum Issue {
+        /// An issue not known by this crate.
+        Unknown(String),
+        /// The processor or bank declined the payment source, the payer should choose another one.
+        InstrumentDeclined = "INSTRUMENT_DECLINED",
+        /// The request was refused.
+        TransactionRefused = "TRANSACTION_REFUSED",
+        /// The payer must take an action, such as completing a 3D Secure challenge.
+        PayerActionRequired = "PAYER_ACTION_REQUIRED",
+        /// The payer has not approved the order yet.
+        OrderNotApproved = "ORDER_NOT_APPROVED",
+        /// The order was already captured.
+        OrderAlreadyCaptured = "ORDER_ALREADY_CAPTURED",
+        /// The order was already authorized.
+        OrderAlreadyAuthorized = "ORDER_ALREADY_AUTHORIZED",
+        /// The invoice id was already used by another transaction.
+        DuplicateInvoiceId = "DUPLICATE_INVOICE_ID",
+        /// The account of the payee is restricted and cannot receive payments.
+        PayeeAccountRestricted = "PAYEE_ACCOUNT_RESTRICTED",
+        /// The account of the payer is restricted and cannot make payments.
+        PayerAccountRestricted = "PAYER_ACCOUNT_RESTRICTED",
+        /// The payer cannot pay the payee.
+        PayerCannotPay = "PAYER_CANNOT_PAY",
+        /// The card expired.
+        CardExpired = "CARD_EXPIRED",
+        /// The currency is not supported.
+        CurrencyNotSupported = "CURRENCY_NOT_SUPPORTED",
+        /// The resource id does not exist.
+        InvalidResourceId = "INVALID_RESOURCE_ID",
+        /// The caller does not have permission for the action.
+        PermissionDenied = "PERMISSION_DENIED",
+    }
+}
+
+/// The details of a paypal api error, describing what is wrong in the request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ErrorDetails {
+    /// The field that caused the error, as a JSON pointer.
+    pub field: Option<String>,
+    /// The value of the field that caused the error.
+    pub value: Option<String>,
+    /// The location of the field that caused the error. Value is `body`, `path`, or `query`.
+    pub location: Option<String>,
+    /// The unique, fine-grained application-level error code, for example `INVALID_CURRENCY_CODE`.
+    pub issue: String,
+    /// The human-readable description for an issue.
+    pub description: Option<String>,
+}
 
 /// A paypal api response error.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct PaypalError {
+    /// The http status code of the response.
+    #[serde(skip)]
+    pub status: Option<reqwest::StatusCode>,
     /// The error name.
-    pub name: String,
+    #[serde(default)]
+    pub name: ErrorName,
     /// The error message.
     pub message: Option<String>,
     /// Paypal debug id
     pub debug_id: Option<String>,
     /// Error details
-    pub details: Vec<HashMap<String, String>>,
+    #[serde(default)]
+    pub details: Vec<ErrorDetails>,
     /// Only available on Identity errors
     pub error: Option<String>,
     /// Only available on Identity errors
     pub error_description: Option<String>,
     /// Links with more information about the error.
+    #[serde(default)]
     pub links: Vec<LinkDescription>,
 }
 
+impl PaypalError {
+    /// Returns whether any of the error details has the given issue.
+    pub fn has_issue(&self, issue: impl Into<Issue>) -> bool {
+        let issue = issue.into();
+        self.details.iter().any(|detail| detail.issue == issue.as_str())
+    }
+
+    /// Returns the issues of the error details.
+    pub fn issue_codes(&self) -> Vec<Issue> {
+        self.details
+            .iter()
+            .map(|detail| Issue::from(detail.issue.as_str()))
+            .collect()
+    }
+
+    /// Returns whether the request was rejected for making too many requests.
+    pub fn is_rate_limited(&self) -> bool {
+        self.name == ErrorName::RateLimitReached || self.status == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// Returns whether the same request may succeed later: rate limits and server errors.
+    pub fn is_retryable(&self) -> bool {
+        self.is_rate_limited()
+            || matches!(
+                self.name,
+                ErrorName::InternalServerError | ErrorName::ServiceUnavailable
+            )
+            || self.status.is_some_and(|status| status.is_server_error())
+    }
+}
+
 impl fmt::Display for PaypalError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:#?}", self)
@@ -39,6 +168,31 @@ pub enum ResponseError {
     ApiError(PaypalError),
     /// A http error.
     HttpError(reqwest::Error),
+    /// The response body could not be parsed.
+    ParseError(serde_json::Error),
+    /// The response body is not valid base64.
+    DecodeError(base64::DecodeError),
+    /// A header parameter of the request is not a valid header value, such as one with a newline.
+    InvalidHeader(reqwest::header::InvalidHeaderValue),
+    /// Too many requests were made (`RATE_LIMIT_REACHED`), retry after the given duration if paypal sent one.
+    RateLimited {
+        /// How long to wait before making a new request, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+        /// The paypal api error, if the response had one.
+        error: Option<PaypalError>,
+    },
+    /// The request was not sent because paypal is failing, see [CircuitBreaker](crate::CircuitBreaker).
+    ServiceUnavailable {
+        /// How long until a new request is let through.
+        retry_after: Duration,
+    },
+    /// The response was not successful and its body is not a paypal api error, for example a html maintenance page.
+    Unexpected {
+        /// The http status code of the response.
+        status: reqwest::StatusCode,
+        /// The raw response body.
+        body: String,
+    },
 }
 
 impl fmt::Display for ResponseError {
@@ -46,6 +200,22 @@ impl fmt::Display for ResponseError {
         match self {
             ResponseError::ApiError(e) => write!(f, "{}", e),
             ResponseError::HttpError(e) => write!(f, "{}", e),
+            ResponseError::ParseError(e) => write!(f, "{}", e),
+            ResponseError::DecodeError(e) => write!(f, "{}", e),
+            ResponseError::InvalidHeader(e) => write!(f, "{}", e),
+            ResponseError::RateLimited { retry_after, .. } => match retry_after {
+                Some(retry_after) => write!(f, "rate limit reached, retry after {:?}", retry_after),
+                None => write!(f, "rate limit reached"),
+            },
+            ResponseError::ServiceUnavailable { retry_after } => {
+                write!(f, "paypal is unavailable, retry after {:?}", retry_after)
+            }
+            ResponseError::Unexpected { status, body } if body.is_empty() => {
+                write!(f, "unexpected response with status {} and empty body", status)
+            }
+            ResponseError::Unexpected { status, body } => {
+                write!(f, "unexpected response with status {}: {}", status, body)
+            }
         }
     }
 }
@@ -55,6 +225,62 @@ impl Error for ResponseError {
         match self {
             ResponseError::ApiError(e) => Some(e),
             ResponseError::HttpError(e) => Some(e),
+            ResponseError::ParseError(e) => Some(e),
+            ResponseError::DecodeError(e) => Some(e),
+            ResponseError::InvalidHeader(e) => Some(e),
+            ResponseError::RateLimited { error, .. } => error.as_ref().map(|e| e as _),
+            ResponseError::ServiceUnavailable { .. } => None,
+            ResponseError::Unexpected { .. } => None,
+        }
+    }
+}
+
+impl ResponseError {
+    /// Returns the paypal api error of the response, if there is one.
+    pub fn paypal_error(&self) -> Option<&PaypalError> {
+        match self {
+            ResponseError::ApiError(e) => Some(e),
+            ResponseError::RateLimited { error, .. } => error.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the issues of the paypal api error, empty if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paypal_rs::errors::{Issue, ResponseError};
+    ///
+    /// fn should_choose_another_card(error: &ResponseError) -> bool {
+    ///     error.issue_codes().contains(&Issue::InstrumentDeclined)
+    /// }
+    /// ```
+    pub fn issue_codes(&self) -> Vec<Issue> {
+        self.paypal_error().map(PaypalError::issue_codes).unwrap_or_default()
+    }
+
+    /// Returns whether the request was rejected for making too many requests.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            ResponseError::RateLimited { .. } => true,
+            ResponseError::ApiError(e) => e.is_rate_limited(),
+            ResponseError::Unexpected { status, .. } => *status == reqwest::StatusCode::TOO_MANY_REQUESTS,
+            _ => false,
+        }
+    }
+
+    /// Returns whether the same request may succeed later, like the default [RetryPolicy](crate::RetryPolicy)
+    /// decides: connection failures, timeouts, rate limits and server errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ResponseError::HttpError(e) => e.is_connect() || e.is_timeout(),
+            ResponseError::RateLimited { .. } | ResponseError::ServiceUnavailable { .. } => true,
+            ResponseError::ApiError(e) => e.is_retryable(),
+            ResponseError::Unexpected { status, .. } => {
+                *status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+            ResponseError::ParseError(_) | ResponseError::DecodeError(_) | ResponseError::InvalidHeader(_) => false,
         }
     }
 }
@@ -73,6 +299,417 @@ impl From<reqwest::Error> for ResponseError {
     }
 }
 
+impl From<reqwest::header::InvalidHeaderValue> for ResponseError {
+    fn from(e: reqwest::header::InvalidHeaderValue) -> Self {
+        ResponseError::InvalidHeader(e)
+    }
+}
+
+/// When a money value is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoneyError {
+    /// The value is not a number.
+    InvalidValue(String),
+    /// The value has more decimal places than its currency allows.
+    InvalidPrecision {
+        /// The value.
+        value: String,
+        /// The currency of the value.
+        currency: Currency,
+        /// The decimal places the currency allows.
+        exponent: u32,
+    },
+    /// The amounts have different currencies.
+    CurrencyMismatch {
+        /// The currency of the amount operated on.
+        expected: Currency,
+        /// The currency of the other amount.
+        found: Currency,
+    },
+    /// The result does not fit in a money value.
+    Overflow,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoneyError::InvalidValue(value) => write!(f, "{:?} is not a valid money value", value),
+            MoneyError::InvalidPrecision {
+                value,
+                currency,
+                exponent,
+            } => write!(
+                f,
+                "{:?} has more than {} decimal places allowed in {}",
+                value, exponent, currency
+            ),
+            MoneyError::CurrencyMismatch { expected, found } => {
+                write!(f, "expected an amount in {} but found one in {}", expected, found)
+            }
+            MoneyError::Overflow => f.write_str("the money value overflowed"),
+        }
+    }
+}
+
+impl Error for MoneyError {}
+
+/// When refunding an order fails.
+#[derive(Debug)]
+pub enum RefundOrderError {
+    /// Fetching the order or refunding a capture failed.
+    Response(ResponseError),
+    /// The order has no captured amount left to refund.
+    NothingToRefund,
+    /// The amount to refund is larger than the captured amount left to refund.
+    AmountExceedsRefundable {
+        /// The amount to refund.
+        requested: Money,
+        /// The captured amount left to refund.
+        refundable: Money,
+    },
+    /// The amounts of the order are invalid or in a different currency than the amount to refund.
+    Money(MoneyError),
+}
+
+impl fmt::Display for RefundOrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RefundOrderError::Response(e) => write!(f, "{}", e),
+            RefundOrderError::NothingToRefund => f.write_str("the order has nothing left to refund"),
+            RefundOrderError::AmountExceedsRefundable { requested, refundable } => write!(
+                f,
+                "cannot refund {} {}, only {} {} are left to refund",
+                requested.value, requested.currency_code, refundable.value, refundable.currency_code
+            ),
+            RefundOrderError::Money(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for RefundOrderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RefundOrderError::Response(e) => Some(e),
+            RefundOrderError::Money(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ResponseError> for RefundOrderError {
+    fn from(e: ResponseError) -> Self {
+        RefundOrderError::Response(e)
+    }
+}
+
+impl From<MoneyError> for RefundOrderError {
+    fn from(e: MoneyError) -> Self {
+        RefundOrderError::Money(e)
+    }
+}
+
+/// When creating a payout batch once fails.
+#[derive(Debug)]
+pub enum PayoutError {
+    /// Showing or creating the batch failed.
+    Response(ResponseError),
+    /// The batch has no sender batch id to recognize it by.
+    MissingSenderBatchId,
+    /// More than one item of the batch has this sender item id.
+    DuplicateSenderItemId(String),
+    /// The previous batch was created with another sender batch id.
+    SenderBatchIdMismatch {
+        /// The sender batch id of the batch to create.
+        expected: String,
+        /// The sender batch id of the previous batch.
+        found: Option<String>,
+    },
+}
+
+impl fmt::Display for PayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PayoutError::Response(e) => write!(f, "{}", e),
+            PayoutError::MissingSenderBatchId => f.write_str("the payout batch has no sender batch id"),
+            PayoutError::DuplicateSenderItemId(id) => {
+                write!(f, "the sender item id {:?} is used by more than one payout", id)
+            }
+            PayoutError::SenderBatchIdMismatch { expected, found } => write!(
+                f,
+                "the previous payout batch has the sender batch id {:?}, not {:?}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for PayoutError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PayoutError::Response(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ResponseError> for PayoutError {
+    fn from(e: ResponseError) -> Self {
+        PayoutError::Response(e)
+    }
+}
+
+/// When building a client fails.
+#[derive(Debug)]
+pub enum BuildError {
+    /// The http client can't be built, for example when the TLS backend can't be initialized.
+    Http(reqwest::Error),
+    /// The client is for the live environment without [allowing it](crate::ClientBuilder::allow_live), in a debug
+    /// build or with `PAYPAL_FORCE_SANDBOX` set.
+    LiveNotAllowed,
+    /// A default header parameter, such as the [partner attribution id](crate::ClientBuilder::partner_attribution_id),
+    /// is not a valid header value.
+    InvalidHeader(reqwest::header::InvalidHeaderValue),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Http(e) => write!(f, "{}", e),
+            BuildError::LiveNotAllowed => f.write_str(
+                "live clients must be allowed in debug builds or when PAYPAL_FORCE_SANDBOX is set, see ClientBuilder::allow_live",
+            ),
+            BuildError::InvalidHeader(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildError::Http(e) => Some(e),
+            BuildError::InvalidHeader(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for BuildError {
+    fn from(e: reqwest::Error) -> Self {
+        BuildError::Http(e)
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for BuildError {
+    fn from(e: reqwest::header::InvalidHeaderValue) -> Self {
+        BuildError::InvalidHeader(e)
+    }
+}
+
+/// When the client of a tenant of a [ClientPool](crate::pool::ClientPool) can't be returned.
+#[derive(Debug)]
+pub enum PoolError {
+    /// The tenant was not added to the pool.
+    UnknownTenant(String),
+    /// The client of the tenant can't be built.
+    Build(BuildError),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::UnknownTenant(tenant_id) => write!(f, "unknown tenant: {}", tenant_id),
+            PoolError::Build(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for PoolError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PoolError::Build(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<BuildError> for PoolError {
+    fn from(e: BuildError) -> Self {
+        PoolError::Build(e)
+    }
+}
+
+/// When provisioning a sandbox account fails.
+#[derive(Debug)]
+pub enum BootstrapError {
+    /// The client is not configured for the sandbox.
+    NotSandbox,
+    /// Listing or creating a resource failed.
+    Response(ResponseError),
+}
+
+impl fmt::Display for BootstrapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootstrapError::NotSandbox => f.write_str("only sandbox accounts can be bootstrapped"),
+            BootstrapError::Response(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for BootstrapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BootstrapError::Response(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ResponseError> for BootstrapError {
+    fn from(e: ResponseError) -> Self {
+        BootstrapError::Response(e)
+    }
+}
+
+/// When getting the certificate a webhook event was signed with fails.
+#[derive(Debug)]
+pub enum WebhookCertError {
+    /// The certificate url is not a https url of paypal.
+    DisallowedUrl(String),
+    /// Downloading the certificate failed.
+    Http(reqwest::Error),
+    /// The certificate can't be parsed.
+    Invalid(String),
+    /// The certificate is expired or not valid yet.
+    Expired,
+    /// The certificate does not match any of the pinned fingerprints.
+    NotPinned,
+}
+
+impl fmt::Display for WebhookCertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookCertError::DisallowedUrl(url) => write!(f, "the certificate url {} is not allowed", url),
+            WebhookCertError::Http(e) => write!(f, "{}", e),
+            WebhookCertError::Invalid(reason) => write!(f, "invalid certificate: {}", reason),
+            WebhookCertError::Expired => f.write_str("the certificate is expired or not valid yet"),
+            WebhookCertError::NotPinned => f.write_str("the certificate does not match the pinned fingerprints"),
+        }
+    }
+}
+
+impl Error for WebhookCertError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WebhookCertError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for WebhookCertError {
+    fn from(e: reqwest::Error) -> Self {
+        WebhookCertError::Http(e)
+    }
+}
+
+/// When verifying a webhook event fails.
+#[derive(Debug)]
+pub enum VerifyWebhookError {
+    /// A transmission header is missing or not valid.
+    MissingHeader(&'static str),
+    /// The event is not valid json.
+    Json(serde_json::Error),
+    /// Getting the certificate of the signature failed.
+    Certificate(WebhookCertError),
+    /// Calling the verification endpoint failed.
+    Response(ResponseError),
+    /// The signature does not match the event.
+    InvalidSignature,
+}
+
+impl fmt::Display for VerifyWebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyWebhookError::MissingHeader(name) => write!(f, "the {} header is missing", name),
+            VerifyWebhookError::Json(e) => write!(f, "{}", e),
+            VerifyWebhookError::Certificate(e) => write!(f, "{}", e),
+            VerifyWebhookError::Response(e) => write!(f, "{}", e),
+            VerifyWebhookError::InvalidSignature => f.write_str("the webhook event signature is not valid"),
+        }
+    }
+}
+
+impl Error for VerifyWebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VerifyWebhookError::Json(e) => Some(e),
+            VerifyWebhookError::Certificate(e) => Some(e),
+            VerifyWebhookError::Response(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for VerifyWebhookError {
+    fn from(e: serde_json::Error) -> Self {
+        VerifyWebhookError::Json(e)
+    }
+}
+
+impl From<WebhookCertError> for VerifyWebhookError {
+    fn from(e: WebhookCertError) -> Self {
+        VerifyWebhookError::Certificate(e)
+    }
+}
+
+impl From<ResponseError> for VerifyWebhookError {
+    fn from(e: ResponseError) -> Self {
+        VerifyWebhookError::Response(e)
+    }
+}
+
+/// When an address would be rejected by paypal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// A field required in the country of the address is missing.
+    MissingField(&'static str),
+    /// The postal code does not have the format of the country.
+    InvalidPostalCode {
+        /// The country of the address.
+        country: Country,
+        /// The postal code.
+        postal_code: String,
+    },
+    /// The admin area 1 is not the 2 letter code of a state or province.
+    InvalidAdminArea {
+        /// The country of the address.
+        country: Country,
+        /// The admin area 1.
+        admin_area: String,
+    },
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::MissingField(field) => write!(f, "the address is missing the {} field", field),
+            AddressError::InvalidPostalCode { country, postal_code } => {
+                write!(f, "{:?} is not a valid postal code in {}", postal_code, country)
+            }
+            AddressError::InvalidAdminArea { country, admin_area } => {
+                write!(
+                    f,
+                    "{:?} is not a valid state or province code in {}",
+                    admin_area, country
+                )
+            }
+        }
+    }
+}
+
+impl Error for AddressError {}
+
 /// When a currency is invalid.
 #[derive(Debug)]
 pub struct InvalidCurrencyError(pub String);
@@ -96,3 +733,17 @@ impl fmt::Display for InvalidCountryError {
 }
 
 impl Error for InvalidCountryError {}
+
+/// When a locale is invalid.
+#[derive(Debug)]
+pub struct InvalidLocaleError(pub String);
+
+impl fmt::Display for InvalidLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid locale", self.0)
+    }
+}
+
+impl Error for InvalidLocaleError {}
+
+impl_links!(PaypalError);
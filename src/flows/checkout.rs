@@ -0,0 +1,80 @@
+//! Recovers from a declined payment source when capturing an order, by sending the buyer back through the approval.
+//!
+//! When the capture fails with [Issue::InstrumentDeclined], paypal recommends redirecting the buyer to the approval
+//! url of the order, so they can choose another payment source, and capturing again once they approved it.
+//!
+//! <https://developer.paypal.com/docs/checkout/standard/customize/handle-funding-failures/>
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{flows::checkout::CaptureOutcome, Client};
+//!
+//! # async fn capture(client: Client) -> Result<(), paypal_rs::errors::ResponseError> {
+//! match client.capture_order_or_restart("5O190127TN364715T").await? {
+//!     CaptureOutcome::Captured(order) => println!("captured {}", order.id),
+//!     CaptureOutcome::Restart(url) => println!("redirect the buyer to {}", url),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::orders::{CaptureOrder, ShowOrderDetails},
+    data::common::{LinkRel, Links},
+    data::ids::OrderId,
+    data::orders::Order,
+    errors::{Issue, ResponseError},
+    Client,
+};
+
+/// The outcome of capturing an order whose payment source may be declined.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureOutcome {
+    /// The order was captured.
+    Captured(Box<Order>),
+    /// The payment source was declined, the buyer must approve the order again at this url.
+    Restart(String),
+}
+
+impl Client {
+    /// Captures an approved order, returning the url to send the buyer back to if the payment source was declined.
+    ///
+    /// Other errors, and a declined payment source without an url to restart the approval, are returned as is.
+    pub async fn capture_order_or_restart(
+        &self,
+        order_id: impl Into<OrderId>,
+    ) -> Result<CaptureOutcome, ResponseError> {
+        let order_id = order_id.into();
+        match self.execute(&CaptureOrder::new(order_id.clone())).await {
+            Ok(order) => Ok(CaptureOutcome::Captured(Box::new(order))),
+            Err(error) => match self.restart_url(order_id, &error).await? {
+                Some(url) => Ok(CaptureOutcome::Restart(url)),
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Returns the url to send the buyer back to when capturing the order failed with a declined payment source.
+    ///
+    /// The url is the redirect link of the error, or else the approval link of the order, which is fetched. Returns
+    /// `None` if the error is not [Issue::InstrumentDeclined] or there is no such link.
+    pub async fn restart_url(
+        &self,
+        order_id: impl Into<OrderId>,
+        error: &ResponseError,
+    ) -> Result<Option<String>, ResponseError> {
+        let Some(paypal_error) = error.paypal_error().filter(|e| e.has_issue(Issue::InstrumentDeclined)) else {
+            return Ok(None);
+        };
+        if let Some(url) = paypal_error.href(LinkRel::Redirect) {
+            return Ok(Some(url.to_string()));
+        }
+
+        let order = self.execute(&ShowOrderDetails::new(order_id)).await?;
+        let url = order
+            .href(LinkRel::Approve)
+            .or_else(|| order.href(LinkRel::PayerAction));
+        Ok(url.map(str::to_string))
+    }
+}
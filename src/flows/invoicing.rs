@@ -0,0 +1,116 @@
+//! Bills a customer with an invoice: creates the draft, sends it and follows it up.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{
+//!     data::invoice::{InvoicePayload, RecordPaymentPayload, SendInvoicePayload},
+//!     flows::invoicing,
+//!     Client,
+//! };
+//!
+//! # async fn bill(client: Client, payload: InvoicePayload) -> Result<(), paypal_rs::errors::ResponseError> {
+//! let invoice = invoicing::bill(&client, payload, SendInvoicePayload::default()).await?;
+//! println!("pay at {}", invoice.recipient_view_url().unwrap_or_default());
+//!
+//! // Later, when the customer paid by other means.
+//! invoice.record_payment(RecordPaymentPayload::default()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::invoice::{CancelInvoice, CreateDraftInvoice, GetInvoice, RecordPayment, SendInvoice, SendInvoiceReminder},
+    data::ids::InvoiceId,
+    data::invoice::{
+        CancelReason, Invoice, InvoicePayload, RecordPaymentPayload, RecordPaymentResponse, SendInvoicePayload,
+    },
+    errors::ResponseError,
+    Client,
+};
+
+/// Creates a draft invoice, returning a handle to send and follow it up.
+pub async fn draft(client: &Client, payload: InvoicePayload) -> Result<InvoiceHandle, ResponseError> {
+    let invoice = client.execute(&CreateDraftInvoice::new(payload)).await?;
+    Ok(InvoiceHandle::new(client.clone(), invoice))
+}
+
+/// Creates an invoice and sends it to the recipient, returning a handle with the sent invoice.
+pub async fn bill(
+    client: &Client,
+    payload: InvoicePayload,
+    notification: SendInvoicePayload,
+) -> Result<InvoiceHandle, ResponseError> {
+    let mut invoice = draft(client, payload).await?;
+    invoice.send(notification).await?;
+    Ok(invoice)
+}
+
+/// An invoice and the client used to act on it.
+#[derive(Debug, Clone)]
+pub struct InvoiceHandle {
+    client: Client,
+    invoice: Invoice,
+}
+
+impl InvoiceHandle {
+    /// Wraps an invoice already fetched or created.
+    pub fn new(client: Client, invoice: Invoice) -> Self {
+        Self { client, invoice }
+    }
+
+    /// Fetches an existing invoice, to follow it up.
+    pub async fn open(client: Client, invoice_id: impl Into<InvoiceId>) -> Result<Self, ResponseError> {
+        let invoice = client.execute(&GetInvoice::new(invoice_id)).await?;
+        Ok(Self { client, invoice })
+    }
+
+    /// The invoice, as of the last operation or [refresh](Self::refresh).
+    pub fn invoice(&self) -> &Invoice {
+        &self.invoice
+    }
+
+    /// The invoice id.
+    pub fn id(&self) -> &InvoiceId {
+        &self.invoice.id
+    }
+
+    /// The url where the recipient views and pays the invoice, available once it is sent.
+    pub fn recipient_view_url(&self) -> Option<&str> {
+        self.invoice.detail.metadata.as_ref()?.recipient_view_url.as_deref()
+    }
+
+    /// Returns the invoice, consuming the handle.
+    pub fn into_invoice(self) -> Invoice {
+        self.invoice
+    }
+
+    /// Fetches the current state of the invoice.
+    pub async fn refresh(&mut self) -> Result<&Invoice, ResponseError> {
+        self.invoice = self.client.execute(&GetInvoice::new(self.id())).await?;
+        Ok(&self.invoice)
+    }
+
+    /// Sends the invoice to the recipient, then fetches it to get its status and recipient view url.
+    pub async fn send(&mut self, notification: SendInvoicePayload) -> Result<&Invoice, ResponseError> {
+        self.client.execute(&SendInvoice::new(self.id(), notification)).await?;
+        self.refresh().await
+    }
+
+    /// Reminds the recipient to pay the invoice.
+    pub async fn remind(&self, notification: SendInvoicePayload) -> Result<(), ResponseError> {
+        self.client
+            .execute(&SendInvoiceReminder::new(self.id(), notification))
+            .await
+    }
+
+    /// Records a payment made outside of paypal, such as cash or check.
+    pub async fn record_payment(&self, payment: RecordPaymentPayload) -> Result<RecordPaymentResponse, ResponseError> {
+        self.client.execute(&RecordPayment::new(self.id(), payment)).await
+    }
+
+    /// Cancels the sent invoice.
+    pub async fn cancel(&self, reason: CancelReason) -> Result<(), ResponseError> {
+        self.client.execute(&CancelInvoice::new(self.id(), reason)).await
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers chaining the api calls of common workflows.
+
+pub mod checkout;
+pub mod invoicing;
+pub mod payouts;
+pub mod platform;
+pub mod reconciliation;
+pub mod refunds;
+pub mod sandbox;
+pub mod subscriptions;
+pub mod webhooks;
@@ -0,0 +1,94 @@
+//! Creates a payout batch at most once, so a retried job doesn't pay the receivers twice.
+//!
+//! The batch is recognized by its sender batch id: it is sent as the request id, so paypal returns the batch already
+//! created instead of creating another one, and it is checked against the batch a previous attempt created.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{
+//!     data::common::Money,
+//!     data::payouts::{PayoutBatchPayload, PayoutItem, SenderBatchHeader},
+//!     flows::payouts,
+//!     Client,
+//! };
+//!
+//! # async fn pay(client: Client, previous_batch_id: Option<String>) -> Result<(), paypal_rs::errors::PayoutError> {
+//! let batch = PayoutBatchPayload {
+//!     sender_batch_header: SenderBatchHeader {
+//!         sender_batch_id: Some("payroll-2024-03".to_string()),
+//!         ..Default::default()
+//!     },
+//!     items: vec![PayoutItem {
+//!         amount: Money::usd("100.00").into(),
+//!         receiver: "receiver@example.com".to_string(),
+//!         sender_item_id: Some("employee-42".to_string()),
+//!         ..Default::default()
+//!     }],
+//! };
+//! let batch = payouts::create_once(&client, batch, previous_batch_id.as_deref()).await?;
+//! // Store the batch id, to check it if the job is retried.
+//! println!("{}", batch.batch_header.payout_batch_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+
+use crate::{
+    api::payouts::{CreateBatchPayout, ShowPayoutBatch},
+    data::payouts::{PayoutBatch, PayoutBatchPayload},
+    errors::PayoutError,
+    Client, HeaderParams,
+};
+
+/// Checks that the batch has a sender batch id and that the sender item ids of its items are unique.
+#[allow(clippy::result_large_err)]
+pub fn validate(batch: &PayoutBatchPayload) -> Result<(), PayoutError> {
+    if batch.sender_batch_header.sender_batch_id.is_none() {
+        return Err(PayoutError::MissingSenderBatchId);
+    }
+    let mut sender_item_ids = HashSet::with_capacity(batch.items.len());
+    for sender_item_id in batch.items.iter().filter_map(|item| item.sender_item_id.as_deref()) {
+        if !sender_item_ids.insert(sender_item_id) {
+            return Err(PayoutError::DuplicateSenderItemId(sender_item_id.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Creates the batch, unless the previous batch was created for the same sender batch id.
+///
+/// Give the id of the batch a previous attempt created, if it got one, to return it instead of creating the batch
+/// again. If the previous attempt failed before getting the id, the request id makes paypal return the batch it
+/// created, if any.
+pub async fn create_once(
+    client: &Client,
+    batch: PayoutBatchPayload,
+    previous_batch_id: Option<&str>,
+) -> Result<PayoutBatch, PayoutError> {
+    validate(&batch)?;
+    let sender_batch_id = batch.sender_batch_header.sender_batch_id.clone().unwrap_or_default();
+
+    if let Some(previous_batch_id) = previous_batch_id {
+        let previous = client.execute(&ShowPayoutBatch::new(previous_batch_id)).await?;
+        let found = previous
+            .batch_header
+            .sender_batch_header
+            .as_ref()
+            .and_then(|header| header.sender_batch_id.clone());
+        if found.as_deref() != Some(sender_batch_id.as_str()) {
+            return Err(PayoutError::SenderBatchIdMismatch {
+                expected: sender_batch_id,
+                found,
+            });
+        }
+        return Ok(previous);
+    }
+
+    let headers = HeaderParams {
+        request_id: Some(sender_batch_id),
+        ..Default::default()
+    };
+    Ok(client.execute_ext(&CreateBatchPayout::new(batch), headers).await?)
+}
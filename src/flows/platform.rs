@@ -0,0 +1,139 @@
+//! Acts on behalf of the sellers of a PayPal Commerce Platform partner.
+//!
+//! The requests of a [Seller] carry the PayPal-Auth-Assertion of the seller and the BN code of the partner, and its
+//! orders can take a platform fee and hold the captured funds until they are released to the seller.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{
+//!     data::common::Money,
+//!     data::orders::{Amount, Intent, OrderPayloadBuilder, PurchaseUnit},
+//!     flows::platform::{self, Platform},
+//!     Client,
+//! };
+//!
+//! # async fn sell(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let platform = Platform::new(client, "EXAMPLE_MP").delayed_disbursement(true);
+//! let seller = platform.seller("C7CYMKZDG8D6E");
+//!
+//! let mut purchase_unit = PurchaseUnit::new(Amount::usd("100.00"));
+//! platform::add_platform_fee(&mut purchase_unit, Money::usd("5.00"));
+//!
+//! let order = OrderPayloadBuilder::default()
+//!     .intent(Intent::Capture)
+//!     .purchase_units(vec![purchase_unit])
+//!     .build()?;
+//! let order = seller.create_order(order).await?;
+//!
+//! // Once the buyer approved the order.
+//! seller.capture_order(order.id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::orders::{CaptureOrder, CreateOrder},
+    data::common::Money,
+    data::ids::OrderId,
+    data::orders::{DisbursementMode, Order, OrderPayload, PaymentInstruction, PlatformFee, PurchaseUnit},
+    errors::ResponseError,
+    Client, HeaderParams,
+};
+
+/// A partner of the PayPal Commerce Platform, identified by its BN code.
+#[derive(Debug, Clone)]
+pub struct Platform {
+    client: Client,
+    partner_attribution_id: String,
+    delayed_disbursement: bool,
+}
+
+impl Platform {
+    /// A partner making its requests with the given client and BN code.
+    pub fn new(client: Client, partner_attribution_id: impl ToString) -> Self {
+        Self {
+            client,
+            partner_attribution_id: partner_attribution_id.to_string(),
+            delayed_disbursement: false,
+        }
+    }
+
+    /// Whether the orders created for the sellers hold the captured funds until they are released, false by default.
+    pub fn delayed_disbursement(mut self, delayed_disbursement: bool) -> Self {
+        self.delayed_disbursement = delayed_disbursement;
+        self
+    }
+
+    /// The BN code of the partner.
+    pub fn partner_attribution_id(&self) -> &str {
+        &self.partner_attribution_id
+    }
+
+    /// Acts on behalf of the seller with the given payer id.
+    pub fn seller(&self, merchant_payer_id: impl ToString) -> Seller {
+        Seller {
+            platform: self.clone(),
+            merchant_payer_id: merchant_payer_id.to_string(),
+        }
+    }
+}
+
+/// A seller of the platform, on whose behalf the requests are made.
+#[derive(Debug, Clone)]
+pub struct Seller {
+    platform: Platform,
+    merchant_payer_id: String,
+}
+
+impl Seller {
+    /// The payer id of the seller.
+    pub fn merchant_payer_id(&self) -> &str {
+        &self.merchant_payer_id
+    }
+
+    /// The headers acting on behalf of the seller, to use with [Client::execute_ext].
+    pub fn header_params(&self) -> HeaderParams {
+        HeaderParams {
+            merchant_payer_id: Some(self.merchant_payer_id.clone()),
+            partner_attribution_id: Some(self.platform.partner_attribution_id.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates an order for the seller, delaying the disbursement of its purchase units if the platform does.
+    pub async fn create_order(&self, mut order: OrderPayload) -> Result<Order, ResponseError> {
+        if self.platform.delayed_disbursement {
+            order.purchase_units.iter_mut().for_each(delay_disbursement);
+        }
+        self.platform
+            .client
+            .execute_ext(&CreateOrder::new(order), self.header_params())
+            .await
+    }
+
+    /// Captures an approved order of the seller.
+    pub async fn capture_order(&self, order_id: impl Into<OrderId>) -> Result<Order, ResponseError> {
+        self.platform
+            .client
+            .execute_ext(&CaptureOrder::new(order_id), self.header_params())
+            .await
+    }
+}
+
+/// Adds a fee paid to the platform from the amount of the purchase unit.
+pub fn add_platform_fee(purchase_unit: &mut PurchaseUnit, amount: Money) {
+    payment_instruction(purchase_unit)
+        .platform_fees
+        .get_or_insert_with(Vec::new)
+        .push(PlatformFee { amount, payee: None });
+}
+
+/// Holds the captured funds of the purchase unit until they are released to the seller.
+pub fn delay_disbursement(purchase_unit: &mut PurchaseUnit) {
+    payment_instruction(purchase_unit).disbursement_mode = Some(DisbursementMode::Delayed);
+}
+
+fn payment_instruction(purchase_unit: &mut PurchaseUnit) -> &mut PaymentInstruction {
+    purchase_unit.payment_instruction.get_or_insert_with(Default::default)
+}
@@ -0,0 +1,212 @@
+//! Reconciles the captured payments of orders with the transactions of the account, for finance exports.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use chrono::{Duration, Utc};
+//! use paypal_rs::{data::orders::Order, flows::reconciliation, Client};
+//!
+//! # async fn report(client: Client, orders: Vec<Order>) -> Result<(), paypal_rs::errors::ResponseError> {
+//! let end_date = Utc::now();
+//! let report = reconciliation::reconcile(&client, end_date - Duration::days(7), end_date, &orders).await?;
+//! for payment in &report.missing {
+//!     println!("no transaction for order {}", payment.order_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
+use futures_util::TryStreamExt;
+
+use crate::{
+    api::transactions::{ListTransactions, ListTransactionsQuery},
+    data::common::Money,
+    data::ids::{CaptureId, OrderId},
+    data::orders::{CaptureStatus, Order},
+    data::transactions::TransactionInfo,
+    errors::ResponseError,
+    Client,
+};
+
+/// The longest date range of a transaction search.
+const MAX_SEARCH_DAYS: i64 = 31;
+
+/// A payment captured for an order, expected among the transactions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderPayment {
+    /// The id of the order.
+    pub order_id: OrderId,
+    /// The id of the capture, which is also the id of its transaction.
+    pub capture_id: CaptureId,
+    /// The invoice id of the capture, or else of its purchase unit.
+    pub invoice_id: Option<String>,
+    /// The custom id of the capture, or else of its purchase unit.
+    pub custom_id: Option<String>,
+    /// The captured amount.
+    pub amount: Option<Money>,
+}
+
+/// A payment and the transaction it was matched to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconciledPayment {
+    /// The payment of the order.
+    pub payment: OrderPayment,
+    /// The transaction of the payment.
+    pub transaction: TransactionInfo,
+}
+
+/// The result of reconciling orders with transactions.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReconciliationReport {
+    /// The payments matched to a transaction of the same amount.
+    pub matched: Vec<ReconciledPayment>,
+    /// The payments without a transaction.
+    pub missing: Vec<OrderPayment>,
+    /// The payments matched to a transaction of another amount or currency.
+    pub amount_mismatches: Vec<ReconciledPayment>,
+    /// The incoming transactions not matched to any payment.
+    pub unmatched_transactions: Vec<TransactionInfo>,
+}
+
+impl ReconciliationReport {
+    /// Matches the captures of the orders to the transactions.
+    ///
+    /// A capture is matched to a transaction by its id, and else by its invoice id and then by its custom id. Only the
+    /// incoming transactions, with a positive amount, are considered: refunds and fees are ignored.
+    pub fn new(orders: &[Order], transactions: impl IntoIterator<Item = TransactionInfo>) -> Self {
+        let mut transactions: Vec<_> = transactions.into_iter().filter(is_incoming).map(Some).collect();
+        let mut report = Self::default();
+
+        for payment in orders.iter().flat_map(order_payments) {
+            let position = find(&transactions, |transaction| {
+                transaction.transaction_id.as_deref() == Some(payment.capture_id.as_str())
+            })
+            .or_else(|| {
+                let invoice_id = payment.invoice_id.as_deref()?;
+                find(&transactions, |transaction| {
+                    transaction.invoice_id.as_deref() == Some(invoice_id)
+                })
+            })
+            .or_else(|| {
+                let custom_id = payment.custom_id.as_deref()?;
+                find(&transactions, |transaction| {
+                    transaction.custom_field.as_deref() == Some(custom_id)
+                })
+            });
+
+            let Some(transaction) = position.and_then(|position| transactions[position].take()) else {
+                report.missing.push(payment);
+                continue;
+            };
+            let same_amount = match (&payment.amount, &transaction.transaction_amount) {
+                (Some(expected), Some(actual)) => same_money(expected, actual),
+                _ => false,
+            };
+            let reconciled = ReconciledPayment { payment, transaction };
+            if same_amount {
+                report.matched.push(reconciled);
+            } else {
+                report.amount_mismatches.push(reconciled);
+            }
+        }
+
+        report.unmatched_transactions = transactions.into_iter().flatten().collect();
+        report
+    }
+
+    /// Whether every payment was matched to a transaction of the same amount, and every transaction to a payment.
+    pub fn is_balanced(&self) -> bool {
+        self.missing.is_empty() && self.amount_mismatches.is_empty() && self.unmatched_transactions.is_empty()
+    }
+}
+
+/// Searches the transactions of the date range and matches the captures of the orders to them.
+///
+/// The range is searched in windows of 31 days, the longest the api allows.
+pub async fn reconcile(
+    client: &Client,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+    orders: &[Order],
+) -> Result<ReconciliationReport, ResponseError> {
+    let transactions = list_transactions(client, start_date, end_date).await?;
+    Ok(ReconciliationReport::new(orders, transactions))
+}
+
+/// Fetches all the transactions of the date range.
+///
+/// The range is searched in windows sharing their boundaries, so a transaction at a boundary is only returned once.
+pub async fn list_transactions(
+    client: &Client,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Result<Vec<TransactionInfo>, ResponseError> {
+    let mut transactions = Vec::new();
+    let mut transaction_ids = HashSet::new();
+    let mut window_start = start_date;
+    while window_start < end_date {
+        let window_end = end_date.min(window_start + Duration::days(MAX_SEARCH_DAYS));
+        let query = ListTransactionsQuery {
+            start_date: Some(window_start),
+            end_date: Some(window_end),
+            fields: Some("transaction_info".to_string()),
+            page_size: Some(500),
+            ..Default::default()
+        };
+        let pages: Vec<_> = client
+            .execute_paginated(ListTransactions::new(query))
+            .try_collect()
+            .await?;
+        transactions.extend(
+            pages
+                .into_iter()
+                .flat_map(|page| page.transaction_details)
+                .map(|detail| detail.transaction_info)
+                .filter(|transaction| match &transaction.transaction_id {
+                    Some(transaction_id) => transaction_ids.insert(transaction_id.clone()),
+                    None => true,
+                }),
+        );
+        window_start = window_end;
+    }
+    Ok(transactions)
+}
+
+/// The captured payments of an order, without the declined and failed ones.
+fn order_payments(order: &Order) -> impl Iterator<Item = OrderPayment> + '_ {
+    order.purchase_units.iter().flatten().flat_map(move |purchase_unit| {
+        let captures = purchase_unit.payments.iter().flat_map(|payments| &payments.captures);
+        captures
+            .filter(|capture| !matches!(capture.status, CaptureStatus::Declined | CaptureStatus::Failed))
+            .filter_map(move |capture| {
+                Some(OrderPayment {
+                    order_id: order.id.clone(),
+                    capture_id: capture.id.clone()?,
+                    invoice_id: capture.invoice_id.clone().or_else(|| purchase_unit.invoice_id.clone()),
+                    custom_id: capture.custom_id.clone().or_else(|| purchase_unit.custom_id.clone()),
+                    amount: capture.amount.clone(),
+                })
+            })
+    })
+}
+
+fn find(transactions: &[Option<TransactionInfo>], predicate: impl Fn(&TransactionInfo) -> bool) -> Option<usize> {
+    transactions
+        .iter()
+        .position(|transaction| transaction.as_ref().is_some_and(&predicate))
+}
+
+fn is_incoming(transaction: &TransactionInfo) -> bool {
+    transaction
+        .transaction_amount
+        .as_ref()
+        .is_some_and(|amount| amount.to_minor_units().is_ok_and(|units| units > 0))
+}
+
+fn same_money(expected: &Money, actual: &Money) -> bool {
+    expected.currency_code == actual.currency_code
+        && matches!((expected.to_minor_units(), actual.to_minor_units()), (Ok(a), Ok(b)) if a == b)
+}
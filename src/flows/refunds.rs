@@ -0,0 +1,122 @@
+//! Refunds an order by its id, finding the captured payments to refund.
+
+use crate::{
+    api::{orders::ShowOrderDetails, payments::RefundCapture},
+    data::common::{LinkRel, Links, Money},
+    data::ids::{CaptureId, OrderId},
+    data::orders::{CaptureStatus, Order, Refund, RefundStatus},
+    data::payment::RefundRequest,
+    errors::{MoneyError, RefundOrderError},
+    Client,
+};
+
+impl Client {
+    /// Refunds the captured payments of an order, fully if there is no amount.
+    ///
+    /// The order is fetched to find its completed captures, and the amount is refunded from the first captures that
+    /// have some amount left to refund. One refund is returned per capture refunded. If a refund fails, the error is
+    /// returned and the previous refunds are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use paypal_rs::{data::common::Money, Client};
+    ///
+    /// # async fn refund(client: Client) -> Result<(), paypal_rs::errors::RefundOrderError> {
+    /// let refunds = client.refund_order("5O190127TN364715T", Some(Money::usd("10.00"))).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn refund_order(
+        &self,
+        order_id: impl Into<OrderId>,
+        amount: Option<Money>,
+    ) -> Result<Vec<Refund>, RefundOrderError> {
+        let order = self.execute(&ShowOrderDetails::new(order_id)).await?;
+
+        let mut refunds = Vec::new();
+        for (capture_id, amount) in plan_refunds(&order, amount)? {
+            let refund = RefundRequest {
+                amount: Some(amount),
+                ..Default::default()
+            };
+            refunds.push(self.execute(&RefundCapture::new(capture_id, refund)).await?);
+        }
+        Ok(refunds)
+    }
+}
+
+/// Splits the amount to refund over the captures of the order with an amount left to refund.
+#[allow(clippy::result_large_err)]
+fn plan_refunds(order: &Order, amount: Option<Money>) -> Result<Vec<(CaptureId, Money)>, RefundOrderError> {
+    let payments = order
+        .purchase_units
+        .iter()
+        .flatten()
+        .filter_map(|purchase_unit| purchase_unit.payments.as_ref());
+
+    let mut refundable = Vec::new();
+    for payments in payments {
+        for capture in &payments.captures {
+            let (Some(id), Some(captured)) = (&capture.id, &capture.amount) else {
+                continue;
+            };
+            if !matches!(
+                capture.status,
+                CaptureStatus::Completed | CaptureStatus::PartiallyRefunded
+            ) {
+                continue;
+            }
+            let mut left = captured.clone();
+            for refund in &payments.refunds {
+                let refunds_capture = refund
+                    .href(LinkRel::Up)
+                    .is_some_and(|href| href.ends_with(&format!("/captures/{}", id)));
+                if refunds_capture && matches!(refund.status, RefundStatus::Completed | RefundStatus::Pending) {
+                    left = left.checked_sub(&refund.amount)?;
+                }
+            }
+            if left.to_minor_units()? > 0 {
+                refundable.push((id.clone(), left));
+            }
+        }
+    }
+
+    if refundable.is_empty() {
+        return Err(RefundOrderError::NothingToRefund);
+    }
+    let Some(amount) = amount else {
+        return Ok(refundable);
+    };
+    if amount.to_minor_units()? <= 0 {
+        return Err(MoneyError::InvalidValue(amount.value).into());
+    }
+
+    let mut total = Money::from_minor_units(amount.currency_code.clone(), 0);
+    for (_, left) in &refundable {
+        total = total.checked_add(left)?;
+    }
+    if amount.to_minor_units()? > total.to_minor_units()? {
+        return Err(RefundOrderError::AmountExceedsRefundable {
+            requested: amount,
+            refundable: total,
+        });
+    }
+
+    let mut remaining = amount;
+    let mut planned = Vec::new();
+    for (id, left) in refundable {
+        let remaining_units = remaining.to_minor_units()?;
+        if remaining_units == 0 {
+            break;
+        }
+        let refund = if left.to_minor_units()? < remaining_units {
+            left
+        } else {
+            remaining.clone()
+        };
+        remaining = remaining.checked_sub(&refund)?;
+        planned.push((id, refund));
+    }
+    Ok(planned)
+}
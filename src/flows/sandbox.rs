@@ -0,0 +1,156 @@
+//! Provisions a sandbox account from code, so new developer environments get the same webhooks, products and plans.
+//!
+//! Bootstrapping is idempotent: a webhook is only created if no webhook listens at its url, a product if there is no
+//! product with its name, and a plan if its product has no plan with its name. Existing resources are kept as is.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{
+//!     data::catalog_products::{ProductPayload, ProductType},
+//!     data::common::Money,
+//!     data::webhooks::EventType,
+//!     flows::sandbox::{self, SandboxConfig},
+//!     flows::subscriptions::PlanDsl,
+//!     Client,
+//! };
+//!
+//! # async fn provision(client: Client) -> Result<(), paypal_rs::errors::BootstrapError> {
+//! let config = SandboxConfig::default()
+//!     .webhook("https://example.com/webhooks", [EventType::All])
+//!     .product(
+//!         ProductPayload::new("Video streaming", ProductType::Digital),
+//!         [PlanDsl::monthly(Money::usd("9.99")).name("Basic").trial_days(7)],
+//!     );
+//! let resources = sandbox::bootstrap(&client, &config).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use futures_util::TryStreamExt;
+
+use crate::{
+    api::catalog_products::{CreateProduct, ListProducts, ListProductsQuery},
+    api::subscriptions::{CreatePlan, ListPlans, ListPlansQuery},
+    api::webhooks::{CreateWebhook, ListWebhooks},
+    data::catalog_products::{Product, ProductPayload},
+    data::subscriptions::Plan,
+    data::webhooks::{EventType, Webhook, WebhookPayload},
+    errors::{BootstrapError, ResponseError},
+    flows::subscriptions::PlanDsl,
+    Client, PaypalEnv,
+};
+
+/// A product of the catalog and its subscription plans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductConfig {
+    /// The product to create.
+    pub product: ProductPayload,
+    /// The plans of the product, their own product is ignored.
+    pub plans: Vec<PlanDsl>,
+}
+
+/// The resources a sandbox account should have.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SandboxConfig {
+    /// The webhooks to create.
+    pub webhooks: Vec<WebhookPayload>,
+    /// The products to create, with their plans.
+    pub products: Vec<ProductConfig>,
+}
+
+impl SandboxConfig {
+    /// Adds a webhook listening at the url to the given events.
+    pub fn webhook(mut self, url: impl ToString, event_types: impl IntoIterator<Item = EventType>) -> Self {
+        self.webhooks.push(WebhookPayload::new(url, event_types));
+        self
+    }
+
+    /// Adds a product and its plans.
+    pub fn product(mut self, product: ProductPayload, plans: impl IntoIterator<Item = PlanDsl>) -> Self {
+        self.products.push(ProductConfig {
+            product,
+            plans: plans.into_iter().collect(),
+        });
+        self
+    }
+}
+
+/// A product of the sandbox and its plans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SandboxProduct {
+    /// The product.
+    pub product: Product,
+    /// The plans of the product, in the order of the config.
+    pub plans: Vec<Plan>,
+}
+
+/// The resources of the sandbox described by the config, whether they were created or already existed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SandboxResources {
+    /// The webhooks, in the order of the config.
+    pub webhooks: Vec<Webhook>,
+    /// The products and their plans, in the order of the config.
+    pub products: Vec<SandboxProduct>,
+}
+
+/// Creates the resources of the config missing from the sandbox account.
+///
+/// Fails with [BootstrapError::NotSandbox] if the client is configured for the live environment.
+pub async fn bootstrap(client: &Client, config: &SandboxConfig) -> Result<SandboxResources, BootstrapError> {
+    if matches!(client.env, PaypalEnv::Live) {
+        return Err(BootstrapError::NotSandbox);
+    }
+
+    let mut resources = SandboxResources::default();
+
+    let existing = client.execute(&ListWebhooks::new()).await?.webhooks;
+    for payload in &config.webhooks {
+        let webhook = match existing.iter().find(|webhook| webhook.url == payload.url) {
+            Some(webhook) => webhook.clone(),
+            None => client.execute(&CreateWebhook::new(payload.clone())).await?,
+        };
+        resources.webhooks.push(webhook);
+    }
+
+    let existing = list_products(client).await?;
+    for config in &config.products {
+        let product = match existing.iter().find(|product| product.name == config.product.name) {
+            Some(product) => product.clone(),
+            None => client.execute(&CreateProduct::new(config.product.clone())).await?,
+        };
+
+        let existing = list_plans(client, &product.id).await?;
+        let mut plans = Vec::with_capacity(config.plans.len());
+        for plan in &config.plans {
+            let payload = plan.plan_payload(&product.id);
+            let plan = match existing.iter().find(|plan| plan.name.as_ref() == Some(&payload.name)) {
+                Some(plan) => plan.clone(),
+                None => client.execute(&CreatePlan::new(payload)).await?,
+            };
+            plans.push(plan);
+        }
+        resources.products.push(SandboxProduct { product, plans });
+    }
+
+    Ok(resources)
+}
+
+async fn list_products(client: &Client) -> Result<Vec<Product>, ResponseError> {
+    let query = ListProductsQuery {
+        page_size: Some(20),
+        ..Default::default()
+    };
+    let pages: Vec<_> = client.execute_paginated(ListProducts::new(query)).try_collect().await?;
+    Ok(pages.into_iter().flat_map(|page| page.products).collect())
+}
+
+async fn list_plans(client: &Client, product_id: &str) -> Result<Vec<Plan>, ResponseError> {
+    let query = ListPlansQuery {
+        product_id: Some(product_id.to_string()),
+        page_size: Some(20),
+        ..Default::default()
+    };
+    let pages: Vec<_> = client.execute_paginated(ListPlans::new(query)).try_collect().await?;
+    Ok(pages.into_iter().flat_map(|page| page.plans).collect())
+}
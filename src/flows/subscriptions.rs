@@ -0,0 +1,196 @@
+//! Assembles a catalog product and a subscription plan with its trial and regular billing cycles.
+//!
+//! # Examples
+//!
+//! ```
+//! use paypal_rs::{data::common::Money, flows::subscriptions::PlanDsl};
+//!
+//! let plan = PlanDsl::monthly(Money::eur("9.99")).name("Pro").trial_days(14);
+//!
+//! let payload = plan.plan_payload("PROD-XXCD1234QWER65782");
+//! assert_eq!(payload.billing_cycles.len(), 2);
+//! assert_eq!(payload.billing_cycles[0].frequency.interval_count, 14);
+//! assert_eq!(payload.billing_cycles[1].sequence, 2);
+//! ```
+
+use crate::{
+    api::{catalog_products::CreateProduct, subscriptions::CreatePlan},
+    data::catalog_products::{Product, ProductPayload, ProductType},
+    data::common::Money,
+    data::subscriptions::{
+        BillingCycle, Frequency, IntervalUnit, PaymentPreferences, Plan, PlanPayload, PricingScheme, Taxes, TenureType,
+    },
+    errors::ResponseError,
+    Client,
+};
+
+/// A trial period, billed once before the regular billing cycles.
+#[derive(Debug, Clone, PartialEq)]
+struct Trial {
+    frequency: Frequency,
+    price: Option<Money>,
+}
+
+/// A fluent builder of a product and its subscription plan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanDsl {
+    product: Option<ProductPayload>,
+    name: Option<String>,
+    description: Option<String>,
+    frequency: Frequency,
+    price: Money,
+    total_cycles: u32,
+    trial: Option<Trial>,
+    setup_fee: Option<Money>,
+    taxes: Option<Taxes>,
+}
+
+impl PlanDsl {
+    /// A plan billing the price every `interval_count` units of time.
+    pub fn every(interval_unit: IntervalUnit, interval_count: u32, price: Money) -> Self {
+        Self {
+            product: None,
+            name: None,
+            description: None,
+            frequency: Frequency {
+                interval_unit,
+                interval_count,
+            },
+            price,
+            total_cycles: 0,
+            trial: None,
+            setup_fee: None,
+            taxes: None,
+        }
+    }
+
+    /// A plan billing the price every day.
+    pub fn daily(price: Money) -> Self {
+        Self::every(IntervalUnit::Day, 1, price)
+    }
+
+    /// A plan billing the price every week.
+    pub fn weekly(price: Money) -> Self {
+        Self::every(IntervalUnit::Week, 1, price)
+    }
+
+    /// A plan billing the price every month.
+    pub fn monthly(price: Money) -> Self {
+        Self::every(IntervalUnit::Month, 1, price)
+    }
+
+    /// A plan billing the price every year.
+    pub fn yearly(price: Money) -> Self {
+        Self::every(IntervalUnit::Year, 1, price)
+    }
+
+    /// The name of the plan, also used as the product name unless [product](Self::product) is set.
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The description of the plan.
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// The product to create for the plan, a service named after the plan by default.
+    pub fn product(mut self, product: ProductPayload) -> Self {
+        self.product = Some(product);
+        self
+    }
+
+    /// A free trial of the given days, at most 365, before the regular billing cycles.
+    pub fn trial_days(self, days: u32) -> Self {
+        self.trial(IntervalUnit::Day, days, None)
+    }
+
+    /// A trial of `interval_count` units of time before the regular billing cycles, free if there is no price.
+    pub fn trial(mut self, interval_unit: IntervalUnit, interval_count: u32, price: Option<Money>) -> Self {
+        self.trial = Some(Trial {
+            frequency: Frequency {
+                interval_unit,
+                interval_count,
+            },
+            price,
+        });
+        self
+    }
+
+    /// The number of regular billing cycles, the subscription renews until cancelled by default.
+    pub fn cycles(mut self, total_cycles: u32) -> Self {
+        self.total_cycles = total_cycles;
+        self
+    }
+
+    /// A fee charged when subscribing.
+    pub fn setup_fee(mut self, setup_fee: Money) -> Self {
+        self.setup_fee = Some(setup_fee);
+        self
+    }
+
+    /// The tax percentage on the billed amount, and whether the price already includes it.
+    pub fn taxes(mut self, percentage: impl ToString, inclusive: bool) -> Self {
+        self.taxes = Some(Taxes {
+            percentage: percentage.to_string(),
+            inclusive,
+        });
+        self
+    }
+
+    /// The payload creating the product of the plan.
+    pub fn product_payload(&self) -> ProductPayload {
+        match &self.product {
+            Some(product) => product.clone(),
+            None => ProductPayload::new(self.name.as_deref().unwrap_or("Subscription"), ProductType::Service),
+        }
+    }
+
+    /// The payload creating the plan for the given product.
+    pub fn plan_payload(&self, product_id: impl ToString) -> PlanPayload {
+        let mut billing_cycles = Vec::with_capacity(2);
+        if let Some(trial) = &self.trial {
+            billing_cycles.push(BillingCycle {
+                frequency: trial.frequency.clone(),
+                tenure_type: TenureType::Trial,
+                sequence: 1,
+                total_cycles: Some(1),
+                pricing_scheme: trial.price.clone().map(|price| PricingScheme {
+                    fixed_price: Some(price),
+                }),
+            });
+        }
+        billing_cycles.push(BillingCycle {
+            frequency: self.frequency.clone(),
+            tenure_type: TenureType::Regular,
+            sequence: billing_cycles.len() as u32 + 1,
+            total_cycles: Some(self.total_cycles),
+            pricing_scheme: Some(PricingScheme {
+                fixed_price: Some(self.price.clone()),
+            }),
+        });
+
+        PlanPayload {
+            product_id: product_id.to_string(),
+            name: self.name.clone().unwrap_or_else(|| self.product_payload().name),
+            description: self.description.clone(),
+            billing_cycles,
+            payment_preferences: PaymentPreferences {
+                auto_bill_outstanding: Some(true),
+                setup_fee: self.setup_fee.clone(),
+                ..Default::default()
+            },
+            taxes: self.taxes.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the product and then the plan for it.
+    pub async fn create(&self, client: &Client) -> Result<(Product, Plan), ResponseError> {
+        let product = client.execute(&CreateProduct::new(self.product_payload())).await?;
+        let plan = client.execute(&CreatePlan::new(self.plan_payload(&product.id))).await?;
+        Ok((product, plan))
+    }
+}
@@ -0,0 +1,163 @@
+//! Verifies webhook events and correlates them with the orders, payments, subscriptions and payouts they are about.
+//!
+//! [Client::verify_webhook] checks that an event was sent by paypal, from the headers and body of its request. With
+//! the `webhook-verification` feature, the signature is verified offline with the certificate of paypal, else paypal
+//! verifies it.
+//!
+//! A webhook event carries the resource as it was when the event was sent. The ids it relates to are extracted with
+//! [WebhookEvent::related_ids], and the current state of the resource can be fetched, since events may be delivered
+//! late, out of order or more than once.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use paypal_rs::{data::webhooks::WebhookEvent, flows::webhooks::EventResource, Client};
+//!
+//! # async fn handle(client: Client, headers: reqwest::header::HeaderMap, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//! let event = client.verify_webhook(&headers, body, "1JE4291016473214C").await?;
+//! if let Some(order_id) = event.related_ids().order_id {
+//!     println!("the event is about the order {}", order_id);
+//! }
+//! if let Some(EventResource::Capture(capture)) = client.fetch_event_resource(&event).await? {
+//!     println!("the capture is now {:?}", capture.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::HeaderMap;
+
+use crate::{
+    api::webhooks::VerifyWebhookSignature,
+    api::{
+        invoice::GetInvoice,
+        orders::ShowOrderDetails,
+        payments::{GetAuthorizedPayment, ShowCapturedPayment, ShowRefund},
+        payouts::{ShowPayoutBatch, ShowPayoutItem},
+        subscriptions::ShowSubscription,
+    },
+    data::ids::WebhookId,
+    data::invoice::Invoice,
+    data::orders::{Capture, Order, Refund},
+    data::payment::AuthorizedPaymentDetails,
+    data::payouts::{PayoutBatch, PayoutItemDetails},
+    data::subscriptions::Subscription,
+    data::webhooks::{
+        ResourceKind, VerificationStatus, VerifyWebhookSignaturePayload, WebhookEvent, WebhookTransmission,
+    },
+    errors::{ResponseError, VerifyWebhookError},
+    Client,
+};
+
+/// The current state of the resource of a webhook event.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum EventResource {
+    /// A checkout order.
+    Order(Box<Order>),
+    /// A captured payment.
+    Capture(Box<Capture>),
+    /// An authorized payment.
+    Authorization(Box<AuthorizedPaymentDetails>),
+    /// A refund.
+    Refund(Box<Refund>),
+    /// A subscription, also returned for the payments of a subscription.
+    Subscription(Box<Subscription>),
+    /// An invoice.
+    Invoice(Box<Invoice>),
+    /// A payout batch, with its items.
+    PayoutBatch(Box<PayoutBatch>),
+    /// A payout of a batch.
+    PayoutItem(Box<PayoutItemDetails>),
+}
+
+impl Client {
+    /// Verifies that a webhook event was sent by paypal to the given webhook, and parses it.
+    ///
+    /// Give the headers and the body of the request as received. With the `webhook-verification` feature, SHA256withRSA
+    /// signatures are verified offline, with the certificate cached by the `webhook_certs` of the client, else paypal
+    /// verifies the signature.
+    pub async fn verify_webhook(
+        &self,
+        headers: &HeaderMap,
+        body: &[u8],
+        webhook_id: impl Into<WebhookId>,
+    ) -> Result<WebhookEvent, VerifyWebhookError> {
+        let transmission = WebhookTransmission::from_headers(headers)?;
+        let webhook_id = webhook_id.into();
+        let event = serde_json::from_slice(body)?;
+
+        #[cfg(feature = "webhook-verification")]
+        if transmission.auth_algo == crate::verification::AUTH_ALGO {
+            let cert = self.webhook_certs.get(self, &transmission.cert_url).await?;
+            if !crate::verification::verify_signature(&cert, &transmission, &webhook_id, body) {
+                return Err(VerifyWebhookError::InvalidSignature);
+            }
+            return Ok(event);
+        }
+
+        let payload = VerifyWebhookSignaturePayload {
+            transmission,
+            webhook_id,
+            webhook_event: serde_json::from_slice(body)?,
+        };
+        let response = self.execute(&VerifyWebhookSignature::new(payload)).await?;
+        if response.verification_status != VerificationStatus::Success {
+            return Err(VerifyWebhookError::InvalidSignature);
+        }
+        Ok(event)
+    }
+
+    /// Fetches the current state of the resource of the event.
+    ///
+    /// Returns `None` if the kind of the resource is not known or its id can't be found.
+    pub async fn fetch_event_resource(&self, event: &WebhookEvent) -> Result<Option<EventResource>, ResponseError> {
+        let ids = event.related_ids();
+        let resource = match event.resource_kind() {
+            Some(ResourceKind::Order) => match ids.order_id {
+                Some(id) => EventResource::Order(Box::new(self.execute(&ShowOrderDetails::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::Capture) => match ids.capture_id {
+                Some(id) => EventResource::Capture(Box::new(self.execute(&ShowCapturedPayment::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::Authorization) => match ids.authorization_id {
+                Some(id) => EventResource::Authorization(Box::new(self.execute(&GetAuthorizedPayment::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::Refund) => match ids.refund_id {
+                Some(id) => EventResource::Refund(Box::new(self.execute(&ShowRefund::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::Subscription | ResourceKind::Sale) => match ids.subscription_id {
+                Some(id) => EventResource::Subscription(Box::new(self.execute(&ShowSubscription::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::Invoice) => match ids.invoice_id {
+                Some(id) => EventResource::Invoice(Box::new(self.execute(&GetInvoice::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::PayoutBatch) => match ids.payout_batch_id {
+                Some(id) => EventResource::PayoutBatch(Box::new(self.execute(&ShowPayoutBatch::new(id)).await?)),
+                None => return Ok(None),
+            },
+            Some(ResourceKind::PayoutItem) => match ids.payout_item_id {
+                Some(id) => EventResource::PayoutItem(Box::new(self.execute(&ShowPayoutItem::new(id)).await?)),
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        Ok(Some(resource))
+    }
+
+    /// Fetches the current state of the order the event relates to, such as the order of a captured payment.
+    ///
+    /// Returns `None` if the event does not relate to an order.
+    pub async fn fetch_event_order(&self, event: &WebhookEvent) -> Result<Option<Order>, ResponseError> {
+        match event.related_ids().order_id {
+            Some(order_id) => Ok(Some(self.execute(&ShowOrderDetails::new(order_id)).await?)),
+            None => Ok(None),
+        }
+    }
+}
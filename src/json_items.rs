@@ -0,0 +1,164 @@
+//! Splits the array of items of a json response as it is read, so large lists are parsed one item at a time.
+
+use std::collections::VecDeque;
+
+/// Splits the array at a field of the top level object of a json document into the bytes of each item, as the
+/// document is pushed chunk by chunk.
+///
+/// Only the items read and not taken yet are held, the rest of the document is skipped.
+#[derive(Debug)]
+pub(crate) struct JsonItems {
+    field: &'static str,
+    /// The nesting depth of the current position, 1 inside the top level object.
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// The last string of the top level object, which is the key of the value following it.
+    key: Vec<u8>,
+    reading_key: bool,
+    /// Whether the current position is inside the array of items.
+    in_items: bool,
+    item: Vec<u8>,
+    items: VecDeque<Vec<u8>>,
+}
+
+impl JsonItems {
+    /// Splits the array at the given field.
+    pub(crate) fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            key: Vec::new(),
+            reading_key: false,
+            in_items: false,
+            item: Vec::new(),
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Reads the next chunk of the document.
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        for &byte in chunk {
+            self.push_byte(byte);
+        }
+    }
+
+    /// Takes the bytes of the next complete item, if any.
+    pub(crate) fn next_item(&mut self) -> Option<Vec<u8>> {
+        self.items.pop_front()
+    }
+
+    /// Checks that the document ended after the array of items, rather than in the middle of it.
+    pub(crate) fn finish(&self) -> Result<(), serde_json::Error> {
+        if self.depth == 0 && !self.in_string {
+            Ok(())
+        } else {
+            Err(serde::de::Error::custom(
+                "the response ended before the end of the items",
+            ))
+        }
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+                self.reading_key = false;
+            }
+            if self.reading_key {
+                self.key.push(byte);
+            }
+            if self.in_items {
+                self.item.push(byte);
+            }
+            return;
+        }
+
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                if self.depth == 1 {
+                    self.key.clear();
+                    self.reading_key = true;
+                }
+            }
+            b'[' if self.depth == 1 && self.key == self.field.as_bytes() => {
+                self.in_items = true;
+                self.depth += 1;
+                return;
+            }
+            b',' if self.in_items && self.depth == 2 => {
+                self.end_item();
+                return;
+            }
+            b']' if self.in_items && self.depth == 2 => {
+                self.end_item();
+                self.in_items = false;
+                self.depth -= 1;
+                return;
+            }
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+        if self.in_items && !(self.item.is_empty() && byte.is_ascii_whitespace()) {
+            self.item.push(byte);
+        }
+    }
+
+    fn end_item(&mut self) {
+        if !self.item.is_empty() {
+            self.items.push_back(std::mem::take(&mut self.item));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonItems;
+
+    #[test]
+    fn test_json_items() {
+        let document = br#"{
+            "total_items": 3,
+            "other": [{"id": "skipped"}],
+            "items": [
+                {"id": "a", "note": "brackets ], { and \"quotes\" in a string", "nested": [[1, 2], {"items": []}]},
+                {"id": "b"} ,
+                "c"
+            ],
+            "links": [{"href": "https://api.paypal.com", "rel": "self"}]
+        }"#;
+
+        // Any chunking gives the same items.
+        for chunk_size in [1, 7, document.len()] {
+            let mut items = JsonItems::new("items");
+            let mut parsed = Vec::new();
+            for chunk in document.chunks(chunk_size) {
+                items.push(chunk);
+                while let Some(item) = items.next_item() {
+                    parsed.push(serde_json::from_slice::<serde_json::Value>(&item).unwrap());
+                }
+            }
+            items.finish().unwrap();
+
+            assert_eq!(parsed.len(), 3);
+            assert_eq!(parsed[0]["note"], r#"brackets ], { and "quotes" in a string"#);
+            assert_eq!(parsed[0]["nested"][0][1], 2);
+            assert_eq!(parsed[1]["id"], "b");
+            assert_eq!(parsed[2], "c");
+        }
+
+        let mut items = JsonItems::new("items");
+        items.push(br#"{"items": [{"id": "a"}, {"id": "#);
+        assert!(items.next_item().is_some());
+        assert!(items.next_item().is_none());
+        assert!(items.finish().is_err());
+    }
+}
@@ -29,7 +29,7 @@
 //!     let clientid = std::env::var("PAYPAL_CLIENTID").unwrap();
 //!     let secret = std::env::var("PAYPAL_SECRET").unwrap();
 //!
-//!     let mut client = Client::new(clientid, secret, PaypalEnv::Sandbox);
+//!     let client = Client::new(clientid, secret, PaypalEnv::Sandbox);
 //!
 //!     client.get_access_token().await.unwrap();
 //!
@@ -45,6 +45,19 @@
 //! }
 //! ```
 //!
+//! ## Features
+//!
+//! - `native-tls` (default), `rustls`: the TLS backend. Disable the default features to use rustls only.
+//! - `gzip` (default), `brotli`, `deflate`: request compressed responses and decompress them.
+//! - `tracing`: instrument the token acquisition and the api calls with [tracing](https://docs.rs/tracing) spans,
+//!   recording the method, path, status, paypal debug id and latency. Authorization headers and card data are redacted.
+//! - `test-util`: canned api responses, [wiremock](https://docs.rs/wiremock) helpers and a recorder replaying sandbox
+//!   interactions in `test_util`, to test code using this crate without a sandbox account.
+//! - `decimal`: create and read `Money` values as [rust_decimal](https://docs.rs/rust_decimal) decimals, formatted with
+//!   the precision of their currency.
+//! - `webhook-verification`: verify the signature of webhook events offline in `verification`, with the certificates
+//!   paypal signs them with, cached and optionally pinned.
+//!
 //! ## Testing
 //! You need the enviroment variables PAYPAL_CLIENTID and PAYPAL_SECRET to be set.
 //!
@@ -67,37 +80,93 @@
 //! - - [x] Update Invoice
 //! - - [x] Cancel Invoice
 //! - - [x] Send Invoice
-//! - - [ ] Send Invoice Reminder
-//! - - [ ] List Templates
-//! - - [ ] Create Template
-//! - - [ ] Delete Template
-//! - - [ ] Fully Update Template
-//! - - [ ] Show Template Template
+//! - - [x] Send Invoice Reminder
+//! - - [x] Search Invoices
+//! - - [x] Record payment for invoice
+//! - - [x] Record refund for invoice
+//! - - [x] Delete external payment
+//! - - [x] Delete external refund
+//! - - [x] Generate QR code
+//! - - [x] Upload invoice attachment
+//! - - [x] List Templates
+//! - - [x] Create Template
+//! - - [x] Delete Template
+//! - - [x] Fully Update Template
+//! - - [x] Show Template Template
 //! - [ ] Payments API - 0.3.0
+//! - - [x] Show captured payment details
+//! - - [x] Show refund details
 //! - [ ] Tracking API - 0.4.0
 //! - [ ] Subscriptions API - 0.5.0
+//! - - [x] Create plan
+//! - - [x] List plans
+//! - - [x] Show plan details
+//! - - [x] Show subscription details
 //! - [ ] Identity API - 0.6.0
 //! - [ ] Disputes API - 0.7.0
 //! - [ ] Catalog Products API - 0.8.0
-//! - [ ] Partner Referrals API - 0.9.0
+//! - - [x] Create product
+//! - - [x] List products
+//! - - [x] Show product details
+//! - [x] Partner Referrals API - 0.9.0
+//! - - [x] Create partner referral
+//! - - [x] Show referral data
+//! - - [x] Show seller status
+//! - - [x] List seller tracking information
 //! - [ ] Payouts API - 0.10.0
+//! - - [x] Create batch payout
+//! - - [x] Show payout batch details
+//! - - [x] Show payout item details
 //! - [ ] Transaction Search API - 0.11.0
+//! - - [x] List transactions
 //! - [ ] Referenced Payouts API - 0.12.0
-//! - [ ] Vault API - 0.13.0
+//! - [x] Vault API - 0.13.0
+//! - - [x] Create setup token
+//! - - [x] Show setup token
+//! - - [x] Create payment token
+//! - - [x] List payment tokens
+//! - - [x] Show payment token
+//! - - [x] Delete payment token
 //! - [ ] Webhooks Management API - 0.14.0
-//! - [ ] Payment Experience Web Profiles API - 1.0.0
+//! - - [x] Create webhook
+//! - - [x] List webhooks
+//! - - [x] Verify webhook signature
+//! - [x] Payment Experience Web Profiles API - 1.0.0
+//! - - [x] Create web experience profile
+//! - - [x] List web experience profiles
+//! - - [x] Show web experience profile details
+//! - - [x] Update web experience profile
+//! - - [x] Partially update web experience profile
+//! - - [x] Delete web experience profile
+//! - [ ] Billing Agreements API - 1.1.0
+//! - - [x] Create agreement token
+//! - - [x] Create billing agreement
+//! - - [x] Show billing agreement details
+//! - - [x] Cancel billing agreement
 
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
 
+#[macro_use]
+mod macros;
+
 pub mod api;
+pub mod cache;
 pub mod client;
 pub mod countries;
 pub mod data;
 pub mod endpoint;
 pub mod errors;
+pub mod flows;
+mod json_items;
+pub mod pool;
 pub use client::*;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "webhook-verification")]
+pub mod verification;
 
+use base64::Engine;
 use derive_builder::Builder;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
@@ -106,6 +175,10 @@ use serde_with::skip_serializing_none;
 pub const LIVE_ENDPOINT: &str = "https://api-m.paypal.com";
 /// The paypal api endpoint used on when testing.
 pub const SANDBOX_ENDPOINT: &str = "https://api-m.sandbox.paypal.com";
+/// The paypal website used on a live application, where users are sent to log in or approve payments.
+pub const LIVE_WEB_ENDPOINT: &str = "https://www.paypal.com";
+/// The paypal website used when testing.
+pub const SANDBOX_WEB_ENDPOINT: &str = "https://www.sandbox.paypal.com";
 /// Represents the query used in most GET api requests.
 ///
 /// Reference: <https://developer.paypal.com/docs/api/reference/api-requests/#query-parameters>
@@ -131,9 +204,9 @@ pub struct Query {
     /// Indicates whether to show the total count in the response.
     pub total_count_required: Option<bool>,
     /// Sorts the payments in the response by a specified value, such as the create time or update time.
-    pub sort_by: Option<String>,
+    pub sort_by: Option<SortField>,
     /// Sorts the items in the response in ascending or descending order.
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
     /// The ID of the starting resource in the response.
     /// When results are paged, you can use the next_id value as the start_id to continue with the next set of results.
     pub start_id: Option<String>,
@@ -142,7 +215,29 @@ pub struct Query {
     pub start_index: Option<i32>,
     /// The start date and time for the range to show in the response.
     pub start_time: Option<chrono::DateTime<chrono::Utc>>,
-    // TODO: Use https://github.com/samscott89/serde_qs
+    /// The fields to return in the response, sent as a comma-separated list.
+    #[serde(serialize_with = "endpoint::serialize_comma_separated")]
+    pub fields: Option<Vec<String>>,
+}
+
+/// The field the payments are sorted by, see [Query::sort_by].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortField {
+    /// The date and time when the payment was created.
+    CreateTime,
+    /// The date and time when the payment was last updated.
+    UpdateTime,
+}
+
+/// The order the items of a list are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SortOrder {
+    /// From the lowest to the highest value, or the oldest to the newest.
+    Asc,
+    /// From the highest to the lowest value, or the newest to the oldest.
+    Desc,
 }
 
 /// Represents the optional header values used on paypal requests.
@@ -150,8 +245,10 @@ pub struct Query {
 /// <https://developer.paypal.com/docs/api/reference/api-requests/#paypal-auth-assertion>
 #[derive(Debug, Default, Builder, Clone)]
 pub struct HeaderParams {
-    /// The merchant payer id used on PayPal-Auth-Assertion
+    /// The merchant payer id used on PayPal-Auth-Assertion, with the client id as issuer.
     pub merchant_payer_id: Option<String>,
+    /// The PayPal-Auth-Assertion claims, takes precedence over the merchant payer id.
+    pub auth_assertion: Option<AuthAssertion>,
     /// Verifies that the payment originates from a valid, user-consented device and application.
     /// Reduces fraud and decreases declines. Transactions that do not include a client metadata ID are not eligible for PayPal Seller Protection.
     pub client_metadata_id: Option<String>,
@@ -164,18 +261,171 @@ pub struct HeaderParams {
     pub request_id: Option<String>,
     /// The media type. Required for operations with a request body.
     pub content_type: Option<String>,
+    /// Simulates an error on the sandbox, to test how it is handled.
+    pub mock_response: Option<MockResponse>,
+    /// How much of the resource paypal returns, [Prefer::Representation] if not set.
+    pub prefer: Option<Prefer>,
+    /// The timeout of each attempt of this request, overriding the timeout of the http client.
+    pub timeout: Option<std::time::Duration>,
 }
 
-#[derive(Debug, Serialize)]
-struct AuthAssertionClaims {
+/// The Prefer header, which sets how much of the resource paypal returns in the response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Prefer {
+    /// Returns only the id, status and HATEOAS links of the resource. Faster, useful in high-volume flows.
+    Minimal,
+    /// Returns the complete resource.
+    #[default]
+    Representation,
+}
+
+impl Prefer {
+    /// Returns the value of the header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Prefer::Minimal => "return=minimal",
+            Prefer::Representation => "return=representation",
+        }
+    }
+}
+
+/// The PayPal-Mock-Response header, which makes the sandbox answer with the given error.
+///
+/// <https://developer.paypal.com/tools/sandbox/negative-testing/request-headers/>
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::{HeaderParams, MockResponse};
+///
+/// let headers = HeaderParams {
+///     mock_response: Some(MockResponse::new(MockResponse::INSTRUMENT_DECLINED)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MockResponse {
+    /// The error code to simulate.
+    pub mock_application_codes: String,
+}
+
+impl MockResponse {
+    /// The processor or bank declined the payment source.
+    pub const INSTRUMENT_DECLINED: &'static str = "INSTRUMENT_DECLINED";
+    /// The request was refused.
+    pub const TRANSACTION_REFUSED: &'static str = "TRANSACTION_REFUSED";
+    /// The payer must take an action, such as completing a 3D Secure challenge.
+    pub const PAYER_ACTION_REQUIRED: &'static str = "PAYER_ACTION_REQUIRED";
+    /// The order was already captured.
+    pub const ORDER_ALREADY_CAPTURED: &'static str = "ORDER_ALREADY_CAPTURED";
+    /// The payer has not approved the order.
+    pub const ORDER_NOT_APPROVED: &'static str = "ORDER_NOT_APPROVED";
+    /// The invoice id was already used.
+    pub const DUPLICATE_INVOICE_ID: &'static str = "DUPLICATE_INVOICE_ID";
+    /// The card expired.
+    pub const CARD_EXPIRED: &'static str = "CARD_EXPIRED";
+    /// The resource id does not exist.
+    pub const INVALID_RESOURCE_ID: &'static str = "INVALID_RESOURCE_ID";
+    /// The caller does not have permission for the action.
+    pub const PERMISSION_DENIED: &'static str = "PERMISSION_DENIED";
+    /// An internal server error.
+    pub const INTERNAL_SERVER_ERROR: &'static str = "INTERNAL_SERVER_ERROR";
+
+    /// Simulates the error with the given code.
+    pub fn new(code: impl ToString) -> Self {
+        Self {
+            mock_application_codes: code.to_string(),
+        }
+    }
+}
+
+impl HeaderParams {
+    /// Sets a random (UUID v4) request id, unless one is already set.
+    pub fn with_generated_request_id(mut self) -> Self {
+        if self.request_id.is_none() {
+            self.request_id = Some(uuid::Uuid::new_v4().to_string());
+        }
+        self
+    }
+
+    /// Fills the unset values with the given defaults.
+    pub(crate) fn or(self, defaults: &HeaderParams) -> HeaderParams {
+        HeaderParams {
+            merchant_payer_id: self.merchant_payer_id.or_else(|| defaults.merchant_payer_id.clone()),
+            auth_assertion: self.auth_assertion.or_else(|| defaults.auth_assertion.clone()),
+            client_metadata_id: self.client_metadata_id.or_else(|| defaults.client_metadata_id.clone()),
+            partner_attribution_id: self
+                .partner_attribution_id
+                .or_else(|| defaults.partner_attribution_id.clone()),
+            request_id: self.request_id.or_else(|| defaults.request_id.clone()),
+            content_type: self.content_type.or_else(|| defaults.content_type.clone()),
+            mock_response: self.mock_response.or_else(|| defaults.mock_response.clone()),
+            prefer: self.prefer.or(defaults.prefer),
+            timeout: self.timeout.or(defaults.timeout),
+        }
+    }
+}
+
+/// The claims of the PayPal-Auth-Assertion header, identifying the merchant a platform acts on behalf of.
+///
+/// It is sent as an unsigned JWT, as described in <https://developer.paypal.com/api/rest/requests/#link-paypalauthassertion>
+///
+/// # Examples
+///
+/// ```
+/// use paypal_rs::AuthAssertion;
+///
+/// let assertion = AuthAssertion::for_payer_id("clientid", "8KK8451T");
+/// assert_eq!(
+///     assertion.encode(),
+///     "eyJhbGciOiJub25lIn0.eyJpc3MiOiJjbGllbnRpZCIsInBheWVyX2lkIjoiOEtLODQ1MVQifQ."
+/// );
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuthAssertion {
+    /// The client id of the platform making the request.
     pub iss: String,
-    pub payer_id: String,
+    /// The payer id of the merchant.
+    pub payer_id: Option<String>,
+    /// The email of the merchant, when the payer id is not known.
+    pub email: Option<String>,
+}
+
+impl AuthAssertion {
+    /// Acts on behalf of the merchant with the given payer id.
+    pub fn for_payer_id(client_id: impl ToString, payer_id: impl ToString) -> Self {
+        Self {
+            iss: client_id.to_string(),
+            payer_id: Some(payer_id.to_string()),
+            email: None,
+        }
+    }
+
+    /// Acts on behalf of the merchant with the given email.
+    pub fn for_email(client_id: impl ToString, email: impl ToString) -> Self {
+        Self {
+            iss: client_id.to_string(),
+            payer_id: None,
+            email: Some(email.to_string()),
+        }
+    }
+
+    /// Encodes the claims as an unsigned JWT, the value of the PayPal-Auth-Assertion header.
+    pub fn encode(&self) -> String {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        let header = engine.encode(r#"{"alg":"none"}"#);
+        let claims = engine.encode(serde_json::to_vec(self).expect("serialize the auth assertion"));
+        // The signature is empty, but the trailing dot is still required.
+        format!("{}.{}.", header, claims)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::countries::Country;
-    use crate::data::common::Currency;
+    use crate::data::common::{Currency, Money};
+    use crate::errors::MoneyError;
     //use crate::Client;
     //use std::env;
     use std::str::FromStr;
@@ -195,6 +445,243 @@ mod tests {
         assert_eq!(Currency::EUR.to_string(), "EUR");
         assert_eq!(Currency::JPY.to_string(), "JPY");
         assert_eq!(Currency::JPY, Currency::from_str("JPY").unwrap());
+        assert_eq!(Currency::SEK, Currency::from_str("SEK").unwrap());
+        assert_eq!(Currency::from_str("XYZ").unwrap(), Currency::Unknown("XYZ".to_string()));
+        assert_eq!(Currency::Unknown("XYZ".to_string()).to_string(), "XYZ");
+        assert!(Currency::from_str("usd").is_err());
+        assert_eq!(serde_json::to_string(&Currency::USD).unwrap(), "\"USD\"");
+        assert_eq!(serde_json::from_str::<Currency>("\"ZAR\"").unwrap(), Currency::ZAR);
+        assert_eq!(Currency::ALL.to_string(), "ALL");
+        assert!(Currency::VALUES.contains(&Currency::ALL));
+        assert!(Currency::VALUES.contains(&Currency::USD));
+        assert!(!Currency::VALUES
+            .iter()
+            .any(|currency| matches!(currency, Currency::Unknown(_))));
+    }
+
+    #[test]
+    fn test_money_validate() {
+        assert!(Money::usd("10").validate().is_ok());
+        assert!(Money::usd("-10.5").validate().is_ok());
+        assert!(Money::usd(".50").validate().is_ok());
+        assert!(Money::jpy("1000").validate().is_ok());
+        let money = Money {
+            currency_code: Currency::TND,
+            value: "1.125".to_string(),
+        };
+        assert!(money.validate().is_ok());
+        assert_eq!(
+            Money::usd("10.").validate(),
+            Err(MoneyError::InvalidValue("10.".to_string()))
+        );
+        assert_eq!(
+            Money::usd("1e3").validate(),
+            Err(MoneyError::InvalidValue("1e3".to_string()))
+        );
+        assert_eq!(Money::usd("").validate(), Err(MoneyError::InvalidValue("".to_string())));
+        assert_eq!(
+            Money::jpy("10.00").validate(),
+            Err(MoneyError::InvalidPrecision {
+                value: "10.00".to_string(),
+                currency: Currency::JPY,
+                exponent: 0,
+            })
+        );
+        assert!(Money::usd("10.001").validate().is_err());
+    }
+
+    #[test]
+    fn test_money_arithmetic() {
+        assert_eq!(
+            Money::usd("10").checked_add(&Money::usd("0.5")),
+            Ok(Money::usd("10.50"))
+        );
+        assert_eq!(
+            Money::usd("1.25").checked_sub(&Money::usd("2")),
+            Ok(Money::usd("-0.75"))
+        );
+        assert_eq!(Money::jpy("150").mul_quantity(3), Ok(Money::jpy("450")));
+        assert_eq!(Money::usd("19.99").mul_quantity(0), Ok(Money::usd("0.00")));
+        assert_eq!(
+            Money::usd("1").checked_add(&Money::eur("1")),
+            Err(MoneyError::CurrencyMismatch {
+                expected: Currency::USD,
+                found: Currency::EUR,
+            })
+        );
+        assert_eq!(
+            Money::usd("abc").checked_sub(&Money::usd("1")),
+            Err(MoneyError::InvalidValue("abc".to_string()))
+        );
+        assert_eq!(
+            Money::usd("9".repeat(32))
+                .mul_quantity(u32::MAX)
+                .and_then(|m| m.mul_quantity(u32::MAX)),
+            Err(MoneyError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_unknown_enum_values() {
+        use crate::data::orders::OrderStatus;
+
+        assert_eq!(
+            serde_json::from_str::<OrderStatus>("\"COMPLETED\"").unwrap(),
+            OrderStatus::Completed
+        );
+        let status = serde_json::from_str::<OrderStatus>("\"SOME_NEW_STATUS\"").unwrap();
+        assert_eq!(status, OrderStatus::Unknown("SOME_NEW_STATUS".to_string()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"SOME_NEW_STATUS\"");
+
+        assert_eq!(
+            serde_json::from_str::<OrderStatus>("\"PAYER_ACTION_REQUIRED\"").unwrap(),
+            OrderStatus::PayerActionRequired
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::data::orders::ItemCategoryType>("\"DONATION\"").unwrap(),
+            crate::data::orders::ItemCategoryType::Donation
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::data::orders::CaptureStatusDetailsReason>(
+                "\"DECLINED_BY_RISK_FRAUD_FILTERS\""
+            )
+            .unwrap(),
+            crate::data::orders::CaptureStatusDetailsReason::DeclinedByRiskFraudFilters
+        );
+    }
+
+    #[test]
+    fn test_link_rel() {
+        use crate::data::common::{LinkDescription, LinkRel, Links};
+
+        let links: Vec<LinkDescription> = serde_json::from_str(
+            r#"[
+                {"href": "https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T", "rel": "self", "method": "GET"},
+                {"href": "https://www.paypal.com/checkoutnow?token=5O190127TN364715T", "rel": "payer-action", "method": "GET"},
+                {"href": "https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T", "rel": "edit", "method": "PATCH"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(links[0].rel, Some(LinkRel::SelfLink));
+        assert_eq!(links[2].rel, Some(LinkRel::Unknown("edit".to_string())));
+        assert_eq!(
+            links.href(LinkRel::PayerAction),
+            Some("https://www.paypal.com/checkoutnow?token=5O190127TN364715T")
+        );
+        assert_eq!(links.find_rel(LinkRel::Unknown("edit".to_string())), Some(&links[2]));
+        assert!(links.find_rel(LinkRel::Approve).is_none());
+    }
+
+    #[test]
+    fn test_address_validate() {
+        use crate::data::common::Address;
+        use crate::errors::AddressError;
+
+        let address = |country: Country, admin_area: Option<&str>, postal_code: Option<&str>| Address {
+            admin_area_1: admin_area.map(ToString::to_string),
+            postal_code: postal_code.map(ToString::to_string),
+            country_code: Some(country),
+            ..Default::default()
+        };
+
+        assert!(address(Country::US, Some("CA"), Some("95131-1234")).validate().is_ok());
+        assert!(address(Country::CA, Some("ON"), Some("K1A 0B1")).validate().is_ok());
+        assert!(address(Country::GB, None, Some("SW1A 1AA")).validate().is_ok());
+        assert!(address(Country::DE, None, Some("10115")).validate().is_ok());
+        assert!(address(Country::BR, Some("SP"), Some("01310-100")).validate().is_ok());
+        assert!(address(Country::JP, Some("Tokyo"), Some("100-0001")).validate().is_ok());
+        // Countries without known formats only need the country.
+        assert!(address(Country::BE, None, None).validate().is_ok());
+
+        assert_eq!(
+            Address::default().validate(),
+            Err(AddressError::MissingField("country_code"))
+        );
+        assert_eq!(
+            address(Country::US, Some("CA"), None).validate(),
+            Err(AddressError::MissingField("postal_code"))
+        );
+        assert_eq!(
+            address(Country::US, None, Some("95131")).validate(),
+            Err(AddressError::MissingField("admin_area_1"))
+        );
+        assert_eq!(
+            address(Country::US, Some("California"), Some("95131")).validate(),
+            Err(AddressError::InvalidAdminArea {
+                country: Country::US,
+                admin_area: "California".to_string()
+            })
+        );
+        assert_eq!(
+            address(Country::DE, None, Some("1011")).validate(),
+            Err(AddressError::InvalidPostalCode {
+                country: Country::DE,
+                postal_code: "1011".to_string()
+            })
+        );
+        assert!(address(Country::GB, None, Some("SW1A1AAA")).validate().is_err());
+    }
+
+    #[test]
+    fn test_event_type() {
+        use crate::data::webhooks::{EventType, WebhookEventType};
+
+        let event_type: EventType = "PAYMENT.CAPTURE.COMPLETED".parse().unwrap();
+        assert_eq!(event_type, EventType::PaymentCaptureCompleted);
+        assert_eq!(event_type.to_string(), "PAYMENT.CAPTURE.COMPLETED");
+        assert_eq!(
+            "SOME.NEW.EVENT".parse::<EventType>().unwrap(),
+            EventType::Unknown("SOME.NEW.EVENT".to_string())
+        );
+        assert_eq!(
+            serde_json::to_string(&WebhookEventType::from(EventType::CheckoutOrderApproved)).unwrap(),
+            r#"{"name":"CHECKOUT.ORDER.APPROVED"}"#
+        );
+    }
+
+    #[test]
+    fn test_date_time() {
+        use crate::data::invoice::{InvoiceDetail, Metadata};
+
+        let metadata: Metadata = serde_json::from_str(
+            r#"{"create_time": "2024-03-01T14:30:00-07:00", "last_update_time": "2024-03-01T21:30:00.123456789Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({
+                "create_time": "2024-03-01T21:30:00Z",
+                "last_update_time": "2024-03-01T21:30:00.123Z",
+            })
+        );
+        assert_eq!(serde_json::to_string(&Metadata::default()).unwrap(), "{}");
+
+        let detail: InvoiceDetail =
+            serde_json::from_str(r#"{"currency_code": "USD", "invoice_date": "2024-03-01 PST"}"#).unwrap();
+        assert_eq!(detail.invoice_date, chrono::NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(serde_json::to_value(&detail).unwrap()["invoice_date"], "2024-03-01");
+
+        let metadata: Metadata = serde_json::from_str(r#"{"create_time": "2024-03-01T14:30:00-0700"}"#).unwrap();
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap()["create_time"],
+            "2024-03-01T21:30:00Z"
+        );
+
+        assert!(serde_json::from_str::<Metadata>(r#"{"create_time": "yesterday"}"#).is_err());
+    }
+
+    #[test]
+    fn test_ids() {
+        use crate::data::ids::OrderId;
+
+        let id: OrderId = serde_json::from_str("\"5O190127TN364715T\"").unwrap();
+        assert_eq!(id, "5O190127TN364715T");
+        assert_eq!(id, OrderId::new("5O190127TN364715T"));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"5O190127TN364715T\"");
+        assert_eq!(
+            format!("/v2/checkout/orders/{}", id),
+            "/v2/checkout/orders/5O190127TN364715T"
+        );
     }
 
     #[test]
@@ -202,5 +689,109 @@ mod tests {
         assert_eq!(Country::US.to_string(), "US");
         assert_eq!(Country::ES.to_string(), "ES");
         assert_eq!(Country::ES, Country::from_str("ES").unwrap());
+        assert_eq!(Country::from_str("ZZ").unwrap(), Country::Unknown("ZZ".to_string()));
+        assert!(Country::from_str("Spain").is_err());
+        assert_eq!(serde_json::to_string(&Country::GB).unwrap(), "\"GB\"");
+        assert_eq!(serde_json::from_str::<Country>("\"XK\"").unwrap(), Country::XK);
+        assert_eq!(Country::VALUES.len(), 251);
+        assert!(Country::VALUES.contains(&Country::PR));
+    }
+
+    #[test]
+    fn test_exchange_rate() {
+        use crate::data::orders::SellerPayableBreakdown;
+
+        let breakdown: SellerPayableBreakdown = serde_json::from_value(serde_json::json!({
+            "gross_amount": { "currency_code": "USD", "value": "10.00" },
+            "paypal_fee": { "currency_code": "USD", "value": "0.00" },
+            "net_amount": { "currency_code": "USD", "value": "10.00" },
+            "net_amount_breakdown": [{
+                "payable_amount": { "currency_code": "USD", "value": "10.00" },
+                "converted_amount": { "currency_code": "EUR", "value": "9.12" },
+                "exchange_rate": { "source_currency": "USD", "target_currency": "EUR", "value": "0.912345" }
+            }],
+            "total_refunded_amount": { "currency_code": "USD", "value": "10.00" }
+        }))
+        .unwrap();
+
+        assert_eq!(breakdown.net_in(&Currency::USD).unwrap(), Money::usd("10.00"));
+        assert_eq!(breakdown.net_in(&Currency::EUR).unwrap(), Money::eur("9.12"));
+        assert!(breakdown.net_in(&Currency::JPY).is_err());
+
+        let net_amount_breakdown = &breakdown.net_amount_breakdown.as_ref().unwrap()[0];
+        assert_eq!(
+            net_amount_breakdown.to_string(),
+            "10.00 USD = 9.12 EUR (1 USD = 0.912345 EUR)"
+        );
+        let rate = &net_amount_breakdown.exchange_rate;
+        assert_eq!(rate.convert(&Money::usd("-10.00")).unwrap(), Money::eur("-9.12"));
+        assert_eq!(rate.convert(&Money::usd("0.01")).unwrap(), Money::eur("0.01"));
+    }
+
+    #[test]
+    fn test_error_classification() {
+        use crate::errors::{ErrorName, Issue, PaypalError, ResponseError};
+
+        let mut error: PaypalError = serde_json::from_value(serde_json::json!({
+            "name": "UNPROCESSABLE_ENTITY",
+            "details": [
+                { "issue": "INSTRUMENT_DECLINED" },
+                { "issue": "SOMETHING_NEW" }
+            ]
+        }))
+        .unwrap();
+        error.status = Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.name, ErrorName::UnprocessableEntity);
+        assert_eq!(
+            ErrorName::from("SOMETHING_NEW"),
+            ErrorName::Unknown("SOMETHING_NEW".to_string())
+        );
+        assert!(error.has_issue(Issue::InstrumentDeclined));
+        assert!(error.has_issue("SOMETHING_NEW"));
+
+        let error = ResponseError::ApiError(error);
+        assert_eq!(
+            error.issue_codes(),
+            [Issue::InstrumentDeclined, Issue::Unknown("SOMETHING_NEW".to_string())]
+        );
+        assert!(!error.is_retryable());
+        assert!(!error.is_rate_limited());
+
+        let rate_limited = ResponseError::RateLimited {
+            retry_after: None,
+            error: Some(PaypalError {
+                name: ErrorName::RateLimitReached,
+                ..Default::default()
+            }),
+        };
+        assert!(rate_limited.is_rate_limited());
+        assert!(rate_limited.is_retryable());
+        assert!(rate_limited.issue_codes().is_empty());
+
+        let server_error = ResponseError::Unexpected {
+            status: reqwest::StatusCode::BAD_GATEWAY,
+            body: String::new(),
+        };
+        assert!(server_error.is_retryable());
+        assert!(!server_error.is_rate_limited());
+        assert!(server_error.paypal_error().is_none());
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        use crate::RetryPolicy;
+        use std::time::Duration;
+
+        let policy = RetryPolicy::new(5).jitter(false);
+        assert_eq!(policy.delay(1), Duration::from_millis(500));
+        assert_eq!(policy.delay(3), Duration::from_secs(2));
+        assert_eq!(policy.delay(10), Duration::from_secs(30));
+
+        let policy = RetryPolicy::new(5).base_delay(Duration::from_secs(10));
+        let delays: Vec<_> = (0..20).map(|_| policy.delay(1)).collect();
+        assert!(delays
+            .iter()
+            .all(|delay| (Duration::from_secs(5)..=Duration::from_secs(10)).contains(delay)));
+        assert!(delays.iter().any(|delay| *delay != delays[0]));
     }
 }
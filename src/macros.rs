@@ -0,0 +1,102 @@
+//! Macros used to define the api data types.
+
+/// Defines an enum of the string values paypal sends, from a single table of variants and values.
+///
+/// Generates `VALUES`, `as_str`, [Display](std::fmt::Display), the conversions from and into [String] and the serde impls.
+/// Values not in the table are kept in the `Unknown(String)` catch-all variant, declared first, so new values paypal
+/// adds don't break deserialization. It is placed last in the generated enum.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(#[$unknown_meta:meta])*
+            Unknown(String),
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(from = "String", into = "String")]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            $(#[$unknown_meta])*
+            Unknown(String),
+        }
+
+        impl $name {
+            /// All the values known by this crate, in the order they are declared.
+            pub const VALUES: &'static [$name] = &[$($name::$variant,)*];
+
+            /// Returns the value as sent by paypal.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    value => value.as_str().to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+/// Implements [Links](crate::data::common::Links) for the types with a `links` field.
+///
+/// Prefix the types with `optional` when the field is an `Option<Vec<LinkDescription>>`.
+macro_rules! impl_links {
+    (optional $($name:ty),+ $(,)?) => {
+        $(
+            impl $crate::data::common::Links for $name {
+                fn links(&self) -> &[$crate::data::common::LinkDescription] {
+                    self.links.as_deref().unwrap_or_default()
+                }
+            }
+        )+
+    };
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl $crate::data::common::Links for $name {
+                fn links(&self) -> &[$crate::data::common::LinkDescription] {
+                    &self.links
+                }
+            }
+        )+
+    };
+}
@@ -0,0 +1,144 @@
+//! Holds a client per tenant, for applications operating many paypal accounts.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::{errors::PoolError, Client, ClientBuilder, PaypalEnv, Secret};
+
+/// Configures the builder of the client of a tenant, see [ClientPool::configure].
+pub type ConfigureClient = Arc<dyn Fn(&str, ClientBuilder) -> ClientBuilder + Send + Sync>;
+
+struct Tenant {
+    client_id: String,
+    secret: Secret,
+    env: PaypalEnv,
+    client: Option<Client>,
+}
+
+/// The clients of many paypal accounts, one per tenant, such as a regional entity.
+///
+/// Clients are created on first use, share a single http client, and get and refresh their own access tokens.
+///
+/// # Examples
+///
+/// ```no_run
+/// use paypal_rs::{api::orders::ShowOrderDetails, pool::ClientPool, PaypalEnv};
+///
+/// # async fn show() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = ClientPool::new().configure(|_, builder| builder.allow_live());
+/// pool.add_tenant("eu", "eu-client-id", "eu-secret", PaypalEnv::Live);
+/// pool.add_tenant("us", "us-client-id", "us-secret", PaypalEnv::Live);
+///
+/// let client = pool.for_tenant("eu")?;
+/// let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientPool {
+    http_client: reqwest::Client,
+    configure: Option<ConfigureClient>,
+    tenants: RwLock<HashMap<String, Tenant>>,
+}
+
+impl ClientPool {
+    /// An empty pool, whose clients share a default http client.
+    pub fn new() -> Self {
+        Self::with_http_client(reqwest::Client::new())
+    }
+
+    /// An empty pool, whose clients share the given http client.
+    pub fn with_http_client(http_client: reqwest::Client) -> Self {
+        Self {
+            http_client,
+            configure: None,
+            tenants: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Configures the clients created from now on, given the tenant id, for example to set a retry policy.
+    ///
+    /// The http client of the pool is set before, and should not be replaced.
+    pub fn configure(
+        mut self,
+        configure: impl Fn(&str, ClientBuilder) -> ClientBuilder + Send + Sync + 'static,
+    ) -> Self {
+        self.configure = Some(Arc::new(configure));
+        self
+    }
+
+    /// Adds the credentials of a tenant, replacing the previous ones and their client if there were any.
+    pub fn add_tenant(
+        &self,
+        tenant_id: impl ToString,
+        client_id: impl ToString,
+        secret: impl ToString,
+        env: PaypalEnv,
+    ) {
+        let tenant = Tenant {
+            client_id: client_id.to_string(),
+            secret: Secret::new(secret.to_string()),
+            env,
+            client: None,
+        };
+        self.tenants.write().unwrap().insert(tenant_id.to_string(), tenant);
+    }
+
+    /// Removes a tenant, returning whether it existed. Clones of its client keep working.
+    pub fn remove_tenant(&self, tenant_id: &str) -> bool {
+        self.tenants.write().unwrap().remove(tenant_id).is_some()
+    }
+
+    /// Returns the ids of the tenants, in no particular order.
+    pub fn tenants(&self) -> Vec<String> {
+        self.tenants.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Returns the client of the tenant, creating it on first use.
+    ///
+    /// The returned clients are clones sharing the access token of the tenant, so it is only got once. Fails if the
+    /// tenant was not added, or if its client can't be built, such as a live tenant without
+    /// [allowing live clients](ClientBuilder::allow_live) in debug builds or when `PAYPAL_FORCE_SANDBOX` is set.
+    pub fn for_tenant(&self, tenant_id: &str) -> Result<Client, PoolError> {
+        let unknown_tenant = || PoolError::UnknownTenant(tenant_id.to_string());
+        if let Some(client) = self
+            .tenants
+            .read()
+            .unwrap()
+            .get(tenant_id)
+            .ok_or_else(unknown_tenant)?
+            .client
+            .clone()
+        {
+            return Ok(client);
+        }
+
+        let mut tenants = self.tenants.write().unwrap();
+        let tenant = tenants.get_mut(tenant_id).ok_or_else(unknown_tenant)?;
+        // Another thread may have created it while waiting for the lock.
+        if let Some(client) = &tenant.client {
+            return Ok(client.clone());
+        }
+        let mut builder = Client::builder(&tenant.client_id, tenant.secret.expose(), tenant.env.clone())
+            .http_client(self.http_client.clone());
+        if let Some(configure) = &self.configure {
+            builder = configure(tenant_id, builder);
+        }
+        let client = builder.build()?;
+        tenant.client = Some(client.clone());
+        Ok(client)
+    }
+}
+
+impl Default for ClientPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ClientPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientPool")
+            .field("tenants", &self.tenants())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,76 @@
+//! Fixtures and [wiremock] helpers to test code using this crate without a sandbox account.
+//!
+//! # Examples
+//!
+//! ```
+//! use paypal_rs::{api::orders::ShowOrderDetails, test_util};
+//! use wiremock::MockServer;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let server = MockServer::start().await;
+//! let client = test_util::mock_client(&server).await;
+//!
+//! let endpoint = ShowOrderDetails::new("5O190127TN364715T");
+//! test_util::mock_endpoint(&endpoint, 200, test_util::fixtures::CAPTURE_ORDER)
+//!     .mount(&server)
+//!     .await;
+//!
+//! let order = client.execute(&endpoint).await.unwrap();
+//! assert_eq!(order.id, "5O190127TN364715T");
+//! # }
+//! ```
+
+pub mod roundtrip;
+pub mod vcr;
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::{endpoint::Endpoint, Client, PaypalEnv};
+
+/// Canned json responses of the paypal api.
+pub mod fixtures {
+    /// An access token response, the token is `TESTBEARERTOKEN`.
+    pub const ACCESS_TOKEN: &str = include_str!("../../tests/resources/oauth_token.json");
+    /// A created order response.
+    pub const CREATE_ORDER: &str = include_str!("../../tests/resources/create_order_response.json");
+    /// A captured order response, with a completed capture.
+    pub const CAPTURE_ORDER: &str = include_str!("../../tests/resources/capture_order_response.json");
+    /// A draft invoice response.
+    pub const SHOW_INVOICE: &str = include_str!("../../tests/resources/show_invoice_response.json");
+    /// A `PAYMENT.CAPTURE.COMPLETED` webhook event.
+    pub const WEBHOOK_PAYMENT_CAPTURE_COMPLETED: &str =
+        include_str!("../../tests/resources/webhook_payment_capture_completed.json");
+}
+
+/// Mounts the access token endpoint on the given server, answering with [fixtures::ACCESS_TOKEN].
+pub async fn mock_access_token(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(json_response(200, fixtures::ACCESS_TOKEN))
+        .mount(server)
+        .await;
+}
+
+/// Returns a client using the given server, with the access token endpoint already mounted.
+pub async fn mock_client(server: &MockServer) -> Client {
+    mock_access_token(server).await;
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(server.uri()),
+    )
+}
+
+/// Returns a mock of the given endpoint answering with the given status and json body, ready to be mounted.
+pub fn mock_endpoint<E: Endpoint>(endpoint: &E, status: u16, body: &str) -> Mock {
+    Mock::given(method(endpoint.method().as_str()))
+        .and(path(endpoint.relative_path()))
+        .respond_with(json_response(status, body))
+}
+
+/// Returns a response with the given status and json body.
+pub fn json_response(status: u16, body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_raw(body.to_string(), "application/json")
+}
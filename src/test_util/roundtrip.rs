@@ -0,0 +1,119 @@
+//! Checks the data models keep every field of a paypal response when deserialized and serialized again.
+//!
+//! A field missing in a model, misnamed or with the wrong type shows up as a mismatch between the response and the
+//! serialized model, or fails the deserialization.
+//!
+//! # Examples
+//!
+//! ```
+//! use paypal_rs::{data::orders::Order, test_util::{fixtures, roundtrip}};
+//!
+//! let order: Order = roundtrip::assert_roundtrip(fixtures::CAPTURE_ORDER);
+//! assert_eq!(order.id, "5O190127TN364715T");
+//! ```
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// A value of the response that was lost or changed by the roundtrip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// The JSON pointer to the value.
+    pub path: String,
+    /// The value in the response.
+    pub expected: Value,
+    /// The value in the serialized model, `None` if it is missing.
+    pub found: Option<Value>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.found {
+            Some(found) => write!(f, "{}: expected {}, found {}", self.path, self.expected, found),
+            None => write!(f, "{}: expected {}, found nothing", self.path, self.expected),
+        }
+    }
+}
+
+/// Deserializes the response into `T` and returns the values lost or changed when serializing it again.
+///
+/// Null values of the response may be omitted and the values the model adds are ignored. Date times are compared by
+/// the instant they represent, not their formatting.
+pub fn mismatches<T: DeserializeOwned + Serialize>(response: &str) -> Result<Vec<Mismatch>, serde_json::Error> {
+    let expected: Value = serde_json::from_str(response)?;
+    let model: T = serde_json::from_value(expected.clone())?;
+    let found = serde_json::to_value(&model)?;
+
+    let mut mismatches = Vec::new();
+    compare(String::new(), &expected, &found, &mut mismatches);
+    Ok(mismatches)
+}
+
+/// Deserializes the response into `T`, panicking if it fails or any value is lost or changed when serializing it
+/// again, see [mismatches].
+pub fn assert_roundtrip<T: DeserializeOwned + Serialize>(response: &str) -> T {
+    let mismatches = match mismatches::<T>(response) {
+        Ok(mismatches) => mismatches,
+        Err(e) => panic!("failed to roundtrip a {}: {}", std::any::type_name::<T>(), e),
+    };
+    if !mismatches.is_empty() {
+        let mismatches: Vec<String> = mismatches.iter().map(Mismatch::to_string).collect();
+        panic!(
+            "a {} does not roundtrip:\n{}",
+            std::any::type_name::<T>(),
+            mismatches.join("\n")
+        );
+    }
+    serde_json::from_str(response).expect("the response deserialized already")
+}
+
+fn compare(path: String, expected: &Value, found: &Value, mismatches: &mut Vec<Mismatch>) {
+    let mismatch = |mismatches: &mut Vec<Mismatch>| {
+        mismatches.push(Mismatch {
+            path: path.clone(),
+            expected: expected.clone(),
+            found: Some(found.clone()),
+        })
+    };
+
+    match (expected, found) {
+        (Value::Object(expected), Value::Object(found)) => {
+            for (key, expected) in expected {
+                let path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match found.get(key) {
+                    Some(found) => compare(path, expected, found, mismatches),
+                    None if expected.is_null() => {}
+                    None => mismatches.push(Mismatch {
+                        path,
+                        expected: expected.clone(),
+                        found: None,
+                    }),
+                }
+            }
+        }
+        (Value::Array(expected_items), Value::Array(found_items)) => {
+            if expected_items.len() != found_items.len() {
+                return mismatch(mismatches);
+            }
+            for (i, (expected, found)) in expected_items.iter().zip(found_items).enumerate() {
+                compare(format!("{}/{}", path, i), expected, found, mismatches);
+            }
+        }
+        (Value::String(expected), Value::String(found)) if expected != found => {
+            let expected = chrono::DateTime::parse_from_rfc3339(expected);
+            let found = chrono::DateTime::parse_from_rfc3339(found);
+            if !matches!((expected, found), (Ok(expected), Ok(found)) if expected == found) {
+                mismatch(mismatches);
+            }
+        }
+        (Value::Number(expected_number), Value::Number(found_number))
+            if expected_number.as_f64() != found_number.as_f64() =>
+        {
+            mismatch(mismatches)
+        }
+        (Value::Number(_), Value::Number(_)) => {}
+        (expected, found) if expected != found => mismatch(mismatches),
+        _ => {}
+    }
+}
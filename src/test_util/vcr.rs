@@ -0,0 +1,298 @@
+//! Records the api interactions to a file and replays them, so tests can run without sandbox credentials.
+//!
+//! Record the interactions once against the sandbox, then replay them deterministically:
+//!
+//! ```no_run
+//! use paypal_rs::{api::orders::ShowOrderDetails, test_util::vcr::Vcr, Client, PaypalEnv};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client_id = std::env::var("PAYPAL_CLIENTID").unwrap_or_default();
+//! let secret = std::env::var("PAYPAL_SECRET").unwrap_or_default();
+//!
+//! // Records when PAYPAL_VCR=record, replays otherwise.
+//! let vcr = Vcr::from_env("tests/cassettes/show_order.json")?.scrub(&secret);
+//! let client = Client::builder(client_id, secret, PaypalEnv::Sandbox)
+//!     .middleware(vcr)
+//!     .build()?;
+//!
+//! let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::{errors::ResponseError, Middleware, Next};
+
+/// The value replacing the scrubbed secrets.
+pub const SCRUBBED: &str = "[SCRUBBED]";
+
+/// The json fields scrubbed from the recorded bodies.
+const SECRET_FIELDS: &[&str] = &["access_token", "refresh_token", "id_token", "nonce"];
+
+/// The response headers that are not recorded.
+const SKIPPED_HEADERS: &[&str] = &["content-length", "date", "set-cookie"];
+
+/// Whether a [Vcr] records or replays the interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Sends the requests and records them with their responses.
+    Record,
+    /// Answers the requests with the recorded responses, without sending them.
+    Replay,
+}
+
+/// The recorded interactions, saved as json.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The interactions, in the order they were recorded.
+    pub interactions: Vec<Interaction>,
+}
+
+/// A request and its response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request.
+    pub request: RecordedRequest,
+    /// The response.
+    pub response: RecordedResponse,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// The http method.
+    pub method: String,
+    /// The path, with the query string if any.
+    pub path: String,
+    /// The scrubbed body.
+    pub body: Option<String>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The http status code.
+    pub status: u16,
+    /// The response headers.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The scrubbed body.
+    pub body: String,
+}
+
+/// A [Middleware] recording or replaying the api interactions, see the [module docs](self).
+///
+/// Access tokens are always scrubbed from the recordings, add other secrets with [Vcr::scrub].
+/// Request headers are not recorded.
+#[derive(Debug)]
+pub struct Vcr {
+    path: PathBuf,
+    mode: VcrMode,
+    secrets: Vec<String>,
+    state: Mutex<VcrState>,
+}
+
+#[derive(Debug, Default)]
+struct VcrState {
+    cassette: Cassette,
+    replayed: Vec<bool>,
+}
+
+impl Vcr {
+    /// Records the interactions to the given file, overwriting it.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: VcrMode::Record,
+            secrets: Vec::new(),
+            state: Mutex::new(VcrState::default()),
+        }
+    }
+
+    /// Replays the interactions recorded in the given file.
+    pub fn replay(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        let cassette: Cassette = serde_json::from_slice(&std::fs::read(&path)?)?;
+        Ok(Self {
+            path,
+            mode: VcrMode::Replay,
+            secrets: Vec::new(),
+            state: Mutex::new(VcrState {
+                replayed: vec![false; cassette.interactions.len()],
+                cassette,
+            }),
+        })
+    }
+
+    /// Records if the `PAYPAL_VCR` environment variable is `record`, replays otherwise.
+    pub fn from_env(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        match std::env::var("PAYPAL_VCR").as_deref() {
+            Ok("record") => Ok(Self::record(path)),
+            _ => Self::replay(path),
+        }
+    }
+
+    /// Scrubs the given secret from the recorded bodies, such as the client secret or customer data.
+    pub fn scrub(mut self, secret: impl ToString) -> Self {
+        let secret = secret.to_string();
+        if !secret.is_empty() {
+            self.secrets.push(secret);
+        }
+        self
+    }
+
+    /// Returns whether this recorder records or replays.
+    pub fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Returns the recorded interactions.
+    pub fn cassette(&self) -> Cassette {
+        self.state.lock().unwrap().cassette.clone()
+    }
+
+    fn scrub_body(&self, body: &[u8]) -> String {
+        let mut body = match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(mut value) => {
+                scrub_fields(&mut value);
+                value.to_string()
+            }
+            Err(_) => String::from_utf8_lossy(body).into_owned(),
+        };
+        for secret in &self.secrets {
+            body = body.replace(secret.as_str(), SCRUBBED);
+        }
+        body
+    }
+
+    async fn record_interaction(
+        &self,
+        request: reqwest::Request,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response, ResponseError> {
+        let recorded_request = RecordedRequest {
+            method: request.method().to_string(),
+            path: request_path(&request),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| self.scrub_body(body)),
+        };
+
+        let res = next.run(request).await?;
+        let status = res.status().as_u16();
+        let headers: Vec<(String, String)> = res
+            .headers()
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = res.bytes().await?;
+
+        let interaction = Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status,
+                headers: headers.clone(),
+                body: self.scrub_body(&body),
+            },
+        };
+
+        {
+            let mut state = self.state.lock().unwrap();
+            state.cassette.interactions.push(interaction);
+            let json = serde_json::to_vec_pretty(&state.cassette).expect("serialize the cassette");
+            if let Some(dir) = self.path.parent() {
+                std::fs::create_dir_all(dir).expect("create the cassette directory");
+            }
+            std::fs::write(&self.path, json).expect("write the cassette");
+        }
+
+        // The caller gets the real body, only the recording is scrubbed.
+        Ok(build_response(status, &headers, body.to_vec()))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn replay_interaction(&self, request: &reqwest::Request) -> Result<reqwest::Response, ResponseError> {
+        let method = request.method().to_string();
+        let path = request_path(request);
+
+        let mut state = self.state.lock().unwrap();
+        let VcrState { cassette, replayed } = &mut *state;
+        let found = cassette
+            .interactions
+            .iter()
+            .zip(replayed.iter_mut())
+            .find(|(interaction, replayed)| {
+                !**replayed && interaction.request.method == method && interaction.request.path == path
+            });
+
+        match found {
+            Some((interaction, replayed)) => {
+                *replayed = true;
+                let response = &interaction.response;
+                Ok(build_response(
+                    response.status,
+                    &response.headers,
+                    response.body.clone().into_bytes(),
+                ))
+            }
+            None => Err(ResponseError::Unexpected {
+                status: reqwest::StatusCode::NOT_IMPLEMENTED,
+                body: format!("no recorded interaction left for {} {}", method, path),
+            }),
+        }
+    }
+}
+
+impl Middleware for Vcr {
+    fn handle<'a>(
+        &'a self,
+        request: reqwest::Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<reqwest::Response, ResponseError>> {
+        Box::pin(async move {
+            match self.mode {
+                VcrMode::Record => self.record_interaction(request, next).await,
+                VcrMode::Replay => self.replay_interaction(&request),
+            }
+        })
+    }
+}
+
+fn request_path(request: &reqwest::Request) -> String {
+    let url = request.url();
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
+
+fn scrub_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_FIELDS.contains(&key.as_str()) {
+                    *value = serde_json::Value::String(SCRUBBED.to_string());
+                } else {
+                    scrub_fields(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(scrub_fields),
+        _ => {}
+    }
+}
+
+fn build_response(status: u16, headers: &[(String, String)], body: Vec<u8>) -> reqwest::Response {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    reqwest::Response::from(builder.body(body).expect("build the recorded response"))
+}
@@ -0,0 +1,229 @@
+//! Verifies the webhook events offline, with the certificate paypal signed them with.
+//!
+//! The certificate is downloaded from the url of the `PAYPAL-CERT-URL` header, which the sender chooses, so only
+//! https urls of paypal are allowed, and the certificates can be pinned.
+//!
+//! <https://developer.paypal.com/api/rest/webhooks/rest/#link-selfverificationmethod>
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use x509_cert::der::Encode;
+use x509_cert::Certificate;
+
+use crate::{
+    data::{ids::WebhookId, webhooks::WebhookTransmission},
+    errors::WebhookCertError,
+    Client, PaypalEnv,
+};
+
+/// The hosts the webhook certificates are downloaded from.
+pub const CERT_HOSTS: &[&str] = &[
+    "api.paypal.com",
+    "api-m.paypal.com",
+    "api.sandbox.paypal.com",
+    "api-m.sandbox.paypal.com",
+];
+
+/// The signature algorithm verified offline, other ones are verified by paypal.
+pub const AUTH_ALGO: &str = "SHA256withRSA";
+
+/// A certificate paypal signs the webhook events with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookCert {
+    public_key: Vec<u8>,
+    fingerprint: [u8; 32],
+    not_before: chrono::DateTime<chrono::Utc>,
+    not_after: chrono::DateTime<chrono::Utc>,
+}
+
+impl WebhookCert {
+    /// Parses the first certificate of a PEM chain, the one the events are signed with.
+    #[allow(clippy::result_large_err)]
+    pub fn from_pem(pem: &[u8]) -> Result<Self, WebhookCertError> {
+        let invalid = |e: x509_cert::der::Error| WebhookCertError::Invalid(e.to_string());
+        let cert = Certificate::load_pem_chain(pem)
+            .map_err(invalid)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| WebhookCertError::Invalid("no certificate found".to_string()))?;
+
+        let der = cert.to_der().map_err(invalid)?;
+        let digest = ring::digest::digest(&ring::digest::SHA256, &der);
+        let mut fingerprint = [0; 32];
+        fingerprint.copy_from_slice(digest.as_ref());
+
+        let validity = cert.tbs_certificate().validity();
+        let time = |time: x509_cert::time::Time| chrono::DateTime::<chrono::Utc>::from(time.to_system_time());
+        Ok(Self {
+            public_key: cert
+                .tbs_certificate()
+                .subject_public_key_info()
+                .subject_public_key
+                .raw_bytes()
+                .to_vec(),
+            fingerprint,
+            not_before: time(validity.not_before),
+            not_after: time(validity.not_after),
+        })
+    }
+
+    /// Returns the DER encoded public key of the certificate.
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Returns the SHA-256 fingerprint of the certificate, to [pin](CertCache::pin) it.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.fingerprint
+    }
+
+    /// Returns the date and time when the certificate expires.
+    pub fn not_after(&self) -> chrono::DateTime<chrono::Utc> {
+        self.not_after
+    }
+
+    /// Returns whether the certificate is valid at the given date and time.
+    pub fn is_valid_at(&self, time: chrono::DateTime<chrono::Utc>) -> bool {
+        self.not_before <= time && time <= self.not_after
+    }
+}
+
+/// Downloads the webhook certificates and keeps them for a while, so verifying an event doesn't download its
+/// certificate every time.
+///
+/// A certificate is kept until its time to live passes or it expires, whichever comes first. Only https urls of the
+/// [paypal hosts](CERT_HOSTS) are downloaded, or of the mock server when mocking.
+///
+/// # Examples
+///
+/// ```no_run
+/// use paypal_rs::{verification::CertCache, Client};
+/// use std::time::Duration;
+///
+/// # async fn get(client: Client, cert_url: &str, fingerprint: [u8; 32]) -> Result<(), paypal_rs::errors::WebhookCertError> {
+/// let certs = CertCache::new().ttl(Duration::from_secs(6 * 60 * 60)).pin(fingerprint);
+/// let cert = certs.get(&client, cert_url).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CertCache {
+    ttl: Duration,
+    pins: Vec<[u8; 32]>,
+    certs: RwLock<HashMap<String, (WebhookCert, Instant)>>,
+}
+
+impl CertCache {
+    /// An empty cache, keeping the certificates for a day, without pinning.
+    pub fn new() -> Self {
+        Self {
+            ttl: Duration::from_secs(24 * 60 * 60),
+            pins: Vec::new(),
+            certs: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long the certificates are kept.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Only accepts the certificates with the given SHA-256 fingerprint, or one of the other pinned ones.
+    pub fn pin(mut self, fingerprint: [u8; 32]) -> Self {
+        self.pins.push(fingerprint);
+        self
+    }
+
+    /// Returns the certificate at the given url, downloading it if it isn't cached or its time to live passed.
+    pub async fn get(&self, client: &Client, cert_url: &str) -> Result<WebhookCert, WebhookCertError> {
+        let now = chrono::Utc::now();
+        if let Some((cert, fetched_at)) = self.certs.read().unwrap().get(cert_url) {
+            if fetched_at.elapsed() < self.ttl && cert.is_valid_at(now) {
+                return Ok(cert.clone());
+            }
+        }
+
+        if !is_allowed_url(&client.env, cert_url) {
+            return Err(WebhookCertError::DisallowedUrl(cert_url.to_string()));
+        }
+        let pem = client
+            .client
+            .get(cert_url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let cert = WebhookCert::from_pem(&pem)?;
+        if !cert.is_valid_at(now) {
+            return Err(WebhookCertError::Expired);
+        }
+        if !self.pins.is_empty() && !self.pins.contains(&cert.fingerprint) {
+            return Err(WebhookCertError::NotPinned);
+        }
+
+        self.certs
+            .write()
+            .unwrap()
+            .insert(cert_url.to_string(), (cert.clone(), Instant::now()));
+        Ok(cert)
+    }
+
+    /// Forgets the cached certificates.
+    pub fn clear(&self) {
+        self.certs.write().unwrap().clear();
+    }
+}
+
+impl Default for CertCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verifies the signature of a webhook event with the certificate of its transmission.
+///
+/// The signature is over the transmission id, the transmission time, the webhook id and the CRC32 of the body.
+/// Returns `false` if the signature algorithm is not [AUTH_ALGO].
+pub fn verify_signature(
+    cert: &WebhookCert,
+    transmission: &WebhookTransmission,
+    webhook_id: &WebhookId,
+    body: &[u8],
+) -> bool {
+    use base64::Engine;
+
+    if transmission.auth_algo != AUTH_ALGO {
+        return false;
+    }
+    let Ok(signature) = base64::engine::general_purpose::STANDARD.decode(&transmission.transmission_sig) else {
+        return false;
+    };
+    let message = format!(
+        "{}|{}|{}|{}",
+        transmission.transmission_id,
+        transmission.transmission_time,
+        webhook_id,
+        crc32fast::hash(body)
+    );
+    ring::signature::UnparsedPublicKey::new(&ring::signature::RSA_PKCS1_2048_8192_SHA256, cert.public_key())
+        .verify(message.as_bytes(), &signature)
+        .is_ok()
+}
+
+/// Whether the certificate url is a https url of paypal, or of the mock server when mocking.
+fn is_allowed_url(env: &PaypalEnv, cert_url: &str) -> bool {
+    if let PaypalEnv::Mock(endpoint) = env {
+        return cert_url.starts_with(&format!("{}/", endpoint.trim_end_matches('/')));
+    }
+    let Ok(url) = reqwest::Url::parse(cert_url) else {
+        return false;
+    };
+    url.scheme() == "https"
+        && url.port().is_none()
+        && url.username().is_empty()
+        && url.host_str().is_some_and(|host| CERT_HOSTS.contains(&host))
+}
@@ -0,0 +1,99 @@
+use paypal_rs::test_util;
+use paypal_rs::{api::billing_agreements::*, data::billing_agreements::*};
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_billing_agreement() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+    let agreement: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_billing_agreement_response.json"))?;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing-agreements/agreement-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "description": "Billing Agreement",
+            "payer": { "payment_method": "PAYPAL" },
+            "plan": {
+                "type": "MERCHANT_INITIATED_BILLING",
+                "merchant_preferences": {
+                    "return_url": "https://example.com/return",
+                    "cancel_url": "https://example.com/cancel",
+                    "accepted_pymt_type": "INSTANT",
+                    "skip_shipping_address": true
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "links": [{
+                "href": "https://www.sandbox.paypal.com/agreements/approve?ba_token=BA-8A802366G0648845Y",
+                "rel": "approval_url",
+                "method": "POST"
+            }],
+            "token_id": "BA-8A802366G0648845Y"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/billing-agreements/agreements"))
+        .and(body_json(serde_json::json!({ "token_id": "BA-8A802366G0648845Y" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&agreement))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/billing-agreements/agreements/B-50V812176H0783741"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&agreement))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/billing-agreements/agreements/B-50V812176H0783741/cancel"))
+        .and(body_json(
+            serde_json::json!({ "description": "The customer closed their account." }),
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let token = AgreementTokenPayloadBuilder::default()
+        .description("Billing Agreement")
+        .plan(
+            AgreementPlanBuilder::default()
+                .merchant_preferences(
+                    MerchantPreferencesBuilder::default()
+                        .return_url("https://example.com/return")
+                        .cancel_url("https://example.com/cancel")
+                        .accepted_pymt_type(AcceptedPaymentType::Instant)
+                        .skip_shipping_address(true)
+                        .build()?,
+                )
+                .build()?,
+        )
+        .build()?;
+    let token = client.execute(&CreateAgreementToken::new(token)).await?;
+    assert_eq!(
+        token.approval_url(),
+        Some("https://www.sandbox.paypal.com/agreements/approve?ba_token=BA-8A802366G0648845Y")
+    );
+
+    let created = client.execute(&CreateBillingAgreement::new(&token.token_id)).await?;
+    assert_eq!(created.id.as_str(), "B-50V812176H0783741");
+    assert_eq!(created.state, Some(AgreementState::Active));
+    let payer_info = created.payer.unwrap().payer_info.unwrap();
+    assert_eq!(payer_info.payer_id.as_deref(), Some("7XM6M9EEKUVAE"));
+
+    let shown = client.execute(&ShowBillingAgreement::new(created.id.clone())).await?;
+    assert_eq!(
+        shown.plan.unwrap().plan_type,
+        AgreementPlanType::MerchantInitiatedBilling
+    );
+
+    let reason = CancelAgreementPayload {
+        description: Some("The customer closed their account.".to_string()),
+    };
+    client.execute(&CancelBillingAgreement::new(shown.id, reason)).await?;
+
+    Ok(())
+}
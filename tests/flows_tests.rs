@@ -0,0 +1,911 @@
+use paypal_rs::{
+    data::catalog_products::{ProductPayload, ProductType},
+    data::common::Money,
+    data::invoice::{CancelReason, InvoicePayload, RecordPaymentPayload, SendInvoicePayload},
+    data::orders::{Amount, CaptureStatus, Intent, Order, OrderPayloadBuilder, PurchaseUnit},
+    data::payouts::{BatchStatus, PayoutBatchPayload, PayoutItem, PayoutItemStatus, SenderBatchHeader},
+    data::subscriptions::PlanStatus,
+    data::webhooks::{EventType, ResourceKind, WebhookEvent},
+    errors::{BootstrapError, Issue, PayoutError, RefundOrderError, VerifyWebhookError},
+    flows::checkout::CaptureOutcome,
+    flows::invoicing::{self, InvoiceHandle},
+    flows::payouts,
+    flows::platform::{self, Platform},
+    flows::reconciliation,
+    flows::sandbox::{self, SandboxConfig},
+    flows::subscriptions::PlanDsl,
+    flows::webhooks::EventResource,
+};
+use paypal_rs::{test_util, AuthAssertion, Client, PaypalEnv};
+use wiremock::matchers::{bearer_token, body_json, body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_invoicing_bill() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let draft: serde_json::Value = serde_json::from_str(include_str!("resources/show_invoice_response.json"))?;
+    let mut sent = draft.clone();
+    sent["status"] = "SENT".into();
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&draft))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/send"))
+        .and(body_partial_json(serde_json::json!({ "send_to_invoicer": true })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&sent))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/remind"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/payments"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "payment_id": "EXTR-86F38350LX4353815" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let notification = SendInvoicePayload {
+        send_to_invoicer: Some(true),
+        ..Default::default()
+    };
+    let invoice = invoicing::bill(&client, InvoicePayload::default(), notification).await?;
+    assert_eq!(invoice.id(), "INV2-Z56S-5LLA-Q52L-CPZ5");
+    assert_eq!(invoice.invoice().status, paypal_rs::data::invoice::Status::Sent);
+    assert_eq!(
+        invoice.recipient_view_url(),
+        Some("https://www.paypal.com/invoice/p#Z56S5LLAQ52LCPZ5")
+    );
+
+    invoice.remind(SendInvoicePayload::default()).await?;
+    let payment = invoice.record_payment(RecordPaymentPayload::default()).await?;
+    assert_eq!(payment.payment_id, "EXTR-86F38350LX4353815");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invoicing_open_and_cancel() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(include_str!("resources/show_invoice_response.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/cancel"))
+        .and(body_partial_json(serde_json::json!({ "note": "Ordered by mistake" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let invoice = InvoiceHandle::open(client, "INV2-Z56S-5LLA-Q52L-CPZ5").await?;
+    let reason = CancelReason {
+        note: Some("Ordered by mistake".to_string()),
+        ..Default::default()
+    };
+    invoice.cancel(reason).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_plan_dsl_create() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/catalogs/products"))
+        .and(body_json(serde_json::json!({ "name": "Pro", "type": "SERVICE" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "PROD-XXCD1234QWER65782",
+            "name": "Pro",
+            "type": "SERVICE",
+            "create_time": "2024-03-01T14:30:00Z",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/plans"))
+        .and(body_json(serde_json::json!({
+            "product_id": "PROD-XXCD1234QWER65782",
+            "name": "Pro",
+            "billing_cycles": [
+                {
+                    "frequency": { "interval_unit": "DAY", "interval_count": 14 },
+                    "tenure_type": "TRIAL",
+                    "sequence": 1,
+                    "total_cycles": 1
+                },
+                {
+                    "frequency": { "interval_unit": "MONTH", "interval_count": 1 },
+                    "tenure_type": "REGULAR",
+                    "sequence": 2,
+                    "total_cycles": 0,
+                    "pricing_scheme": { "fixed_price": { "currency_code": "EUR", "value": "9.99" } }
+                }
+            ],
+            "payment_preferences": {
+                "auto_bill_outstanding": true,
+                "setup_fee": { "currency_code": "EUR", "value": "1.00" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "P-5ML4271244454362WXNWU5NQ",
+            "product_id": "PROD-XXCD1234QWER65782",
+            "name": "Pro",
+            "status": "ACTIVE",
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let (product, plan) = PlanDsl::monthly(Money::eur("9.99"))
+        .name("Pro")
+        .trial_days(14)
+        .setup_fee(Money::eur("1.00"))
+        .create(&client)
+        .await?;
+    assert_eq!(product.id, "PROD-XXCD1234QWER65782");
+    assert_eq!(plan.id, "P-5ML4271244454362WXNWU5NQ");
+    assert_eq!(plan.status, Some(PlanStatus::Active));
+
+    Ok(())
+}
+
+fn refund_response(id: &str, value: &str) -> serde_json::Value {
+    let money = serde_json::json!({ "currency_code": "USD", "value": value });
+    serde_json::json!({
+        "id": id,
+        "status": "COMPLETED",
+        "amount": money,
+        "seller_payable_breakdown": {
+            "gross_amount": money,
+            "paypal_fee": { "currency_code": "USD", "value": "0.00" },
+            "net_amount": money,
+            "total_refunded_amount": money
+        },
+        "links": []
+    })
+}
+
+async fn mount_captured_order(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "purchase_units": [{
+                "amount": { "currency_code": "USD", "value": "50.00" },
+                "payments": {
+                    "captures": [
+                        {
+                            "id": "CAPTURE-A",
+                            "status": "PARTIALLY_REFUNDED",
+                            "amount": { "currency_code": "USD", "value": "30.00" }
+                        },
+                        {
+                            "id": "CAPTURE-B",
+                            "status": "COMPLETED",
+                            "amount": { "currency_code": "USD", "value": "20.00" }
+                        }
+                    ],
+                    "refunds": [{
+                        "id": "REFUND-0",
+                        "status": "COMPLETED",
+                        "amount": { "currency_code": "USD", "value": "10.00" },
+                        "seller_payable_breakdown": {
+                            "gross_amount": { "currency_code": "USD", "value": "10.00" },
+                            "paypal_fee": { "currency_code": "USD", "value": "0.00" },
+                            "net_amount": { "currency_code": "USD", "value": "10.00" },
+                            "total_refunded_amount": { "currency_code": "USD", "value": "10.00" }
+                        },
+                        "links": [{
+                            "href": "https://api-m.paypal.com/v2/payments/captures/CAPTURE-A",
+                            "rel": "up",
+                            "method": "GET"
+                        }]
+                    }]
+                }
+            }],
+            "links": []
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_refund_order() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+    mount_captured_order(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-A/refund"))
+        .and(body_json(
+            serde_json::json!({ "amount": { "currency_code": "USD", "value": "20.00" } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(refund_response("REFUND-A", "20.00")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/captures/CAPTURE-B/refund"))
+        .and(body_json(
+            serde_json::json!({ "amount": { "currency_code": "USD", "value": "5.00" } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(refund_response("REFUND-B", "5.00")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let refunds = client
+        .refund_order("5O190127TN364715T", Some(Money::usd("25.00")))
+        .await?;
+    let ids: Vec<_> = refunds.iter().map(|refund| refund.id.as_str()).collect();
+    assert_eq!(ids, ["REFUND-A", "REFUND-B"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refund_order_exceeding_captures() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+    mount_captured_order(&mock_server).await;
+
+    let res = client
+        .refund_order("5O190127TN364715T", Some(Money::usd("45.00")))
+        .await;
+    assert!(matches!(
+        res,
+        Err(RefundOrderError::AmountExceedsRefundable { refundable, .. }) if refundable == Money::usd("40.00")
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_platform_seller_order() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let auth_assertion = AuthAssertion::for_payer_id("clientid", "C7CYMKZDG8D6E").encode();
+    let created: serde_json::Value = serde_json::from_str(include_str!("resources/create_order_response.json"))?;
+    let captured: serde_json::Value = serde_json::from_str(include_str!("resources/capture_order_response.json"))?;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(header("PayPal-Auth-Assertion", auth_assertion.as_str()))
+        .and(header("PayPal-Partner-Attribution-Id", "EXAMPLE_MP"))
+        .and(body_partial_json(serde_json::json!({
+            "purchase_units": [{
+                "payment_instruction": {
+                    "platform_fees": [{ "amount": { "currency_code": "USD", "value": "5.00" } }],
+                    "disbursement_mode": "DELAYED"
+                }
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&created))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/v2/checkout/orders/{}/capture",
+            created["id"].as_str().unwrap()
+        )))
+        .and(header("PayPal-Auth-Assertion", auth_assertion.as_str()))
+        .and(header("PayPal-Partner-Attribution-Id", "EXAMPLE_MP"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&captured))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let seller = Platform::new(client, "EXAMPLE_MP")
+        .delayed_disbursement(true)
+        .seller("C7CYMKZDG8D6E");
+
+    let mut purchase_unit = PurchaseUnit::new(Amount::usd("100.00"));
+    platform::add_platform_fee(&mut purchase_unit, Money::usd("5.00"));
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![purchase_unit])
+        .build()?;
+
+    let order = seller.create_order(order).await?;
+    seller.capture_order(order.id).await?;
+    Ok(())
+}
+
+fn captured_order(order_id: &str, capture: serde_json::Value) -> color_eyre::Result<Order> {
+    Ok(serde_json::from_value(serde_json::json!({
+        "id": order_id,
+        "status": "COMPLETED",
+        "purchase_units": [{
+            "amount": capture["amount"],
+            "payments": { "captures": [capture] }
+        }],
+        "links": []
+    }))?)
+}
+
+#[tokio::test]
+async fn test_reconcile() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let transaction = |id: &str, value: &str, invoice_id: &str| {
+        serde_json::json!({
+            "transaction_info": {
+                "transaction_id": id,
+                "transaction_event_code": "T0006",
+                "transaction_initiation_date": "2024-03-02T10:00:00+0000",
+                "transaction_amount": { "currency_code": "USD", "value": value },
+                "transaction_status": "S",
+                "invoice_id": invoice_id
+            }
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/v1/reporting/transactions"))
+        .and(query_param("start_date", "2024-03-01T00:00:00Z"))
+        .and(query_param("end_date", "2024-03-10T00:00:00Z"))
+        .and(query_param("fields", "transaction_info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "transaction_details": [
+                transaction("CAPTURE-A", "10.00", "INV-A"),
+                transaction("TRANSACTION-B", "25.00", "INV-B"),
+                transaction("REFUND-A", "-5.00", "INV-A"),
+                transaction("TRANSACTION-D", "7.00", "INV-D")
+            ],
+            "page": 1,
+            "total_items": 4,
+            "total_pages": 1,
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let orders = vec![
+        captured_order(
+            "ORDER-A",
+            serde_json::json!({
+                "id": "CAPTURE-A",
+                "status": "PARTIALLY_REFUNDED",
+                "amount": { "currency_code": "USD", "value": "10.00" }
+            }),
+        )?,
+        captured_order(
+            "ORDER-B",
+            serde_json::json!({
+                "id": "CAPTURE-B",
+                "status": "COMPLETED",
+                "amount": { "currency_code": "USD", "value": "20.00" },
+                "invoice_id": "INV-B"
+            }),
+        )?,
+        captured_order(
+            "ORDER-C",
+            serde_json::json!({
+                "id": "CAPTURE-C",
+                "status": "COMPLETED",
+                "amount": { "currency_code": "USD", "value": "15.00" }
+            }),
+        )?,
+    ];
+
+    let start_date = "2024-03-01T00:00:00Z".parse()?;
+    let end_date = "2024-03-10T00:00:00Z".parse()?;
+    let report = reconciliation::reconcile(&client, start_date, end_date, &orders).await?;
+
+    assert_eq!(report.matched.len(), 1);
+    assert_eq!(report.matched[0].payment.order_id, "ORDER-A");
+    assert_eq!(report.amount_mismatches.len(), 1);
+    assert_eq!(report.amount_mismatches[0].payment.capture_id, "CAPTURE-B");
+    assert_eq!(
+        report.amount_mismatches[0].transaction.transaction_id.as_deref(),
+        Some("TRANSACTION-B")
+    );
+    assert_eq!(report.missing.len(), 1);
+    assert_eq!(report.missing[0].order_id, "ORDER-C");
+    assert_eq!(report.unmatched_transactions.len(), 1);
+    assert_eq!(
+        report.unmatched_transactions[0].transaction_id.as_deref(),
+        Some("TRANSACTION-D")
+    );
+    assert!(!report.is_balanced());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reconcile_windows() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    // The transaction at the boundary of the two windows is returned by both.
+    for (start_date, end_date) in [
+        ("2024-03-01T00:00:00Z", "2024-04-01T00:00:00Z"),
+        ("2024-04-01T00:00:00Z", "2024-04-15T00:00:00Z"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/v1/reporting/transactions"))
+            .and(query_param("start_date", start_date))
+            .and(query_param("end_date", end_date))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "transaction_details": [{
+                    "transaction_info": {
+                        "transaction_id": "CAPTURE-A",
+                        "transaction_event_code": "T0006",
+                        "transaction_initiation_date": "2024-04-01T00:00:00+0000",
+                        "transaction_amount": { "currency_code": "USD", "value": "10.00" },
+                        "transaction_status": "S"
+                    }
+                }],
+                "page": 1,
+                "total_items": 1,
+                "total_pages": 1,
+                "links": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let orders = vec![captured_order(
+        "ORDER-A",
+        serde_json::json!({
+            "id": "CAPTURE-A",
+            "status": "COMPLETED",
+            "amount": { "currency_code": "USD", "value": "10.00" }
+        }),
+    )?];
+
+    let start_date = "2024-03-01T00:00:00Z".parse()?;
+    let end_date = "2024-04-15T00:00:00Z".parse()?;
+    let report = reconciliation::reconcile(&client, start_date, end_date, &orders).await?;
+
+    assert_eq!(report.matched.len(), 1);
+    assert!(report.unmatched_transactions.is_empty());
+    assert!(report.is_balanced());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sandbox_bootstrap() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/notifications/webhooks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "webhooks": [{
+                "id": "40Y916089Y8324740",
+                "url": "https://example.com/webhooks/orders",
+                "event_types": [{ "name": "CHECKOUT.ORDER.APPROVED" }],
+                "links": []
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/notifications/webhooks"))
+        .and(body_json(serde_json::json!({
+            "url": "https://example.com/webhooks/subscriptions",
+            "event_types": [{ "name": "BILLING.SUBSCRIPTION.CREATED" }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "0EH40505U7160970P",
+            "url": "https://example.com/webhooks/subscriptions",
+            "event_types": [{ "name": "BILLING.SUBSCRIPTION.CREATED" }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/catalogs/products"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "products": [{ "id": "PROD-XXCD1234QWER65782", "name": "Video streaming" }],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/catalogs/products"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/billing/plans"))
+        .and(query_param("product_id", "PROD-XXCD1234QWER65782"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "plans": [{ "id": "P-5ML4271244454362WXNWU5NQ", "product_id": "PROD-XXCD1234QWER65782", "name": "Basic" }],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/plans"))
+        .and(body_partial_json(
+            serde_json::json!({ "product_id": "PROD-XXCD1234QWER65782", "name": "Premium" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "P-2UF78835G6983425GLSM44MA",
+            "product_id": "PROD-XXCD1234QWER65782",
+            "name": "Premium",
+            "status": "ACTIVE"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config = SandboxConfig::default()
+        .webhook(
+            "https://example.com/webhooks/orders",
+            [EventType::CheckoutOrderApproved],
+        )
+        .webhook(
+            "https://example.com/webhooks/subscriptions",
+            [EventType::BillingSubscriptionCreated],
+        )
+        .product(
+            ProductPayload::new("Video streaming", ProductType::Digital),
+            [
+                PlanDsl::monthly(Money::usd("9.99")).name("Basic"),
+                PlanDsl::monthly(Money::usd("19.99")).name("Premium"),
+            ],
+        );
+    let resources = sandbox::bootstrap(&client, &config).await?;
+
+    let webhook_ids: Vec<_> = resources.webhooks.iter().map(|webhook| webhook.id.as_str()).collect();
+    assert_eq!(webhook_ids, ["40Y916089Y8324740", "0EH40505U7160970P"]);
+    assert_eq!(resources.products[0].product.id, "PROD-XXCD1234QWER65782");
+    let plan_ids: Vec<_> = resources.products[0]
+        .plans
+        .iter()
+        .map(|plan| plan.id.as_str())
+        .collect();
+    assert_eq!(plan_ids, ["P-5ML4271244454362WXNWU5NQ", "P-2UF78835G6983425GLSM44MA"]);
+
+    let live = Client::builder("clientid", "secret", PaypalEnv::Live)
+        .allow_live()
+        .build()?;
+    assert!(matches!(
+        sandbox::bootstrap(&live, &config).await,
+        Err(BootstrapError::NotSandbox)
+    ));
+
+    Ok(())
+}
+
+fn unprocessable_entity(issue: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": "UNPROCESSABLE_ENTITY",
+        "details": [{ "issue": issue }],
+        "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+        "debug_id": "c9a75b43fc807",
+        "links": []
+    })
+}
+
+#[tokio::test]
+async fn test_capture_order_or_restart() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(unprocessable_entity("INSTRUMENT_DECLINED")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "APPROVED",
+            "links": [{
+                "href": "https://www.paypal.com/checkoutnow?token=5O190127TN364715T",
+                "rel": "approve",
+                "method": "GET"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/8F783829MH2224513/capture"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(unprocessable_entity("ORDER_NOT_APPROVED")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client.capture_order_or_restart("5O190127TN364715T").await?;
+    assert_eq!(
+        outcome,
+        CaptureOutcome::Restart("https://www.paypal.com/checkoutnow?token=5O190127TN364715T".to_string())
+    );
+
+    let error = client.capture_order_or_restart("8F783829MH2224513").await.unwrap_err();
+    assert_eq!(error.issue_codes(), [Issue::OrderNotApproved]);
+
+    Ok(())
+}
+
+fn payout_batch(sender_item_ids: &[&str]) -> PayoutBatchPayload {
+    PayoutBatchPayload {
+        sender_batch_header: SenderBatchHeader {
+            sender_batch_id: Some("payroll-2024-03".to_string()),
+            ..Default::default()
+        },
+        items: sender_item_ids
+            .iter()
+            .map(|sender_item_id| PayoutItem {
+                amount: Money::usd("100.00").into(),
+                receiver: format!("{}@example.com", sender_item_id),
+                sender_item_id: Some(sender_item_id.to_string()),
+                ..Default::default()
+            })
+            .collect(),
+    }
+}
+
+#[tokio::test]
+async fn test_payouts_create_once() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let created = serde_json::json!({
+        "batch_header": {
+            "payout_batch_id": "5UXD2E8A7EBQJ",
+            "batch_status": "PENDING",
+            "sender_batch_header": { "sender_batch_id": "payroll-2024-03" }
+        },
+        "links": []
+    });
+    Mock::given(method("POST"))
+        .and(path("/v1/payments/payouts"))
+        .and(header("PayPal-Request-Id", "payroll-2024-03"))
+        .and(body_partial_json(serde_json::json!({
+            "items": [{ "amount": { "currency": "USD", "value": "100.00" }, "sender_item_id": "employee-1" }, {}]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&created))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let mut processed = created.clone();
+    processed["batch_header"]["batch_status"] = "SUCCESS".into();
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/payouts/5UXD2E8A7EBQJ"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&processed))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let batch = payouts::create_once(&client, payout_batch(&["employee-1", "employee-2"]), None).await?;
+    assert_eq!(batch.batch_header.payout_batch_id, "5UXD2E8A7EBQJ");
+
+    let retried = payouts::create_once(
+        &client,
+        payout_batch(&["employee-1", "employee-2"]),
+        Some("5UXD2E8A7EBQJ"),
+    )
+    .await?;
+    assert_eq!(retried.batch_header.batch_status, BatchStatus::Success);
+
+    let mut other = payout_batch(&["employee-1"]);
+    other.sender_batch_header.sender_batch_id = Some("payroll-2024-04".to_string());
+    assert!(matches!(
+        payouts::create_once(&client, other, Some("5UXD2E8A7EBQJ")).await,
+        Err(PayoutError::SenderBatchIdMismatch { found: Some(found), .. }) if found == "payroll-2024-03"
+    ));
+
+    assert!(matches!(
+        payouts::create_once(&client, payout_batch(&["employee-1", "employee-1"]), None).await,
+        Err(PayoutError::DuplicateSenderItemId(id)) if id == "employee-1"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_webhook_event_correlation() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let captured: WebhookEvent = serde_json::from_value(serde_json::json!({
+        "id": "WH-58D329510W468432D-8HN650336L201105X",
+        "event_type": "PAYMENT.CAPTURE.COMPLETED",
+        "resource_type": "capture",
+        "resource": {
+            "id": "42311647XV020574X",
+            "status": "COMPLETED",
+            "supplementary_data": {"related_ids": {"order_id": "5O190127TN364715T"}}
+        }
+    }))?;
+    let refunded: WebhookEvent = serde_json::from_value(serde_json::json!({
+        "id": "WH-1GE84257G0350133W-6RW800890C634293G",
+        "event_type": "PAYMENT.CAPTURE.REFUNDED",
+        "resource": {
+            "id": "1JU08902781691411",
+            "status": "COMPLETED",
+            "links": [{
+                "href": "https://api.paypal.com/v2/payments/captures/42311647XV020574X",
+                "rel": "up",
+                "method": "GET"
+            }]
+        }
+    }))?;
+
+    let ids = captured.related_ids();
+    assert_eq!(ids.capture_id.unwrap().as_str(), "42311647XV020574X");
+    assert_eq!(ids.order_id.unwrap().as_str(), "5O190127TN364715T");
+    let ids = refunded.related_ids();
+    assert_eq!(ids.refund_id.unwrap().as_str(), "1JU08902781691411");
+    assert_eq!(ids.capture_id.unwrap().as_str(), "42311647XV020574X");
+
+    Mock::given(method("GET"))
+        .and(path("/v2/payments/captures/42311647XV020574X"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "42311647XV020574X",
+            "status": "REFUNDED"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let Some(EventResource::Capture(capture)) = client.fetch_event_resource(&captured).await? else {
+        panic!("the resource of the event is a capture");
+    };
+    assert_eq!(capture.status, CaptureStatus::Refunded);
+    let order = client.fetch_event_order(&captured).await?.unwrap();
+    assert_eq!(order.id.as_str(), "5O190127TN364715T");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_webhook_with_paypal() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+    let body = include_bytes!("resources/webhook_payment_capture_completed.json");
+
+    Mock::given(method("POST"))
+        .and(path("/v1/notifications/verify-webhook-signature"))
+        .and(body_partial_json(serde_json::json!({
+            "auth_algo": "SHA512withRSA",
+            "transmission_id": "69cd13f0-d67a-11e5-baa3-778b53f4ae55",
+            "webhook_id": "1JE4291016473214C",
+            "webhook_event": { "event_type": "PAYMENT.CAPTURE.COMPLETED" },
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "verification_status": "SUCCESS" })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/notifications/verify-webhook-signature"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "verification_status": "FAILURE" })))
+        .mount(&mock_server)
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("PAYPAL-AUTH-ALGO", "SHA512withRSA".parse()?);
+    headers.insert(
+        "PAYPAL-CERT-URL",
+        "https://api.paypal.com/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77".parse()?,
+    );
+    headers.insert(
+        "PAYPAL-TRANSMISSION-ID",
+        "69cd13f0-d67a-11e5-baa3-778b53f4ae55".parse()?,
+    );
+    headers.insert("PAYPAL-TRANSMISSION-SIG", "c2lnbmF0dXJl".parse()?);
+    headers.insert("PAYPAL-TRANSMISSION-TIME", "2016-02-18T20:01:35Z".parse()?);
+
+    let event = client.verify_webhook(&headers, body, "1JE4291016473214C").await?;
+    assert_eq!(event.event_type, EventType::PaymentCaptureCompleted);
+    assert!(matches!(
+        client.verify_webhook(&headers, body, "1JE4291016473214C").await,
+        Err(VerifyWebhookError::InvalidSignature)
+    ));
+
+    headers.remove("PAYPAL-TRANSMISSION-SIG");
+    assert!(matches!(
+        client.verify_webhook(&headers, body, "1JE4291016473214C").await,
+        Err(VerifyWebhookError::MissingHeader("PAYPAL-TRANSMISSION-SIG"))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_payout_webhook_events() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let unclaimed: WebhookEvent = serde_json::from_str(include_str!("resources/webhook_payouts_item_unclaimed.json"))?;
+    assert_eq!(unclaimed.resource_kind(), Some(ResourceKind::PayoutItem));
+    assert!(unclaimed.payout_batch()?.is_none());
+    let item = unclaimed.payout_item()?.unwrap();
+    assert_eq!(item.transaction_status, Some(PayoutItemStatus::Unclaimed));
+    assert_eq!(item.payout_item.sender_item_id.as_deref(), Some("employee-42"));
+    assert_eq!(item.sender_batch_id.as_deref(), Some("payroll-2024-03"));
+    let ids = unclaimed.related_ids();
+    assert_eq!(ids.payout_item_id.as_deref(), Some("8AELMXH8UB2P8"));
+    assert_eq!(ids.payout_batch_id.as_deref(), Some("RGXWGMCKDE5NU"));
+
+    let processed: WebhookEvent = serde_json::from_value(serde_json::json!({
+        "id": "WH-0JY62421XW6164014-5N4306582H5357429",
+        "event_type": "PAYMENT.PAYOUTSBATCH.SUCCESS",
+        "resource": {
+            "batch_header": {
+                "payout_batch_id": "RGXWGMCKDE5NU",
+                "batch_status": "SUCCESS",
+                "sender_batch_header": { "sender_batch_id": "payroll-2024-03" },
+                "amount": { "currency": "USD", "value": "100.00" },
+                "payments": 1
+            },
+            "links": []
+        }
+    }))?;
+    let batch = processed.payout_batch()?.unwrap();
+    assert_eq!(batch.batch_header.batch_status, BatchStatus::Success);
+    assert_eq!(batch.batch_header.payments, Some(1));
+    assert_eq!(
+        processed.related_ids().payout_batch_id.as_deref(),
+        Some("RGXWGMCKDE5NU")
+    );
+
+    // The payout may have been claimed since.
+    let mut claimed = serde_json::to_value(&item)?;
+    claimed["transaction_status"] = "SUCCESS".into();
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/payouts-item/8AELMXH8UB2P8"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&claimed))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    match client.fetch_event_resource(&unclaimed).await? {
+        Some(EventResource::PayoutItem(item)) => {
+            assert_eq!(item.transaction_status, Some(PayoutItemStatus::Success))
+        }
+        resource => panic!("unexpected resource {:?}", resource),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,651 @@
+use futures_util::TryStreamExt;
+use paypal_rs::test_util;
+use paypal_rs::{
+    api::invoice::*,
+    data::common::{Currency, Money},
+    data::invoice::*,
+    data::invoice_template::*,
+    errors::ErrorName,
+};
+use wiremock::matchers::{
+    bearer_token, body_json, body_partial_json, body_string_contains, header_regex, method, path, query_param,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_send_invoice_reminder() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/remind"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "subject": "Reminder: Payment due for the invoice #ABC-123",
+            "note": "Please pay before the due date to avoid incurring late payment charges.",
+            "send_to_invoicer": true
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let payload = SendInvoicePayloadBuilder::default()
+        .subject(Some("Reminder: Payment due for the invoice #ABC-123".to_string()))
+        .note(Some(
+            "Please pay before the due date to avoid incurring late payment charges.".to_string(),
+        ))
+        .send_to_invoicer(Some(true))
+        .additional_recipients(None)
+        .send_to_recipient(None)
+        .build()?;
+
+    client
+        .execute(&SendInvoiceReminder::new("INV2-Z56S-5LLA-Q52L-CPZ5", payload))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_templates() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/list_templates_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/templates"))
+        .and(query_param("fields", "none"))
+        .and(query_param("page_size", "20"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let query = ListTemplatesQueryBuilder::default()
+        .fields("none")
+        .page_size(20)
+        .build()?;
+    let list = client.execute(&ListTemplates::new(query)).await?;
+
+    assert_eq!(list.templates.len(), 2);
+    assert_eq!(list.templates[1].default_template, Some(true));
+    assert_eq!(list.templates[1].unit_of_measure, Some(UnitOfMeasure::Quantity));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_template() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_template_response.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/templates"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "Hours Template",
+            "default_template": true,
+            "template_info": { "detail": { "currency_code": "USD" } },
+            "settings": {
+                "template_item_settings": [{ "field_name": "items.date", "display_preference": { "hidden": true } }]
+            },
+            "unit_of_measure": "HOURS"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let template = TemplateBuilder::default()
+        .name("Hours Template")
+        .default_template(true)
+        .template_info(
+            TemplateInfoBuilder::default()
+                .detail(InvoiceDetailBuilder::default().currency_code(Currency::USD).build()?)
+                .build()?,
+        )
+        .settings(
+            TemplateSettingsBuilder::default()
+                .template_item_settings(vec![TemplateItemSetting::new("items.date", true)])
+                .build()?,
+        )
+        .unit_of_measure(UnitOfMeasure::Hours)
+        .build()?;
+
+    let created = client.execute(&CreateTemplate::new(template)).await?;
+
+    assert_eq!(created.id.as_deref(), Some("TEMP-4NW98229SC0703920"));
+    assert_eq!(created.template_info.unwrap().items.unwrap().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_template() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_template_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/templates/TEMP-4NW98229SC0703920"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let template = client.execute(&ShowTemplate::new("TEMP-4NW98229SC0703920")).await?;
+
+    let configuration = template.configuration();
+    assert_eq!(configuration.template_id.as_deref(), Some("TEMP-4NW98229SC0703920"));
+    assert_eq!(configuration.allow_tip, Some(true));
+
+    let info = template.template_info.unwrap();
+    assert_eq!(
+        info.detail.unwrap().payment_term.unwrap().term_type,
+        PaymentTermType::Net10
+    );
+    assert_eq!(template.settings.unwrap().template_subtotal_settings.unwrap().len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_template() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let mut response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_template_response.json")).unwrap();
+    response_body["default_template"] = false.into();
+
+    Mock::given(method("PUT"))
+        .and(path("/v2/invoicing/templates/TEMP-4NW98229SC0703920"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "Hours Template",
+            "default_template": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let mut template: Template = serde_json::from_str(include_str!("resources/show_template_response.json"))?;
+    template.default_template = Some(false);
+
+    let updated = client
+        .execute(&UpdateTemplate::new("TEMP-4NW98229SC0703920", template))
+        .await?;
+
+    assert_eq!(updated.default_template, Some(false));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_template() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/invoicing/templates/TEMP-4NW98229SC0703920"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client.execute(&DeleteTemplate::new("TEMP-4NW98229SC0703920")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_search_invoices() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/search-invoices"))
+        .and(query_param("page_size", "50"))
+        .and(query_param("total_required", "true"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "recipient_email": "bill-me@example.com",
+            "status": ["SENT", "UNPAID"],
+            "total_amount_range": {
+                "lower_amount": { "currency_code": "USD", "value": "20.00" },
+                "upper_amount": { "currency_code": "USD", "value": "50.00" }
+            },
+            "invoice_date_range": { "start": "2018-06-01", "end": "2018-06-21" },
+            "archived": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_items": 0,
+            "total_pages": 0,
+            "items": [],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let payload = SearchInvoicesPayloadBuilder::default()
+        .recipient_email("bill-me@example.com")
+        .status(vec![Status::Sent, Status::Unpaid])
+        .total_amount_range(AmountRange {
+            lower_amount: Money::usd("20.00"),
+            upper_amount: Money::usd("50.00"),
+        })
+        .invoice_date_range(DateRange {
+            start: chrono::NaiveDate::from_ymd_opt(2018, 6, 1).unwrap(),
+            end: chrono::NaiveDate::from_ymd_opt(2018, 6, 21).unwrap(),
+        })
+        .archived(false)
+        .build()?;
+    let query = SearchInvoicesQueryBuilder::default()
+        .page_size(50)
+        .total_required(true)
+        .build()?;
+
+    let list = client.execute(&SearchInvoices::new(payload, query)).await?;
+    assert_eq!(list.total_items, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_refund() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/refunds"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "method": "CASH",
+            "amount": { "currency_code": "USD", "value": "5.00" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "refund_id": "EXTR-2LG703375E477444T"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let refund = RefundDetailBuilder::default()
+        .method(PaymentMethod::Cash)
+        .amount(Money::usd("5.00"))
+        .build()?;
+
+    let recorded = client
+        .execute(&RecordRefund::new("INV2-Z56S-5LLA-Q52L-CPZ5", refund))
+        .await?;
+    assert_eq!(recorded.refund_id, "EXTR-2LG703375E477444T");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_external_payment() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/payments/EXTR-86F38350LX4353815",
+        ))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .execute(&DeleteExternalPayment::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5",
+            "EXTR-86F38350LX4353815",
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_external_refund() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/refunds/EXTR-2LG703375E477444T",
+        ))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .execute(&DeleteExternalRefund::new(
+            "INV2-Z56S-5LLA-Q52L-CPZ5",
+            "EXTR-2LG703375E477444T",
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_qr_code() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    // A multipart body with the base64 encoded PNG signature, as returned by paypal.
+    let response_body = "--fc2d1ab0-6d8d-4fd5-8c6c-0b4c1f4a8d7c\r\n\
+        Content-Type: text/plain; charset=UTF-8\r\n\r\n\
+        iVBORw0KGgo=\r\n\
+        --fc2d1ab0-6d8d-4fd5-8c6c-0b4c1f4a8d7c--\r\n";
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/generate-qr-code"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(
+            serde_json::json!({ "width": 400, "height": 400, "action": "pay" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(response_body, "multipart/related"))
+        .mount(&mock_server)
+        .await;
+
+    let params = QRCodeParams {
+        width: 400,
+        height: 400,
+        action: Some(QR_ACTION_PAY.to_string()),
+    };
+    let qr_code = client
+        .execute(&GenerateQrCode::new("INV2-Z56S-5LLA-Q52L-CPZ5", params))
+        .await?;
+
+    assert_eq!(qr_code.image, b"\x89PNG\r\n\x1a\n");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_invoice_attachment() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/files"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(header_regex("content-type", "^multipart/form-data; boundary="))
+        .and(body_string_contains("filename=\"timesheet.pdf\""))
+        .and(body_string_contains("Content-Type: application/pdf"))
+        .and(body_string_contains("%PDF-1.4"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "FILE-1D7F0B6C2E3A4B5C",
+            "reference_url": "https://example.com/invoicing/files/FILE-1D7F0B6C2E3A4B5C",
+            "content_type": "application/pdf",
+            "create_time": "2024-01-12T10:20:30Z",
+            "size": "8"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let file = client
+        .execute(&UploadInvoiceAttachment::new(
+            "timesheet.pdf",
+            "application/pdf",
+            &b"%PDF-1.4"[..],
+        ))
+        .await?;
+
+    let mut detail = InvoiceDetailBuilder::default().currency_code(Currency::USD).build()?;
+    detail.add_attachment(file);
+    assert_eq!(detail.attachments.unwrap()[0].id, "FILE-1D7F0B6C2E3A4B5C");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_payment() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/payments"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!({
+            "method": "CHECK",
+            "note": "Paid in full",
+            "amount": { "currency_code": "USD", "value": "10.00" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "payment_id": "EXTR-86F38350LX4353815"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let payment = RecordPaymentPayloadBuilder::default()
+        .method(PaymentMethod::Check)
+        .note("Paid in full")
+        .amount(Money::usd("10.00"))
+        .build()?;
+
+    let recorded = client
+        .execute(&RecordPayment::new("INV2-Z56S-5LLA-Q52L-CPZ5", payment))
+        .await?;
+    assert_eq!(recorded.payment_id, "EXTR-86F38350LX4353815");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_invoices() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(query_param("page", "2"))
+        .and(query_param("page_size", "10"))
+        .and(query_param("total_required", "true"))
+        .and(query_param("fields", "none"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_items": 12,
+            "total_pages": 2,
+            "items": [],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let query = ListInvoicesQueryBuilder::default()
+        .page(2)
+        .page_size(10)
+        .total_required(true)
+        .fields("none")
+        .build()?;
+
+    let list = client.execute(&ListInvoices::new(query)).await?;
+    assert_eq!(list.total_items, 12);
+    assert_eq!(list.total_pages, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_invoices_paginated() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    for page in 1..=3 {
+        Mock::given(method("GET"))
+            .and(path("/v2/invoicing/invoices"))
+            .and(query_param("page", page.to_string()))
+            .and(bearer_token("TESTBEARERTOKEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_items": 5,
+                "total_pages": 3,
+                "items": [],
+                "links": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let query = ListInvoicesQueryBuilder::default()
+        .page(1)
+        .page_size(2)
+        .total_required(true)
+        .build()?;
+    let pages: Vec<_> = client.execute_paginated(ListInvoices::new(query)).try_collect().await?;
+
+    assert_eq!(pages.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_invoices_items() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let invoice: serde_json::Value = serde_json::from_str(include_str!("resources/show_invoice_response.json"))?;
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_items": 2,
+            "total_pages": 1,
+            "items": [invoice, invoice],
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "name": "NOT_AUTHORIZED",
+            "message": "Authorization failed due to insufficient permissions.",
+            "debug_id": "b1d1f06c7246c",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let query = ListInvoicesQueryBuilder::default().page(1).build()?;
+    let invoices: Vec<_> = client.execute_items(ListInvoices::new(query)).try_collect().await?;
+    assert_eq!(invoices.len(), 2);
+    assert_eq!(invoices[0].id.as_str(), invoice["id"].as_str().unwrap());
+
+    let query = ListInvoicesQueryBuilder::default().page(2).build()?;
+    let error = client
+        .execute_items(ListInvoices::new(query))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert_eq!(error.paypal_error().unwrap().name, ErrorName::NotAuthorized);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_all() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-0001/send"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-0002/send"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "name": "RESOURCE_NOT_FOUND",
+            "message": "The specified resource does not exist.",
+            "debug_id": "b1d1f06c7246c"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-0003/send"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let endpoints: Vec<_> = ["INV2-0001", "INV2-0002", "INV2-0003"]
+        .iter()
+        .map(|id| SendInvoice::new(*id, SendInvoicePayload::default()))
+        .collect();
+
+    let results = client.execute_all(&endpoints, 2).await;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_invoice_accepted() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    // Sending an invoice answers with links, which are ignored by the unit response.
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-0001/send"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "rel": "payer-view",
+            "href": "https://www.paypal.com/invoice/p/#INV2-0001",
+            "method": "GET"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/invoicing/invoices/INV2-0002/send"))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .insert_header("Location", "https://api-m.paypal.com/v2/invoicing/invoices/INV2-0002"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    client
+        .execute(&SendInvoice::new("INV2-0001", SendInvoicePayload::default()))
+        .await?;
+
+    let res = client
+        .execute_raw(
+            &SendInvoice::new("INV2-0002", SendInvoicePayload::default()),
+            Default::default(),
+        )
+        .await?;
+    assert!(res.is_accepted());
+    assert_eq!(
+        res.location(),
+        Some("https://api-m.paypal.com/v2/invoicing/invoices/INV2-0002")
+    );
+
+    Ok(())
+}
@@ -2,8 +2,13 @@ use paypal_rs::{
     api::orders::*,
     data::{common::AddressBuilder, orders::*},
 };
-use paypal_rs::{Client, PaypalEnv};
-use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
+use paypal_rs::{
+    errors::{ErrorName, ResponseError},
+    Client, HeaderParams, MockResponse, PaypalEnv, Prefer,
+};
+use wiremock::matchers::{
+    basic_auth, bearer_token, body_partial_json, body_string, header, header_regex, method, path,
+};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn create_client(url: &str) -> Client {
@@ -42,7 +47,7 @@ async fn test_create_order() -> color_eyre::Result<()> {
         .mount(&mock_server)
         .await;
 
-    let mut client = create_client(&mock_server.uri());
+    let client = create_client(&mock_server.uri());
 
     client.get_access_token().await?;
 
@@ -93,6 +98,362 @@ async fn test_create_order() -> color_eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_create_order_with_vault_id() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body = serde_json::json!({
+        "id": "5O190127TN364715T",
+        "status": "COMPLETED",
+        "links": []
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "payment_source": { "paypal": { "vault_id": "8kk8451t" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+        .payment_source(OrderPaymentSource::vaulted_paypal("8kk8451t"))
+        .build()?;
+
+    client.execute(&CreateOrder::new(order)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pay_with_billing_agreement() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body = serde_json::json!({
+        "id": "5O190127TN364715T",
+        "status": "COMPLETED",
+        "links": []
+    });
+    let token = serde_json::json!({
+        "payment_source": { "token": { "id": "B-50V812176H0783741", "type": "BILLING_AGREEMENT" } }
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(body_partial_json(&token))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(body_partial_json(&token))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+        .payment_source(OrderPaymentSource::billing_agreement("B-50V812176H0783741"))
+        .build()?;
+    client.execute(&CreateOrder::new(order)).await?;
+
+    let capture = CaptureOrder::with_billing_agreement("5O190127TN364715T", "B-50V812176H0783741");
+    client.execute(&capture).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_order_unprocessable() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body = serde_json::json!({
+        "name": "UNPROCESSABLE_ENTITY",
+        "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+        "debug_id": "90957fca61718",
+        "details": [{
+            "field": "/purchase_units/@reference_id=='default'/amount/currency_code",
+            "value": "XYZ",
+            "location": "body",
+            "issue": "CURRENCY_NOT_SUPPORTED",
+            "description": "Currency code is not supported."
+        }],
+        "links": []
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+        .build()?;
+
+    match client.execute(&CreateOrder::new(order)).await {
+        Err(ResponseError::ApiError(error)) => {
+            assert_eq!(error.name, ErrorName::UnprocessableEntity);
+            assert_eq!(error.status, Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY));
+            assert!(error.has_issue("CURRENCY_NOT_SUPPORTED"));
+            assert_eq!(error.details[0].location.as_deref(), Some("body"));
+        }
+        res => panic!("expected an api error, got {:?}", res),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_order_prefer_minimal() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header("Prefer", "return=minimal"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let order = client
+        .execute_ext(
+            &CaptureOrder::new("5O190127TN364715T"),
+            HeaderParams {
+                prefer: Some(Prefer::Minimal),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(order.status, OrderStatus::Completed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_order_raw() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("Paypal-Debug-Id", "f1b4ac8b2d6a3")
+                .set_body_json(serde_json::json!({
+                    "id": "5O190127TN364715T",
+                    "status": "COMPLETED",
+                    "links": []
+                })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let res = client
+        .execute_raw(&CaptureOrder::new("5O190127TN364715T"), HeaderParams::default())
+        .await?;
+    assert_eq!(res.status, reqwest::StatusCode::CREATED);
+    assert_eq!(res.debug_id.as_deref(), Some("f1b4ac8b2d6a3"));
+    assert!(res.headers.contains_key("content-type"));
+    assert_eq!(res.body.status, OrderStatus::Completed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_order_mock_response() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body = serde_json::json!({
+        "name": "UNPROCESSABLE_ENTITY",
+        "details": [{
+            "issue": "INSTRUMENT_DECLINED",
+            "description": "The instrument presented  was either declined by the processor or bank, or it can't be used for this payment."
+        }],
+        "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+        "debug_id": "c9a75b43fc807",
+        "links": []
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(header(
+            "PayPal-Mock-Response",
+            r#"{"mock_application_codes":"INSTRUMENT_DECLINED"}"#,
+        ))
+        .respond_with(ResponseTemplate::new(422).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let res = client
+        .execute_ext(
+            &CaptureOrder::new("5O190127TN364715T"),
+            HeaderParams {
+                mock_response: Some(MockResponse::new(MockResponse::INSTRUMENT_DECLINED)),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    match res {
+        Err(ResponseError::ApiError(error)) => assert!(error.has_issue(MockResponse::INSTRUMENT_DECLINED)),
+        res => panic!("expected an api error, got {:?}", res),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_order_compressed() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(header_regex("Accept-Encoding", "gzip"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "CREATED",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_order_unexpected_body() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(
+            ResponseTemplate::new(503).set_body_raw("<html><body>Down for maintenance</body></html>", "text/html"),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    match client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await {
+        Err(ResponseError::Unexpected { status, body }) => {
+            assert_eq!(status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(body, "<html><body>Down for maintenance</body></html>");
+        }
+        res => panic!("expected an unexpected response error, got {:?}", res),
+    }
+
+    Ok(())
+}
+
 /*
 
 #[tokio::test]
@@ -100,7 +461,7 @@ async fn test_order2() -> anyhow::Result<()> {
     let mock_server = MockServer::start().await;
 
 
-    let mut client = create_client(&mock_server.uri());
+    let client = create_client(&mock_server.uri());
     client.get_access_token().await?;
 
 
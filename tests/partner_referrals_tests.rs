@@ -0,0 +1,157 @@
+use paypal_rs::test_util;
+use paypal_rs::{api::partner_referrals::*, data::partner_referrals::*};
+use wiremock::matchers::{bearer_token, body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_partner_referral() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_partner_referral_response.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v2/customer/partner-referrals"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "tracking_id": "seller-1",
+            "operations": [{
+                "operation": "API_INTEGRATION",
+                "api_integration_preference": {
+                    "rest_api_integration": {
+                        "integration_method": "PAYPAL",
+                        "integration_type": "THIRD_PARTY",
+                        "third_party_details": { "features": ["PAYMENT", "REFUND"] }
+                    }
+                }
+            }],
+            "products": ["EXPRESS_CHECKOUT"],
+            "legal_consents": [{ "type": "SHARE_DATA_CONSENT", "granted": true }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let referral = PartnerReferralPayloadBuilder::default()
+        .tracking_id("seller-1")
+        .operations(vec![OperationRequestBuilder::default()
+            .operation(Operation::ApiIntegration)
+            .api_integration_preference(
+                IntegrationDetailsBuilder::default()
+                    .rest_api_integration(
+                        RestApiIntegrationBuilder::default()
+                            .third_party_details(
+                                ThirdPartyDetailsBuilder::default()
+                                    .features(vec![Feature::Payment, Feature::Refund])
+                                    .build()?,
+                            )
+                            .build()?,
+                    )
+                    .build()?,
+            )
+            .build()?])
+        .products(vec![Product::ExpressCheckout])
+        .legal_consents(vec![LegalConsentBuilder::default().granted(true).build()?])
+        .build()?;
+
+    let links = client.execute(&CreatePartnerReferral::new(referral)).await?;
+
+    assert!(links
+        .action_url()
+        .unwrap()
+        .starts_with("https://www.sandbox.paypal.com/bizsignup/partner/entry"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_referral_data() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let create_links: PartnerReferralLinks =
+        serde_json::from_str(include_str!("resources/create_partner_referral_response.json"))?;
+    let referral_id = create_links.partner_referral_id().unwrap();
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_referral_data_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/customer/partner-referrals/{}", referral_id)))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let referral = client.execute(&ShowReferralData::new(referral_id)).await?;
+
+    assert_eq!(referral.partner_referral_id, referral_id);
+    assert_eq!(referral.referral_data.tracking_id.as_deref(), Some("seller-1"));
+    assert_eq!(
+        referral.referral_data.operations[0].operation,
+        Operation::ApiIntegration
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_merchant_integration() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_merchant_integration_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/v1/customer/partners/PARTNER1/merchant-integrations/8LQLM2ML4ZTYU",
+        ))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let status = client
+        .execute(&ShowMerchantIntegration::new("PARTNER1", "8LQLM2ML4ZTYU"))
+        .await?;
+
+    assert!(status.is_ready());
+    assert_eq!(
+        status.capability("CUSTOM_CARD_PROCESSING"),
+        Some(CapabilityStatus::Active)
+    );
+    assert_eq!(status.oauth_integrations[0].oauth_third_party[0].scopes.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_find_merchant_integration() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customer/partners/PARTNER1/merchant-integrations"))
+        .and(query_param("tracking_id", "seller-1"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "merchant_id": "8LQLM2ML4ZTYU",
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v1/customer/partners/PARTNER1/merchant-integrations/8LQLM2ML4ZTYU",
+                "rel": "read",
+                "method": "GET"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let found = client
+        .execute(&FindMerchantIntegration::new("PARTNER1", "seller-1"))
+        .await?;
+
+    assert_eq!(found.merchant_id, "8LQLM2ML4ZTYU");
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "test-util")]
+
+use paypal_rs::data::{
+    billing_agreements::BillingAgreement,
+    invoice::Invoice,
+    invoice_template::{Template, TemplateList},
+    orders::Order,
+    partner_referrals::{MerchantIntegration, PartnerReferralLinks, ReferralData},
+    vault::{PaymentToken, SetupToken},
+    web_profiles::WebProfile,
+    webhooks::WebhookEvent,
+};
+use paypal_rs::test_util::roundtrip::mismatches;
+use paypal_rs::AccessToken;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+type Check = fn(&str) -> Result<Vec<String>, serde_json::Error>;
+
+fn check<T: serde::de::DeserializeOwned + serde::Serialize>(response: &str) -> Result<Vec<String>, serde_json::Error> {
+    Ok(mismatches::<T>(response)?.iter().map(ToString::to_string).collect())
+}
+
+/// The model each fixture in tests/resources is deserialized into.
+const FIXTURES: &[(&str, Check)] = &[
+    ("capture_order_response.json", check::<Order>),
+    ("create_order_response.json", check::<Order>),
+    ("create_partner_referral_response.json", check::<PartnerReferralLinks>),
+    ("create_payment_token_response.json", check::<PaymentToken>),
+    ("create_setup_token_response.json", check::<SetupToken>),
+    ("list_templates_response.json", check::<TemplateList>),
+    ("oauth_token.json", check::<AccessToken>),
+    ("show_billing_agreement_response.json", check::<BillingAgreement>),
+    ("show_invoice_response.json", check::<Invoice>),
+    ("show_merchant_integration_response.json", check::<MerchantIntegration>),
+    ("show_referral_data_response.json", check::<ReferralData>),
+    ("show_template_response.json", check::<Template>),
+    ("show_web_profile_response.json", check::<WebProfile>),
+    ("webhook_payment_capture_completed.json", check::<WebhookEvent>),
+    ("webhook_payouts_item_unclaimed.json", check::<WebhookEvent>),
+];
+
+#[test]
+fn test_fixtures_roundtrip() {
+    let resources = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/resources");
+
+    let mut failures = Vec::new();
+    for (name, check) in FIXTURES {
+        let response = std::fs::read_to_string(resources.join(name)).unwrap();
+        match check(&response) {
+            Ok(mismatches) => failures.extend(mismatches.into_iter().map(|m| format!("{}: {}", name, m))),
+            Err(e) => failures.push(format!("{}: {}", name, e)),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "fixtures do not roundtrip:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_every_fixture_has_a_model() {
+    let resources = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/resources");
+    let files: BTreeSet<String> = std::fs::read_dir(resources)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".json"))
+        .collect();
+    let registered: BTreeSet<String> = FIXTURES.iter().map(|(name, _)| name.to_string()).collect();
+    assert_eq!(files, registered, "register the new fixtures in FIXTURES");
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "test-util")]
+
+use paypal_rs::api::{
+    invoice::GetInvoice,
+    orders::{CaptureOrder, ShowOrderDetails},
+};
+use paypal_rs::errors::ResponseError;
+use paypal_rs::test_util::{self, fixtures, vcr::Vcr};
+use paypal_rs::{Client, PaypalEnv};
+use wiremock::MockServer;
+
+#[tokio::test]
+async fn test_fixtures() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let capture = CaptureOrder::new("5O190127TN364715T");
+    test_util::mock_endpoint(&capture, 201, fixtures::CAPTURE_ORDER)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let order = client.execute(&capture).await?;
+    let payments = order.purchase_units.as_ref().unwrap()[0].payments.as_ref().unwrap();
+    assert_eq!(payments.captures.len(), 1);
+
+    let get_invoice = GetInvoice::new("INV2-Z56S-5LLA-Q52L-CPZ5");
+    test_util::mock_endpoint(&get_invoice, 200, fixtures::SHOW_INVOICE)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let invoice = client.execute(&get_invoice).await?;
+    assert_eq!(invoice.detail.invoice_number.as_deref(), Some("#123"));
+
+    let event: serde_json::Value = serde_json::from_str(fixtures::WEBHOOK_PAYMENT_CAPTURE_COMPLETED)?;
+    assert_eq!(event["event_type"], "PAYMENT.CAPTURE.COMPLETED");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_vcr_record_replay() -> color_eyre::Result<()> {
+    let cassette = std::env::temp_dir().join(format!("paypal-rs-vcr-{}.json", std::process::id()));
+    let show_order = ShowOrderDetails::new("5O190127TN364715T");
+
+    {
+        let mock_server = MockServer::start().await;
+        test_util::mock_access_token(&mock_server).await;
+        test_util::mock_endpoint(&show_order, 200, fixtures::CAPTURE_ORDER)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::builder("clientid", "secret", PaypalEnv::Mock(mock_server.uri()))
+            .middleware(Vcr::record(&cassette).scrub("QYR5Z8XDVJNXQ"))
+            .build()?;
+        let order = client.execute(&show_order).await?;
+        assert_eq!(order.id, "5O190127TN364715T");
+    }
+
+    let recorded = std::fs::read_to_string(&cassette)?;
+    assert!(!recorded.contains("TESTBEARERTOKEN"));
+    assert!(!recorded.contains("QYR5Z8XDVJNXQ"));
+
+    // Nothing listens on this port, every response comes from the cassette.
+    let client = Client::builder("clientid", "secret", PaypalEnv::Mock("http://127.0.0.1:9".to_string()))
+        .middleware(Vcr::replay(&cassette)?)
+        .build()?;
+    let order = client.execute(&show_order).await?;
+    assert_eq!(order.id, "5O190127TN364715T");
+
+    let res = client.execute(&show_order).await;
+    assert!(
+        matches!(res, Err(ResponseError::Unexpected { status, .. }) if status == reqwest::StatusCode::NOT_IMPLEMENTED)
+    );
+
+    std::fs::remove_file(&cassette)?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use paypal_rs::test_util;
+use paypal_rs::{api::vault::*, data::vault::*};
+use wiremock::matchers::{bearer_token, body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_setup_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_setup_token_response.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v3/vault/setup-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "customer": { "id": "customer_4029352050" },
+            "payment_source": {
+                "paypal": {
+                    "usage_pattern": "IMMEDIATE",
+                    "usage_type": "MERCHANT",
+                    "experience_context": { "return_url": "https://example.com/returnUrl" }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let payload = SetupTokenPayloadBuilder::default()
+        .customer(CustomerBuilder::default().id("customer_4029352050").build()?)
+        .payment_source(
+            SetupTokenPaymentSourceBuilder::default()
+                .paypal(
+                    VaultPaypalBuilder::default()
+                        .usage_pattern(UsagePattern::Immediate)
+                        .usage_type(UsageType::Merchant)
+                        .experience_context(
+                            VaultExperienceContextBuilder::default()
+                                .return_url("https://example.com/returnUrl")
+                                .cancel_url("https://example.com/cancelUrl")
+                                .build()?,
+                        )
+                        .build()?,
+                )
+                .build()?,
+        )
+        .build()?;
+
+    let setup_token = client.execute(&CreateSetupToken::new(payload)).await?;
+
+    assert_eq!(setup_token.status, SetupTokenStatus::PayerActionRequired);
+    assert!(setup_token
+        .approve_url()
+        .unwrap()
+        .starts_with("https://sandbox.paypal.com/agreements/approve"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_setup_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let mut response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_setup_token_response.json")).unwrap();
+    response_body["status"] = "APPROVED".into();
+
+    Mock::given(method("GET"))
+        .and(path("/v3/vault/setup-tokens/5C991763VB2781612"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let setup_token = client.execute(&ShowSetupToken::new("5C991763VB2781612")).await?;
+
+    assert!(setup_token.is_approved());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_payment_token() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_payment_token_response.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v3/vault/payment-tokens"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "payment_source": { "token": { "id": "5C991763VB2781612", "type": "SETUP_TOKEN" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let payment_token = client
+        .execute(&CreatePaymentToken::from_setup_token("5C991763VB2781612"))
+        .await?;
+
+    assert_eq!(payment_token.id, "8kk8451t");
+    assert_eq!(
+        payment_token.payment_source.card.unwrap().last_digits.as_deref(),
+        Some("1111")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_manage_payment_tokens() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let payment_token: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_payment_token_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v3/vault/payment-tokens"))
+        .and(query_param("customer_id", "customer_4029352050"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "customer": { "id": "customer_4029352050" },
+            "payment_tokens": [payment_token],
+            "links": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v3/vault/payment-tokens/8kk8451t"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&payment_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v3/vault/payment-tokens/8kk8451t"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let list = client
+        .execute(&ListPaymentTokens::for_customer("customer_4029352050"))
+        .await?;
+    assert_eq!(list.payment_tokens.len(), 1);
+
+    let token = client
+        .execute(&ShowPaymentToken::new(&list.payment_tokens[0].id))
+        .await?;
+    assert_eq!(token.id, "8kk8451t");
+
+    client.execute(&DeletePaymentToken::new(&token.id)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use paypal_rs::test_util;
+use paypal_rs::{api::web_profiles::*, data::common::Patch, data::web_profiles::*};
+use wiremock::matchers::{bearer_token, body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_web_profile() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/payment-experience/web-profiles"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "exampleProfile",
+            "temporary": true,
+            "flow_config": { "landing_page_type": "Billing", "user_action": "commit" },
+            "input_fields": { "no_shipping": 1, "address_override": 1 },
+            "presentation": { "brand_name": "exampleBrand", "locale_code": "US" }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "XP-CP6S-W9DY-96H8-MVN2"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let profile = WebProfileBuilder::default()
+        .name("exampleProfile")
+        .temporary(true)
+        .flow_config(
+            FlowConfigBuilder::default()
+                .landing_page_type(LandingPageType::Billing)
+                .user_action("commit")
+                .build()?,
+        )
+        .input_fields(
+            InputFieldsBuilder::default()
+                .no_shipping(1u8)
+                .address_override(1u8)
+                .build()?,
+        )
+        .presentation(
+            PresentationBuilder::default()
+                .brand_name("exampleBrand")
+                .locale_code("US")
+                .build()?,
+        )
+        .build()?;
+
+    let created = client.execute(&CreateWebProfile::new(profile)).await?;
+    assert_eq!(created.id, "XP-CP6S-W9DY-96H8-MVN2");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_and_show_web_profiles() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let profile: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_web_profile_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payment-experience/web-profiles"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([profile])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payment-experience/web-profiles/XP-RFV4-PVD8-AGHJ-8E5J"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&profile))
+        .mount(&mock_server)
+        .await;
+
+    let profiles = client.execute(&ListWebProfiles::new()).await?;
+    assert_eq!(profiles.len(), 1);
+
+    let profile = client
+        .execute(&ShowWebProfile::new(profiles[0].id.as_ref().unwrap()))
+        .await?;
+    assert_eq!(profile.name, "exampleProfile");
+    assert_eq!(
+        profile.flow_config.unwrap().return_uri_http_method,
+        Some(ReturnUriHttpMethod::Get)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_and_delete_web_profile() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = test_util::mock_client(&mock_server).await;
+
+    let profile_path = "/v1/payment-experience/web-profiles/XP-RFV4-PVD8-AGHJ-8E5J";
+
+    Mock::given(method("PUT"))
+        .and(path(profile_path))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(serde_json::json!({ "name": "exampleProfile" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(profile_path))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!([
+            { "op": "replace", "path": "/presentation/brand_name", "value": "new_brand_name" },
+            { "op": "remove", "path": "/flow_config/landing_page_type" }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(profile_path))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let profile = WebProfileBuilder::default().name("exampleProfile").build()?;
+    client
+        .execute(&UpdateWebProfile::new("XP-RFV4-PVD8-AGHJ-8E5J", profile))
+        .await?;
+
+    client
+        .execute(&PatchWebProfile::new(
+            "XP-RFV4-PVD8-AGHJ-8E5J",
+            vec![
+                Patch::replace("/presentation/brand_name", "new_brand_name"),
+                Patch::remove("/flow_config/landing_page_type"),
+            ],
+        ))
+        .await?;
+
+    client.execute(&DeleteWebProfile::new("XP-RFV4-PVD8-AGHJ-8E5J")).await?;
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "webhook-verification")]
+
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+use paypal_rs::errors::{VerifyWebhookError, WebhookCertError};
+use paypal_rs::verification::{CertCache, WebhookCert};
+use paypal_rs::{test_util, Client, PaypalEnv};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CERT: &str = include_str!("resources/client_cert.pem");
+
+async fn create_client(mock_server: &MockServer) -> Client {
+    Mock::given(method("GET"))
+        .and(path("/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(CERT))
+        .expect(1..)
+        .mount(mock_server)
+        .await;
+
+    test_util::mock_client(mock_server).await
+}
+
+#[tokio::test]
+async fn test_cert_cache() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = create_client(&mock_server).await;
+    let cert_url = format!(
+        "{}/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        mock_server.uri()
+    );
+
+    let certs = CertCache::new();
+    let cert = certs.get(&client, &cert_url).await?;
+    assert_eq!(cert, WebhookCert::from_pem(CERT.as_bytes())?);
+    assert!(cert.not_after() > chrono::Utc::now());
+    certs.get(&client, &cert_url).await?;
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+    // An expired time to live downloads the certificate again.
+    let certs = CertCache::new().ttl(Duration::ZERO);
+    certs.get(&client, &cert_url).await?;
+    certs.get(&client, &cert_url).await?;
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cert_cache_rejections() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = create_client(&mock_server).await;
+    let cert_url = format!(
+        "{}/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        mock_server.uri()
+    );
+    let fingerprint = WebhookCert::from_pem(CERT.as_bytes())?.fingerprint();
+
+    let certs = CertCache::new().pin([0; 32]);
+    assert!(matches!(
+        certs.get(&client, &cert_url).await,
+        Err(WebhookCertError::NotPinned)
+    ));
+    let certs = CertCache::new().pin([0; 32]).pin(fingerprint);
+    certs.get(&client, &cert_url).await?;
+
+    let certs = CertCache::new();
+    for url in [
+        "https://attacker.example.com/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        "https://api.paypal.com.attacker.example.com/cert.pem",
+    ] {
+        assert!(matches!(
+            certs.get(&client, url).await,
+            Err(WebhookCertError::DisallowedUrl(_))
+        ));
+    }
+
+    let live = Client::builder("clientid", "secret", PaypalEnv::Live)
+        .allow_live()
+        .build()?;
+    for url in [
+        "http://api.paypal.com/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        "https://api.paypal.com:8443/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        "https://user@api.paypal.com/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+        "https://paypal.com.example.com/cert.pem",
+    ] {
+        assert!(matches!(
+            certs.get(&live, url).await,
+            Err(WebhookCertError::DisallowedUrl(_))
+        ));
+    }
+
+    assert!(matches!(
+        WebhookCert::from_pem(b"not a certificate"),
+        Err(WebhookCertError::Invalid(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_webhook_offline() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    let client = create_client(&mock_server).await;
+    let body = include_bytes!("resources/webhook_payment_capture_completed.json");
+
+    // Signed with resources/client_key.pem.
+    let mut headers = HeaderMap::new();
+    headers.insert("PAYPAL-AUTH-ALGO", "SHA256withRSA".parse()?);
+    headers.insert(
+        "PAYPAL-CERT-URL",
+        format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-a5cafa77",
+            mock_server.uri()
+        )
+        .parse()?,
+    );
+    headers.insert(
+        "PAYPAL-TRANSMISSION-ID",
+        "69cd13f0-d67a-11e5-baa3-778b53f4ae55".parse()?,
+    );
+    headers.insert("PAYPAL-TRANSMISSION-SIG", "kkhI/aV5oSSnnsGxiGaa9IoHefVkh/GW9+FeMmsf9LDuedMgrLhNzVHL/g6ugKtnlxo8zV7legeNye2D5fADaotgYfZJ+4LdwlRyypTAvhAygOZBZGssYoGasF9YsNeoHaUvIAZN+zMfSm1Y/gCqoyNENpZAnIsts8yvu6stxiBhktCU6GNrYtRngkL3XPwJjqvDsP2DxWNFxSE3NPAURdarYApDr9z4FnI2vldKGpMKkXDqB293PUrdbry/9+Xs+zHJ70EuAjlM8fvd1CWkxEmD7atszkLXdHzv8PLIPcksr0BG3+cFkhp6eVYN2x8jnJftMolmIGwa1c4knJKZ5A==".parse()?);
+    headers.insert("PAYPAL-TRANSMISSION-TIME", "2016-02-18T20:01:35Z".parse()?);
+
+    let event = client.verify_webhook(&headers, body, "1JE4291016473214C").await?;
+    assert_eq!(event.id, "WH-58D329510W468432D-8HN650336L201105X");
+    client.verify_webhook(&headers, body, "1JE4291016473214C").await?;
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+    // Another webhook, or a modified body.
+    assert!(matches!(
+        client.verify_webhook(&headers, body, "5GP028458E2496506").await,
+        Err(VerifyWebhookError::InvalidSignature)
+    ));
+    let mut modified = body.to_vec();
+    modified.push(b'\n');
+    assert!(matches!(
+        client.verify_webhook(&headers, &modified, "1JE4291016473214C").await,
+        Err(VerifyWebhookError::InvalidSignature)
+    ));
+
+    // A spoofed certificate url.
+    headers.insert("PAYPAL-CERT-URL", "https://attacker.example.com/cert.pem".parse()?);
+    assert!(matches!(
+        client.verify_webhook(&headers, body, "1JE4291016473214C").await,
+        Err(VerifyWebhookError::Certificate(WebhookCertError::DisallowedUrl(_)))
+    ));
+
+    Ok(())
+}